@@ -0,0 +1,83 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[cfg(feature = "aws")]
+use evm_signer_kms::evm_account::EvmAccount;
+use evm_signer_kms::evm_account::{
+    hash::{eip191_hash_message, keccak256},
+    signature::Signature,
+};
+
+// Signature test vector shared with the unit tests of the library and the binary
+#[allow(dead_code)]
+mod signature_vector {
+    include!("../tests/data/signature_vector.rs");
+}
+use signature_vector::{TEST_DIGEST, TEST_R, TEST_S};
+
+// Public key of the account
+#[cfg(feature = "aws")]
+const PUBLIC_KEY: [u8; 64] = [
+    0xf9, 0x52, 0xb9, 0x6e, 0xb7, 0xa7, 0x84, 0x5a, 0xda, 0xbe, 0x93, 0x4b, 0xe3, 0x43, 0x8d, 0x92,
+    0xe9, 0x97, 0x64, 0x78, 0x56, 0xdb, 0xc4, 0x89, 0x7c, 0x66, 0x1d, 0x2e, 0x8f, 0x39, 0xbe, 0x7a,
+    0x27, 0x83, 0x23, 0x47, 0x42, 0xd4, 0x11, 0xb3, 0xc9, 0xe4, 0x55, 0x4d, 0xb4, 0xc8, 0x66, 0x2a,
+    0x54, 0x71, 0x60, 0xf7, 0xee, 0x30, 0xd0, 0xaa, 0x68, 0x00, 0x88, 0xe1, 0xa1, 0xdd, 0x80, 0xc0,
+];
+
+fn digests(c: &mut Criterion) {
+    let message = "Hello, world!";
+
+    c.bench_function("keccak256", |b| {
+        b.iter(|| keccak256(black_box(&TEST_DIGEST)))
+    });
+    c.bench_function("eip191_hash_message", |b| {
+        b.iter(|| eip191_hash_message(black_box(message)))
+    });
+}
+
+fn signatures(c: &mut Criterion) {
+    let signature = Signature::new(TEST_R, TEST_S, 0);
+    let bytes = signature.to_bytes();
+
+    // Public key recovery is done for every signature obtained from KMS, to find its parity
+    c.bench_function("signature_recover", |b| {
+        b.iter(|| {
+            black_box(&signature)
+                .recover(black_box(&TEST_DIGEST))
+                .unwrap()
+        })
+    });
+    c.bench_function("signature_to_bytes", |b| {
+        b.iter(|| black_box(&signature).to_bytes())
+    });
+    c.bench_function("signature_from_bytes", |b| {
+        b.iter(|| Signature::try_from(black_box(&bytes[..])).unwrap())
+    });
+}
+
+// The DER signature returned by KMS is parsed, fitted into components, canonicalized and its
+// parity found by public key recovery, for every signature
+#[cfg(feature = "aws")]
+fn kms_signatures(c: &mut Criterion) {
+    // DER sequence of the integers, the high bit of `TEST_S` requiring a leading zero byte
+    let mut signature_der = vec![0x30, 0x45, 0x02, 0x20];
+    signature_der.extend_from_slice(&TEST_R);
+    signature_der.extend_from_slice(&[0x02, 0x21, 0x00]);
+    signature_der.extend_from_slice(&TEST_S);
+
+    c.bench_function("signature_from_der", |b| {
+        b.iter(|| {
+            EvmAccount::signature_from_der(
+                black_box(&PUBLIC_KEY),
+                black_box(&TEST_DIGEST),
+                black_box(&signature_der),
+            )
+            .unwrap()
+        })
+    });
+}
+
+#[cfg(feature = "aws")]
+criterion_group!(benches, digests, signatures, kms_signatures);
+#[cfg(not(feature = "aws"))]
+criterion_group!(benches, digests, signatures);
+criterion_main!(benches);
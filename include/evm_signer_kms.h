@@ -0,0 +1,47 @@
+/*
+ * C ABI of evm-signer-kms, built with the `ffi` feature.
+ *
+ * Functions return EVM_SIGNER_KMS_OK on success, or another status code on failure, in which case
+ * evm_signer_kms_last_error() describes the error. Strings returned by the library are released
+ * with evm_signer_kms_string_free().
+ */
+#ifndef EVM_SIGNER_KMS_H
+#define EVM_SIGNER_KMS_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define EVM_SIGNER_KMS_OK 0
+#define EVM_SIGNER_KMS_INVALID_ARGUMENT 1
+#define EVM_SIGNER_KMS_PERMISSION_DENIED 2
+#define EVM_SIGNER_KMS_NOT_FOUND 3
+#define EVM_SIGNER_KMS_ERROR 4
+
+/* Account backed by a KMS key. */
+typedef struct EvmSignerKmsAccount EvmSignerKmsAccount;
+
+/* Creates the account of the KMS key, with the AWS configuration from the environment. */
+int evm_signer_kms_account_new(const char *key_id, EvmSignerKmsAccount **account);
+
+/* Releases the account. Null is ignored. */
+void evm_signer_kms_account_free(EvmSignerKmsAccount *account);
+
+/* Returns the checksummed address of the account. */
+int evm_signer_kms_account_address(const EvmSignerKmsAccount *account, char **address);
+
+/* Signs the transaction JSON of any supported type and returns the signed transaction in hex. */
+int evm_signer_kms_sign_transaction_json(const EvmSignerKmsAccount *account, const char *tx_json,
+                                         char **raw_tx);
+
+/* Releases a string returned by the library. Null is ignored. */
+void evm_signer_kms_string_free(char *string);
+
+/* Returns the message of the last error on the calling thread, or null after a successful call. */
+const char *evm_signer_kms_last_error(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* EVM_SIGNER_KMS_H */
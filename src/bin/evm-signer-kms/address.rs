@@ -0,0 +1,112 @@
+use std::io::Error;
+
+use clap::Args;
+use evm_signer_kms::evm_account::{transaction::Bytes, EvmAccount};
+
+use crate::{config::Config, key::KeyArgs};
+
+// SEC1 prefixes of uncompressed and compressed public keys.
+const UNCOMPRESSED_PREFIX: u8 = 0x04;
+const EVEN_Y_PREFIX: u8 = 0x02;
+const ODD_Y_PREFIX: u8 = 0x03;
+// Length of each public key coordinate.
+const COORDINATE_LENGTH: usize = 32;
+
+#[derive(Args, Debug)]
+pub struct AddressArgs {
+    #[command(flatten)]
+    key: KeyArgs,
+    /// Also print the public key in the raw, SEC1 uncompressed, SEC1 compressed and DER formats.
+    #[arg(long)]
+    public_key: bool,
+}
+
+pub async fn run(args: AddressArgs, config: &Config) -> Result<(), Error> {
+    let kms_key = args.key.kms_key(config).await?;
+    let evm_account = EvmAccount::new(&kms_key).await?;
+
+    if !args.public_key {
+        println!("{}", evm_account.address());
+        return Ok(());
+    }
+
+    let public_key_der = kms_key.get_public_key().await?;
+    println!("Address:                   {}", evm_account.address());
+    println!(
+        "Public key (raw):          {}",
+        Bytes(evm_account.public_key.to_vec())
+    );
+    println!(
+        "Public key (uncompressed): {}",
+        Bytes(uncompressed(&evm_account.public_key))
+    );
+    println!(
+        "Public key (compressed):   {}",
+        Bytes(compressed(&evm_account.public_key))
+    );
+    println!("Public key (DER):          {}", Bytes(public_key_der));
+
+    Ok(())
+}
+
+// Prefixes the raw public key with the SEC1 uncompressed point prefix.
+fn uncompressed(public_key: &[u8]) -> Vec<u8> {
+    let mut encoding = Vec::with_capacity(public_key.len() + 1);
+    encoding.push(UNCOMPRESSED_PREFIX);
+    encoding.extend_from_slice(public_key);
+
+    encoding
+}
+
+// Keeps the x coordinate of the raw public key prefixed with the parity of the y coordinate.
+fn compressed(public_key: &[u8]) -> Vec<u8> {
+    let (x, y) = public_key.split_at(COORDINATE_LENGTH);
+    let prefix = match y.last() {
+        Some(last_byte) if last_byte % 2 == 1 => ODD_Y_PREFIX,
+        _ => EVEN_Y_PREFIX,
+    };
+
+    let mut encoding = Vec::with_capacity(COORDINATE_LENGTH + 1);
+    encoding.push(prefix);
+    encoding.extend_from_slice(x);
+
+    encoding
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    // Public key of the private key `0x01`
+    const TEST_PUBLIC_KEY_STR: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+                                        483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+    #[test]
+    fn uncompressed_succeed() {
+        let input = hex::decode(TEST_PUBLIC_KEY_STR).unwrap();
+
+        let right = uncompressed(&input);
+
+        assert_eq!(format!("04{}", TEST_PUBLIC_KEY_STR), hex::encode(right));
+    }
+
+    #[test]
+    fn compressed_even_y_succeed() {
+        let left = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let input = hex::decode(TEST_PUBLIC_KEY_STR).unwrap();
+
+        let right = compressed(&input);
+
+        assert_eq!(left, hex::encode(right));
+    }
+
+    #[test]
+    fn compressed_odd_y_succeed() {
+        let mut input = hex::decode(TEST_PUBLIC_KEY_STR).unwrap();
+        *input.last_mut().unwrap() |= 0x01;
+
+        let right = compressed(&input);
+
+        assert_eq!(ODD_Y_PREFIX, right[0]);
+    }
+}
@@ -0,0 +1,71 @@
+use std::{
+    io::{Error, ErrorKind},
+    path::PathBuf,
+    time::Duration,
+};
+
+use clap::Args;
+use evm_signer_kms::evm_account::{
+    rpc::{broadcast::broadcast_raw, receipt::wait_for_receipt, HttpProvider},
+    transaction::Bytes,
+};
+
+use crate::{config::Config, input};
+
+#[derive(Args, Debug)]
+pub struct BroadcastArgs {
+    /// File with the raw signed transaction in hex, or `-` for the standard input.
+    #[arg(default_value = "-")]
+    input: PathBuf,
+    /// URL of the JSON-RPC endpoint. Defaults to the endpoint of the chain in the configuration
+    /// file.
+    #[arg(long, env = "RPC_URL")]
+    rpc_url: Option<String>,
+    /// Chain whose endpoint is looked up in the configuration file. Defaults to `chain_id` of
+    /// the configuration file.
+    #[arg(long)]
+    chain_id: Option<u64>,
+    /// Wait until the transaction has the number of confirmations (i.e. `1` once included).
+    #[arg(long)]
+    confirmations: Option<u64>,
+    /// Maximum time in seconds to wait for the confirmations.
+    #[arg(long, default_value_t = 300)]
+    timeout: u64,
+}
+
+pub async fn run(args: BroadcastArgs, config: &Config) -> Result<(), Error> {
+    let rpc_url = args
+        .rpc_url
+        .as_deref()
+        .or_else(|| config.rpc_url(args.chain_id))
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "No RPC URL selected"))?;
+    let raw_tx: Bytes = input::read_to_string(&args.input)?.trim().parse()?;
+    let provider = HttpProvider::new(rpc_url)?;
+
+    let tx_hash = broadcast_raw(&raw_tx.0, &provider).await?;
+    println!("{}", Bytes::from(tx_hash.as_slice()));
+
+    let Some(confirmations) = args.confirmations else {
+        return Ok(());
+    };
+    let receipt = wait_for_receipt(
+        &tx_hash,
+        confirmations,
+        Duration::from_secs(args.timeout),
+        &provider,
+    )
+    .await?;
+
+    if !receipt.success {
+        return Err(Error::other(format!(
+            "Transaction reverted in block {}",
+            receipt.block_number
+        )));
+    }
+    eprintln!(
+        "Included in block {} (gas used: {})",
+        receipt.block_number, receipt.gas_used
+    );
+
+    Ok(())
+}
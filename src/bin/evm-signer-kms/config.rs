@@ -0,0 +1,255 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    io::{Error, ErrorKind},
+    path::Path,
+    str::FromStr,
+};
+
+use evm_signer_kms::evm_account::policy::SigningPolicy;
+use serde::Deserialize;
+
+use crate::input;
+
+/// Settings shared by all subcommands, loaded from a TOML file. Command line flags take
+/// precedence over environment variables, which take precedence over the file.
+///
+/// ```toml
+/// key_id = "alias/treasury"
+/// role_arn = "arn:aws:iam::123456789012:role/signer"
+/// chain_id = 11155111
+///
+/// [rpc_urls]
+/// 1 = "https://eth.example.com"
+/// 11155111 = "https://sepolia.example.com"
+///
+/// [policy]
+/// permitted_chain_ids = [1, 11155111]
+/// max_data_length = 4096
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// ID, ARN or alias of the KMS key (`KMS_KEY_ID`).
+    pub key_id: Option<String>,
+    /// ARN of the IAM role assumed to use the key (`KMS_ROLE_ARN`).
+    pub role_arn: Option<String>,
+    /// Chain used when none is selected explicitly (`CHAIN_ID`).
+    pub chain_id: Option<u64>,
+    /// JSON-RPC endpoints by chain ID. TOML keys are strings, hence the chain IDs are as well.
+    #[serde(default)]
+    pub rpc_urls: BTreeMap<String, String>,
+    /// Restrictions of transactions which are signed.
+    #[serde(default)]
+    pub policy: PolicyConfig,
+}
+
+/// Signing policy settings (see `SigningPolicy`).
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyConfig {
+    /// Chain IDs which transactions can be signed for (`PERMITTED_CHAIN_IDS`, comma separated).
+    pub permitted_chain_ids: Option<Vec<u64>>,
+    /// Maximum length of transaction data in bytes (`MAX_DATA_LENGTH`).
+    pub max_data_length: Option<usize>,
+}
+
+impl Config {
+    /// Loads the configuration file, if any, and applies overrides from the environment.
+    pub fn load(path: Option<&Path>) -> Result<Self, Error> {
+        let mut config = match path {
+            Some(path) => input::read_to_string(path)?.parse()?,
+            None => Self::default(),
+        };
+        config.apply_overrides(|name| env::var(name).ok())?;
+
+        Ok(config)
+    }
+
+    /// Returns the JSON-RPC endpoint of the chain, or of the default chain if none is selected.
+    pub fn rpc_url(&self, chain_id: Option<u64>) -> Option<&str> {
+        let chain_id = chain_id.or(self.chain_id)?;
+
+        self.rpc_urls.get(&chain_id.to_string()).map(String::as_str)
+    }
+
+    /// Returns the signing policy described by the `policy` table.
+    pub fn signing_policy(&self) -> SigningPolicy {
+        let mut policy = SigningPolicy::new();
+        if let Some(chain_ids) = &self.policy.permitted_chain_ids {
+            policy = policy.with_permitted_chain_ids(chain_ids.iter().copied());
+        }
+        if let Some(max_data_length) = self.policy.max_data_length {
+            policy = policy.with_max_data_length(max_data_length);
+        }
+
+        policy
+    }
+
+    fn apply_overrides<F>(&mut self, var: F) -> Result<(), Error>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if let Some(key_id) = var("KMS_KEY_ID") {
+            self.key_id = Some(key_id);
+        }
+        if let Some(role_arn) = var("KMS_ROLE_ARN") {
+            self.role_arn = Some(role_arn);
+        }
+        if let Some(chain_id) = var("CHAIN_ID") {
+            self.chain_id = Some(parse_var("CHAIN_ID", &chain_id)?);
+        }
+        if let Some(chain_ids) = var("PERMITTED_CHAIN_IDS") {
+            self.policy.permitted_chain_ids = Some(
+                chain_ids
+                    .split(',')
+                    .map(|chain_id| parse_var("PERMITTED_CHAIN_IDS", chain_id))
+                    .collect::<Result<_, _>>()?,
+            );
+        }
+        if let Some(max_data_length) = var("MAX_DATA_LENGTH") {
+            self.policy.max_data_length = Some(parse_var("MAX_DATA_LENGTH", &max_data_length)?);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Config {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let config: Self = toml::from_str(s).map_err(|error| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Failed to parse configuration: {}", error),
+            )
+        })?;
+        if let Some(chain_id) = config
+            .rpc_urls
+            .keys()
+            .find(|chain_id| chain_id.parse::<u64>().is_err())
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid chain ID of RPC URL: {}", chain_id),
+            ));
+        }
+
+        Ok(config)
+    }
+}
+
+fn parse_var<T: FromStr>(name: &str, value: &str) -> Result<T, Error> {
+    value.trim().parse().map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("Invalid value of {}: {}", name, value),
+        )
+    })
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    const TEST_CONFIG: &str = r#"
+        key_id = "alias/treasury"
+        chain_id = 11155111
+
+        [rpc_urls]
+        1 = "https://eth.example.com"
+        11155111 = "https://sepolia.example.com"
+
+        [policy]
+        permitted_chain_ids = [1, 11155111]
+        max_data_length = 4096
+    "#;
+
+    #[test]
+    fn parse_config_succeed() {
+        let right: Config = TEST_CONFIG.parse().unwrap();
+
+        assert_eq!(Some("alias/treasury"), right.key_id.as_deref());
+        assert_eq!(None, right.role_arn);
+        assert_eq!(Some(11155111), right.chain_id);
+        assert_eq!(Some(vec![1, 11155111]), right.policy.permitted_chain_ids);
+        assert_eq!(Some(4096), right.policy.max_data_length);
+    }
+
+    #[test]
+    fn parse_empty_config_succeed() {
+        let right: Config = "".parse().unwrap();
+
+        assert_eq!(Config::default(), right);
+    }
+
+    #[test]
+    fn parse_unknown_field_fail() {
+        let input = "key = \"alias/treasury\"";
+
+        let right = input.parse::<Config>().unwrap_err();
+
+        assert_eq!(ErrorKind::InvalidData, right.kind());
+    }
+
+    #[test]
+    fn parse_invalid_rpc_chain_id_fail() {
+        let input = "[rpc_urls]\nmainnet = \"https://eth.example.com\"";
+
+        let right = input.parse::<Config>().unwrap_err();
+
+        assert_eq!(ErrorKind::InvalidData, right.kind());
+    }
+
+    #[test]
+    fn rpc_url_succeed() {
+        let input: Config = TEST_CONFIG.parse().unwrap();
+
+        assert_eq!(Some("https://eth.example.com"), input.rpc_url(Some(1)));
+        assert_eq!(Some("https://sepolia.example.com"), input.rpc_url(None));
+        assert_eq!(None, input.rpc_url(Some(10)));
+    }
+
+    #[test]
+    fn apply_overrides_succeed() {
+        let mut right: Config = TEST_CONFIG.parse().unwrap();
+
+        right
+            .apply_overrides(|name| match name {
+                "KMS_KEY_ID" => Some("alias/payouts".to_string()),
+                "PERMITTED_CHAIN_IDS" => Some("10, 8453".to_string()),
+                _ => None,
+            })
+            .unwrap();
+
+        assert_eq!(Some("alias/payouts"), right.key_id.as_deref());
+        assert_eq!(Some(11155111), right.chain_id);
+        assert_eq!(Some(vec![10, 8453]), right.policy.permitted_chain_ids);
+    }
+
+    #[test]
+    fn apply_overrides_fail() {
+        let mut input = Config::default();
+
+        let right = input
+            .apply_overrides(|name| (name == "CHAIN_ID").then(|| "mainnet".to_string()))
+            .unwrap_err();
+
+        assert_eq!(ErrorKind::InvalidInput, right.kind());
+    }
+
+    #[test]
+    fn signing_policy_succeed() {
+        let input: Config = TEST_CONFIG.parse().unwrap();
+
+        let right = input.signing_policy();
+
+        assert_eq!(
+            SigningPolicy::new()
+                .with_permitted_chain_ids([1, 11155111])
+                .with_max_data_length(4096),
+            right
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    io::{Error, ErrorKind},
+    path::Path,
+};
+
+use clap::Args;
+use evm_signer_kms::evm_account::transaction::{
+    access_list_transaction::AccessListTransaction, free_market_transaction::FreeMarketTransaction,
+    legacy_transaction::LegacyTransaction, Bytes, SignedTransaction, Transaction, TxType,
+};
+use rlp::Decodable;
+use serde_json::Value;
+
+use crate::input;
+
+// Argument denoting the standard input.
+const STDIN_ARG: &str = "-";
+
+#[derive(Args, Debug)]
+pub struct DecodeArgs {
+    /// Raw signed transaction in hex, or `-` for the standard input.
+    raw_tx: String,
+}
+
+pub async fn run(args: DecodeArgs) -> Result<(), Error> {
+    let raw_tx = match args.raw_tx.as_str() {
+        STDIN_ARG => input::read_to_string(Path::new(STDIN_ARG))?,
+        raw_tx => raw_tx.to_string(),
+    };
+
+    let decoded = decode(raw_tx.trim())?;
+    let pretty_json = serde_json::to_string_pretty(&decoded)
+        .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+    println!("{}", pretty_json);
+
+    Ok(())
+}
+
+/// Decodes the raw signed transaction of any supported type into its JSON representation (see
+/// `SignedTransaction::to_json`) extended with the maximum cost and the summary.
+pub fn decode(raw_tx: &str) -> Result<Value, Error> {
+    let raw_tx: Bytes = raw_tx.parse()?;
+
+    match TxType::from_encoding(&raw_tx.0) {
+        TxType::Legacy => to_json::<LegacyTransaction>(&raw_tx.0),
+        TxType::Eip2930 => to_json::<AccessListTransaction>(&raw_tx.0),
+        TxType::Eip1559 => to_json::<FreeMarketTransaction>(&raw_tx.0),
+        tx_type => Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("Unsupported transaction type: {:#04x}", tx_type.id()),
+        )),
+    }
+}
+
+fn to_json<T: Transaction + Decodable>(raw_tx: &[u8]) -> Result<Value, Error> {
+    let signed_tx = SignedTransaction::<T>::decode(raw_tx)?;
+
+    let mut json = signed_tx.to_json()?;
+    if let Some(fields) = json.as_object_mut() {
+        fields.insert(
+            "maxCost".to_string(),
+            format!("{:#x}", signed_tx.max_cost()?).into(),
+        );
+        fields.insert("summary".to_string(), signed_tx.summary().into());
+    }
+
+    Ok(json)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use evm_signer_kms::evm_account::hash::keccak256;
+
+    // Signature test vector shared with the unit tests of the library and the benchmarks
+    #[allow(dead_code)]
+    mod signature_vector {
+        include!("../../../tests/data/signature_vector.rs");
+    }
+    use signature_vector::{TEST_R, TEST_S};
+
+    // Mainnet transaction of the EIP-155 specification, whose sender is known
+    const TEST_EIP_155_TX: &str = "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+    const TEST_EIP_155_SENDER: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+    const TEST_TX_JSON: &str = r#"
+    {
+        "gasLimit": 21000,
+        "maxFeePerGas": 100000000000,
+        "maxPriorityFeePerGas": 3000000000,
+        "chainId": 11155111,
+        "nonce": 7,
+        "to": "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573",
+        "value": 10000000000000000,
+        "data": "",
+        "accessList": []
+    }
+    "#;
+
+    #[test]
+    fn decode_free_market_succeed() {
+        let tx: FreeMarketTransaction = serde_json::from_str(TEST_TX_JSON).unwrap();
+        let encoding = tx.encode();
+        let signed_tx =
+            SignedTransaction::new(tx, &encoding, keccak256(&encoding), 0, TEST_R, TEST_S);
+        let input = Bytes(signed_tx.encode()).to_string();
+
+        let right = decode(&input).unwrap();
+
+        assert_eq!("0x2", right["type"]);
+        assert_eq!("0x7", right["nonce"]);
+        assert_eq!("0x2afce2c9c84000", right["maxCost"]);
+        assert_eq!(
+            Bytes::from(signed_tx.hash().as_slice()).to_string(),
+            right["hash"]
+        );
+        assert_eq!(signed_tx.sender().unwrap().to_string(), right["from"]);
+    }
+
+    #[test]
+    fn decode_eip155_legacy_succeed() {
+        let right = decode(TEST_EIP_155_TX).unwrap();
+
+        assert_eq!("0x0", right["type"]);
+        assert_eq!("0x1", right["chainId"]);
+        assert_eq!(TEST_EIP_155_SENDER, right["from"]);
+        assert_eq!("0xde234b086324000", right["maxCost"]);
+    }
+
+    #[test]
+    fn decode_invalid_hex_fail() {
+        assert!(decode("0xzz").is_err());
+    }
+
+    #[test]
+    fn decode_unsupported_type_fail() {
+        let error = decode("0x03c0").unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+    }
+}
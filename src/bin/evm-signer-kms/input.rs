@@ -0,0 +1,25 @@
+use std::{
+    fs,
+    io::{self, Error, Read},
+    path::Path,
+};
+
+// Path denoting the standard input.
+const STDIN_PATH: &str = "-";
+
+/// Reads the whole file, or the standard input if the path is `-`.
+pub fn read_to_string(path: &Path) -> Result<String, Error> {
+    if path.as_os_str() == STDIN_PATH {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+
+        return Ok(input);
+    }
+
+    fs::read_to_string(path).map_err(|error| {
+        Error::new(
+            error.kind(),
+            format!("Failed to read {}: {}", path.display(), error),
+        )
+    })
+}
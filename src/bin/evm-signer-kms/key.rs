@@ -0,0 +1,61 @@
+use std::io::{Error, ErrorKind};
+
+use aws_config::{sts::AssumeRoleProvider, SdkConfig};
+use clap::Args;
+use evm_signer_kms::evm_account::kms_key::{aws_config_loader, KmsKey};
+
+use crate::config::Config;
+
+// Name of the session of the assumed role, as shown in CloudTrail.
+const ROLE_SESSION_NAME: &str = "evm-signer-kms";
+
+/// Selection of the KMS key and the credentials used to access it.
+#[derive(Args, Debug)]
+pub struct KeyArgs {
+    /// ID, ARN or alias of the KMS key. Defaults to `key_id` of the configuration file.
+    #[arg(long, env = "KMS_KEY_ID")]
+    pub key_id: Option<String>,
+    /// ARN of the IAM role assumed to use the key. Credentials from the environment are used
+    /// otherwise.
+    #[arg(long, env = "KMS_ROLE_ARN")]
+    pub role_arn: Option<String>,
+}
+
+impl KeyArgs {
+    /// Creates the KMS key, assuming the role if one is selected. Settings missing from the
+    /// command line are taken from the configuration.
+    pub async fn kms_key<'a>(&'a self, config: &'a Config) -> Result<KmsKey<'a>, Error> {
+        let key_id = self.key_id(config)?;
+        let role_arn = self.role_arn.as_deref().or(config.role_arn.as_deref());
+
+        Ok(KmsKey::with_config(key_id, load_aws_config(role_arn).await))
+    }
+
+    /// Returns the selected key ID, falling back to the one in the configuration.
+    pub fn key_id<'a>(&'a self, config: &'a Config) -> Result<&'a str, Error> {
+        self.key_id
+            .as_deref()
+            .or(config.key_id.as_deref())
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "No KMS key ID selected"))
+    }
+}
+
+/// Loads the AWS configuration from the environment, with credentials of the role if one is
+/// selected.
+pub async fn load_aws_config(role_arn: Option<&str>) -> SdkConfig {
+    let base_config = aws_config_loader().load().await;
+    let Some(role_arn) = role_arn else {
+        return base_config;
+    };
+
+    let credentials_provider = AssumeRoleProvider::builder(role_arn)
+        .session_name(ROLE_SESSION_NAME)
+        .configure(&base_config)
+        .build()
+        .await;
+
+    aws_config_loader()
+        .credentials_provider(credentials_provider)
+        .load()
+        .await
+}
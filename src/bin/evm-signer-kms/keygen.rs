@@ -0,0 +1,169 @@
+use std::{
+    fs,
+    io::{Error, ErrorKind},
+    path::PathBuf,
+    time::Duration,
+};
+
+use aws_sdk_kms::{
+    types::{KeySpec, KeyState, KeyUsageType, Tag},
+    Client,
+};
+use clap::Args;
+use evm_signer_kms::evm_account::{kms_key::KmsKey, EvmAccount};
+
+use crate::{config::Config, key::load_aws_config};
+
+// Prefix of KMS key alias names.
+const ALIAS_PREFIX: &str = "alias/";
+const KEY_STATE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const KEY_STATE_POLL_ATTEMPTS: u32 = 60;
+
+#[derive(Args, Debug)]
+pub struct KeygenArgs {
+    /// Alias of the new key, with or without the `alias/` prefix.
+    #[arg(long)]
+    alias: Option<String>,
+    /// Description of the new key.
+    #[arg(long)]
+    description: Option<String>,
+    /// Tag of the new key as `KEY=VALUE`. Can be repeated.
+    #[arg(long = "tag", value_parser = parse_tag)]
+    tags: Vec<(String, String)>,
+    /// File with the key policy JSON. The default KMS key policy is used otherwise.
+    #[arg(long)]
+    policy_file: Option<PathBuf>,
+    /// ARN of the IAM role assumed to create the key. Credentials from the environment are used
+    /// otherwise.
+    #[arg(long, env = "KMS_ROLE_ARN")]
+    role_arn: Option<String>,
+}
+
+pub async fn run(args: KeygenArgs, config: &Config) -> Result<(), Error> {
+    let policy = args
+        .policy_file
+        .as_deref()
+        .map(fs::read_to_string)
+        .transpose()?;
+    let tags = args
+        .tags
+        .into_iter()
+        .map(|(key, value)| Tag::builder().tag_key(key).tag_value(value).build())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?;
+
+    let role_arn = args.role_arn.as_deref().or(config.role_arn.as_deref());
+    let aws_config = load_aws_config(role_arn).await;
+    let client = Client::new(&aws_config);
+
+    let key_metadata = client
+        .create_key()
+        .key_spec(KeySpec::EccSecgP256K1)
+        .key_usage(KeyUsageType::SignVerify)
+        .set_description(args.description)
+        .set_policy(policy)
+        .set_tags(Some(tags).filter(|tags| !tags.is_empty()))
+        .send()
+        .await
+        .map_err(|error| {
+            Error::new(
+                ErrorKind::PermissionDenied,
+                format!("Error creating key: {:?}", error),
+            )
+        })?
+        .key_metadata
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "Invalid response. No key created"))?;
+    let key_id = key_metadata.key_id().to_string();
+
+    if let Some(alias) = args.alias {
+        client
+            .create_alias()
+            .alias_name(alias_name(&alias))
+            .target_key_id(&key_id)
+            .send()
+            .await
+            .map_err(|error| {
+                Error::new(
+                    ErrorKind::PermissionDenied,
+                    format!("Error creating alias of key {}: {:?}", key_id, error),
+                )
+            })?;
+    }
+
+    wait_until_enabled(&client, &key_id).await?;
+
+    let kms_key = KmsKey::with_config(&key_id, aws_config.clone());
+    let evm_account = EvmAccount::new(&kms_key).await?;
+
+    println!("Key ARN: {}", key_metadata.arn().unwrap_or(key_id.as_str()));
+    println!("Address: {}", evm_account.address());
+
+    Ok(())
+}
+
+async fn wait_until_enabled(client: &Client, key_id: &str) -> Result<(), Error> {
+    for _ in 0..KEY_STATE_POLL_ATTEMPTS {
+        let key_state = client
+            .describe_key()
+            .key_id(key_id)
+            .send()
+            .await
+            .map_err(|error| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("Error describing key {}: {:?}", key_id, error),
+                )
+            })?
+            .key_metadata
+            .and_then(|key_metadata| key_metadata.key_state);
+
+        if key_state == Some(KeyState::Enabled) {
+            return Ok(());
+        }
+        tokio::time::sleep(KEY_STATE_POLL_INTERVAL).await;
+    }
+
+    Err(Error::new(
+        ErrorKind::TimedOut,
+        format!("Key {} did not become enabled", key_id),
+    ))
+}
+
+fn alias_name(alias: &str) -> String {
+    if alias.starts_with(ALIAS_PREFIX) {
+        alias.to_string()
+    } else {
+        format!("{}{}", ALIAS_PREFIX, alias)
+    }
+}
+
+fn parse_tag(tag: &str) -> Result<(String, String), String> {
+    tag.split_once('=')
+        .filter(|(key, _)| !key.is_empty())
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .ok_or_else(|| format!("Invalid tag `{}`, expected KEY=VALUE", tag))
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn alias_name_succeed() {
+        assert_eq!("alias/treasury", alias_name("treasury"));
+        assert_eq!("alias/treasury", alias_name("alias/treasury"));
+    }
+
+    #[test]
+    fn parse_tag_succeed() {
+        let right = parse_tag("team=payments=eu").unwrap();
+
+        assert_eq!(("team".to_string(), "payments=eu".to_string()), right);
+    }
+
+    #[test]
+    fn parse_tag_fail() {
+        assert!(parse_tag("team").is_err());
+        assert!(parse_tag("=payments").is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! Command line interface signing EVM transactions with keys stored in AWS KMS.
+
+use std::{io::Error, path::PathBuf, process::ExitCode};
+
+use clap::{Parser, Subcommand};
+use config::Config;
+
+mod address;
+mod broadcast;
+mod config;
+mod decode;
+mod input;
+mod key;
+mod keygen;
+mod message;
+#[cfg(feature = "server")]
+mod serve;
+mod sign;
+mod stream;
+
+/// Signs EVM transactions with keys stored in AWS KMS.
+#[derive(Debug, Parser)]
+#[command(name = "evm-signer-kms", version, about)]
+struct Cli {
+    /// TOML configuration file with defaults of the options.
+    #[arg(long, global = true, env = "EVM_SIGNER_KMS_CONFIG")]
+    config: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Prints the checksummed address of the KMS key.
+    Address(address::AddressArgs),
+    /// Submits a raw signed transaction and prints its hash.
+    Broadcast(broadcast::BroadcastArgs),
+    /// Prints the fields, sender, hash and maximum cost of a raw signed transaction.
+    Decode(decode::DecodeArgs),
+    /// Creates a new secp256k1 signing key in KMS and prints its ARN and address.
+    Keygen(keygen::KeygenArgs),
+    /// Serves the HTTP signing API.
+    #[cfg(feature = "server")]
+    Serve(serve::ServeArgs),
+    /// Signs a transaction JSON and prints the raw signed transaction in hex.
+    Sign(sign::SignArgs),
+    /// Signs a message according to EIP-191 (i.e. `personal_sign`) and prints the signature.
+    SignMessage(message::SignMessageArgs),
+    /// Signs newline-delimited transaction JSON from the standard input and prints the raw
+    /// signed transactions line by line.
+    SignStream(stream::SignStreamArgs),
+    /// Signs typed data JSON according to EIP-712 (i.e. `eth_signTypedData_v4`) and prints the
+    /// signature.
+    SignTypedData(message::SignTypedDataArgs),
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), Error> {
+    let config = Config::load(cli.config.as_deref())?;
+
+    match cli.command {
+        Command::Address(args) => address::run(args, &config).await,
+        Command::Broadcast(args) => broadcast::run(args, &config).await,
+        Command::Decode(args) => decode::run(args).await,
+        Command::Keygen(args) => keygen::run(args, &config).await,
+        #[cfg(feature = "server")]
+        Command::Serve(args) => serve::run(args, &config).await,
+        Command::Sign(args) => sign::run(args, &config).await,
+        Command::SignMessage(args) => message::sign_message(args, &config).await,
+        Command::SignStream(args) => stream::run(args, &config).await,
+        Command::SignTypedData(args) => message::sign_typed_data(args, &config).await,
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn cli_definition_succeed() {
+        Cli::command().debug_assert();
+    }
+}
@@ -0,0 +1,129 @@
+use std::{io::Error, path::PathBuf};
+
+use clap::{Args, ValueEnum};
+use evm_signer_kms::evm_account::{
+    signature::Signature, transaction::Bytes, typed_data::TypedData, EvmAccount,
+};
+
+use crate::{config::Config, input, key::KeyArgs};
+
+#[derive(Args, Debug)]
+pub struct SignMessageArgs {
+    #[command(flatten)]
+    key: KeyArgs,
+    /// File with the message, or `-` for the standard input.
+    #[arg(default_value = "-")]
+    input: PathBuf,
+    /// Treat the message as hex encoded bytes rather than text.
+    #[arg(long)]
+    hex: bool,
+    /// Format of the printed signature.
+    #[arg(long, value_enum, default_value_t = SignatureFormat::Hex)]
+    format: SignatureFormat,
+}
+
+#[derive(Args, Debug)]
+pub struct SignTypedDataArgs {
+    #[command(flatten)]
+    key: KeyArgs,
+    /// File with the typed data JSON, or `-` for the standard input.
+    #[arg(default_value = "-")]
+    input: PathBuf,
+    /// Format of the printed signature.
+    #[arg(long, value_enum, default_value_t = SignatureFormat::Hex)]
+    format: SignatureFormat,
+}
+
+/// Output formats of signatures, following the conventions of `cast`.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum SignatureFormat {
+    /// 65-byte `r || s || v` signature in hex, as printed by `cast wallet sign`.
+    Hex,
+    /// 64-byte [`EIP-2098`](https://eips.ethereum.org/EIPS/eip-2098) compact signature in hex.
+    Compact,
+    /// JSON object with the `r`, `s`, `v` and `yParity` components.
+    Json,
+}
+
+pub async fn sign_message(args: SignMessageArgs, config: &Config) -> Result<(), Error> {
+    let message = message_bytes(input::read_to_string(&args.input)?, args.hex)?;
+    let kms_key = args.key.kms_key(config).await?;
+    let evm_account = EvmAccount::new(&kms_key).await?;
+
+    let signature = evm_account.sign_message(message).await?;
+    println!("{}", format_signature(&signature, args.format));
+
+    Ok(())
+}
+
+pub async fn sign_typed_data(args: SignTypedDataArgs, config: &Config) -> Result<(), Error> {
+    let typed_data: TypedData = input::read_to_string(&args.input)?.parse()?;
+    let kms_key = args.key.kms_key(config).await?;
+    let evm_account = EvmAccount::new(&kms_key).await?;
+
+    let signature = evm_account.sign_typed_data(&typed_data).await?;
+    println!("{}", format_signature(&signature, args.format));
+
+    Ok(())
+}
+
+fn format_signature(signature: &Signature, format: SignatureFormat) -> String {
+    match format {
+        SignatureFormat::Hex => signature.to_string(),
+        SignatureFormat::Compact => {
+            Bytes::from(signature.to_compact_bytes().as_slice()).to_string()
+        }
+        SignatureFormat::Json => signature.to_json().to_string(),
+    }
+}
+
+// Text is signed as is, whereas hex messages are decoded ignoring surrounding whitespace.
+fn message_bytes(message: String, hex: bool) -> Result<Vec<u8>, Error> {
+    if hex {
+        return message.trim().parse::<Bytes>().map(|bytes| bytes.0);
+    }
+
+    Ok(message.into_bytes())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn message_bytes_text_succeed() {
+        let right = message_bytes("Hello World\n".to_string(), false).unwrap();
+
+        assert_eq!(b"Hello World\n".to_vec(), right);
+    }
+
+    #[test]
+    fn message_bytes_hex_succeed() {
+        let right = message_bytes("0xabcd\n".to_string(), true).unwrap();
+
+        assert_eq!(vec![0xab, 0xcd], right);
+    }
+
+    #[test]
+    fn format_signature_succeed() {
+        let input = Signature::new([0x11; 32], [0x22; 32], 1);
+
+        assert_eq!(
+            format!("0x{}{}1c", "11".repeat(32), "22".repeat(32)),
+            format_signature(&input, SignatureFormat::Hex)
+        );
+        assert_eq!(
+            format!("0x{}a2{}", "11".repeat(32), "22".repeat(31)),
+            format_signature(&input, SignatureFormat::Compact)
+        );
+        assert_eq!(
+            input.to_json().to_string(),
+            format_signature(&input, SignatureFormat::Json)
+        );
+    }
+
+    #[test]
+    fn message_bytes_hex_fail() {
+        assert!(message_bytes("Hello World".to_string(), true).is_err());
+    }
+}
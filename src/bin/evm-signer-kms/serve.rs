@@ -0,0 +1,397 @@
+use std::{
+    io::{Error, ErrorKind},
+    net::SocketAddr,
+    sync::Arc,
+};
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use clap::Args;
+use evm_signer_kms::evm_account::{
+    events::SigningContext,
+    hash::{eip191_hash_message, keccak256, Keccak256Digest},
+    kms_key::KmsKey,
+    policy::PolicyError,
+    transaction::{json::Mode, Bytes},
+    EvmAccount,
+};
+use futures_util::future;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::signal;
+
+/// Node signing methods over JSON-RPC, e.g. `eth_signTransaction`, for wallets and tools which
+/// delegate signing to a node.
+mod json_rpc;
+/// Request and signing metrics in the Prometheus text format, served at `/metrics`.
+mod metrics;
+/// Subset of the [Web3Signer](https://docs.web3signer.consensys.io) ETH1 REST API, so that its
+/// clients can use the service without modification.
+mod web3signer;
+
+use crate::{config::Config, key::KeyArgs, sign::ModeArg};
+
+// Headers of the correlation ID of signing requests, in order of precedence.
+const CORRELATION_ID_HEADERS: [&str; 2] = ["x-correlation-id", "x-request-id"];
+
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    #[command(flatten)]
+    key: KeyArgs,
+    /// Address and port to listen on.
+    #[arg(long, env = "LISTEN_ADDRESS", default_value = "127.0.0.1:8080")]
+    listen: SocketAddr,
+    /// Handling of fields which are not part of the transaction format.
+    #[arg(long, value_enum, default_value_t = ModeArg::Lenient)]
+    mode: ModeArg,
+    /// Also serve the node signing methods over JSON-RPC at `/`.
+    #[arg(long)]
+    json_rpc: bool,
+    /// Also serve the Web3Signer ETH1 API. It signs hashes of arbitrary data, which bypasses the
+    /// signing policy.
+    #[arg(long)]
+    web3signer: bool,
+}
+
+/// Account shared by the request handlers, along with what is reported in the audit log.
+struct Signer {
+    evm_account: EvmAccount<'static>,
+    kms_key: &'static KmsKey<'static>,
+    key_id: &'static str,
+    mode: Mode,
+    metrics: metrics::Metrics,
+}
+
+/// Body of `/sign-message` requests, with either a text `message` or hex encoded `data`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SignMessageRequest {
+    message: Option<String>,
+    data: Option<Bytes>,
+}
+
+impl SignMessageRequest {
+    fn into_bytes(self) -> Result<Vec<u8>, Error> {
+        match (self.message, self.data) {
+            (Some(message), None) => Ok(message.into_bytes()),
+            (None, Some(data)) => Ok(data.0),
+            _ => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Exactly one of message and data is required",
+            )),
+        }
+    }
+}
+
+/// Error responded with a status derived from its kind and a JSON body with the message.
+struct ApiError(Error);
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        Self(error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = Json(json!({ "error": self.0.to_string() }));
+
+        (status_code(self.0.kind()), body).into_response()
+    }
+}
+
+/// Serves the signing API until the process is interrupted or terminated, after which requests in
+/// flight are completed. The key and the policy are fixed at
+/// startup, so that clients of the service need no KMS permissions.
+pub async fn run(args: ServeArgs, config: &Config) -> Result<(), Error> {
+    // The account is borrowed by the handlers for the lifetime of the process.
+    let config: &'static Config = Box::leak(Box::new(config.clone()));
+    let key: &'static KeyArgs = Box::leak(Box::new(args.key));
+    let kms_key: &'static KmsKey = Box::leak(Box::new(key.kms_key(config).await?));
+    let signer = Signer {
+        evm_account: EvmAccount::with_policy(kms_key, config.signing_policy()).await?,
+        kms_key,
+        key_id: key.key_id(config)?,
+        mode: args.mode.into(),
+        metrics: metrics::Metrics::default(),
+    };
+
+    let listener = tokio::net::TcpListener::bind(args.listen).await?;
+    eprintln!(
+        "Serving {} on {}",
+        signer.evm_account.address(),
+        listener.local_addr()?
+    );
+
+    let mut router = router();
+    if args.json_rpc {
+        router = router.merge(json_rpc::routes());
+    }
+    if args.web3signer {
+        router = router.merge(web3signer::routes());
+    }
+
+    let signer = Arc::new(signer);
+    let router = router.layer(middleware::from_fn_with_state(
+        Arc::clone(&signer),
+        metrics::track,
+    ));
+
+    axum::serve(listener, router.with_state(signer))
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+}
+
+// Completes on Ctrl+C or, on Unix, on `SIGTERM` sent e.g. by Kubernetes to stop the pod.
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    let terminate = async {
+        match signal::unix::signal(signal::unix::SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(_) => future::pending().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = future::pending::<()>();
+
+    tokio::select! {
+        _ = signal::ctrl_c() => {}
+        _ = terminate => {}
+    }
+    eprintln!("Shutting down");
+}
+
+fn router() -> Router<Arc<Signer>> {
+    Router::new()
+        .route("/address", get(address))
+        .route("/healthz", get(livez))
+        .route("/livez", get(livez))
+        .route("/metrics", get(metrics::metrics))
+        .route("/readyz", get(readyz))
+        .route("/sign", post(sign))
+        .route("/sign-message", post(sign_message))
+}
+
+async fn address(State(signer): State<Arc<Signer>>) -> Json<Value> {
+    Json(json!({ "address": signer.evm_account.checksummed_address() }))
+}
+
+async fn livez() -> &'static str {
+    "ok"
+}
+
+/// The service is ready if KMS is reachable and the key is enabled. The public key is fetched at
+/// startup, so it is always cached by then.
+async fn readyz(State(signer): State<Arc<Signer>>) -> (StatusCode, Json<Value>) {
+    let error = match signer.kms_key.is_enabled().await {
+        Ok(true) => {
+            let address = signer.evm_account.checksummed_address();
+            return (
+                StatusCode::OK,
+                Json(json!({ "status": "ready", "address": address })),
+            );
+        }
+        Ok(false) => "Key is not enabled".to_string(),
+        Err(error) => error.to_string(),
+    };
+
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(json!({ "status": "unavailable", "error": error })),
+    )
+}
+
+async fn sign(
+    State(signer): State<Arc<Signer>>,
+    headers: HeaderMap,
+    tx_json: String,
+) -> Result<Json<Value>, ApiError> {
+    let context = signing_context(&headers);
+
+    let result = signer
+        .evm_account
+        .sign_transaction_json_with_context(&tx_json, signer.mode, &context)
+        .await
+        .map(Bytes);
+    let raw_tx = signer.audit("sign", &context, result, |raw_tx| keccak256(&raw_tx.0))?;
+    let tx_hash = keccak256(&raw_tx);
+
+    Ok(Json(json!({
+        "hash": Bytes::from(tx_hash.as_slice()).to_string(),
+        "rawTransaction": raw_tx.to_string(),
+    })))
+}
+
+async fn sign_message(
+    State(signer): State<Arc<Signer>>,
+    headers: HeaderMap,
+    Json(request): Json<SignMessageRequest>,
+) -> Result<Json<Value>, ApiError> {
+    let context = signing_context(&headers);
+    let message = request.into_bytes()?;
+    let digest = eip191_hash_message(&message);
+
+    let result = signer
+        .evm_account
+        .sign_digest_with_context(&digest, &context)
+        .await;
+    let signature = signer.audit("sign-message", &context, result, |_| digest)?;
+
+    Ok(Json(json!({ "signature": signature.to_string() })))
+}
+
+impl Signer {
+    /// Writes the outcome of the signing operation, with the correlation ID of the request, to the
+    /// audit log on the standard error, one JSON object per line, records it in the metrics and
+    /// passes the result through.
+    fn audit<T, F>(
+        &self,
+        operation: &str,
+        context: &SigningContext,
+        result: Result<T, Error>,
+        digest: F,
+    ) -> Result<T, Error>
+    where
+        F: FnOnce(&T) -> Keccak256Digest,
+    {
+        let mut entry = json!({
+            "operation": operation,
+            "keyId": self.key_id,
+            "address": self.evm_account.checksummed_address(),
+        });
+        if let Some(correlation_id) = &context.correlation_id {
+            entry["correlationId"] = correlation_id.as_str().into();
+        }
+        match &result {
+            Ok(signed) => {
+                entry["outcome"] = "signed".into();
+                entry["digest"] = Bytes::from(digest(signed).as_slice()).to_string().into();
+            }
+            Err(error) => {
+                entry["outcome"] = outcome(error).into();
+                entry["error"] = error.to_string().into();
+            }
+        }
+        eprintln!("{}", entry);
+        self.metrics
+            .record_signing(operation, result.as_ref().err());
+
+        result
+    }
+}
+
+/// Context of the signing request, with the correlation ID supplied by the client in the
+/// `X-Correlation-Id` or `X-Request-Id` header, if any.
+fn signing_context(headers: &HeaderMap) -> SigningContext {
+    CORRELATION_ID_HEADERS
+        .iter()
+        .find_map(|name| headers.get(*name)?.to_str().ok())
+        .map(SigningContext::with_correlation_id)
+        .unwrap_or_default()
+}
+
+// Policy violations are reported separately from other failures.
+fn outcome(error: &Error) -> &'static str {
+    match PolicyError::from_io_error(error) {
+        Some(_) => "rejected",
+        None => "failed",
+    }
+}
+
+fn status_code(kind: ErrorKind) -> StatusCode {
+    match kind {
+        ErrorKind::InvalidInput | ErrorKind::InvalidData | ErrorKind::Unsupported => {
+            StatusCode::BAD_REQUEST
+        }
+        ErrorKind::PermissionDenied => StatusCode::FORBIDDEN,
+        ErrorKind::NotFound => StatusCode::NOT_FOUND,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn sign_message_request_text_succeed() {
+        let input: SignMessageRequest =
+            serde_json::from_str(r#"{ "message": "Hello World" }"#).unwrap();
+
+        let right = input.into_bytes().unwrap();
+
+        assert_eq!(b"Hello World".to_vec(), right);
+    }
+
+    #[test]
+    fn sign_message_request_data_succeed() {
+        let input: SignMessageRequest = serde_json::from_str(r#"{ "data": "0xabcd" }"#).unwrap();
+
+        let right = input.into_bytes().unwrap();
+
+        assert_eq!(vec![0xab, 0xcd], right);
+    }
+
+    #[test]
+    fn sign_message_request_fail() {
+        let input: SignMessageRequest =
+            serde_json::from_str(r#"{ "message": "Hello World", "data": "0xabcd" }"#).unwrap();
+
+        let right = input.into_bytes().unwrap_err();
+
+        assert_eq!(ErrorKind::InvalidInput, right.kind());
+    }
+
+    #[test]
+    fn signing_context_succeed() {
+        let mut input = HeaderMap::new();
+        input.insert("x-request-id", "request-1234".parse().unwrap());
+        input.insert("x-correlation-id", "order-1234".parse().unwrap());
+
+        let right = signing_context(&input);
+
+        assert_eq!(Some("order-1234".to_string()), right.correlation_id);
+    }
+
+    #[test]
+    fn signing_context_no_header_succeed() {
+        let right = signing_context(&HeaderMap::new());
+
+        assert_eq!(SigningContext::default(), right);
+    }
+
+    #[test]
+    fn status_code_succeed() {
+        assert_eq!(StatusCode::BAD_REQUEST, status_code(ErrorKind::InvalidData));
+        assert_eq!(
+            StatusCode::FORBIDDEN,
+            status_code(ErrorKind::PermissionDenied)
+        );
+        assert_eq!(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            status_code(ErrorKind::Other)
+        );
+    }
+
+    #[test]
+    fn outcome_succeed() {
+        let input = Error::from(PolicyError::DataTooLong {
+            length: 2,
+            max_data_length: 1,
+        });
+
+        assert_eq!("rejected", outcome(&input));
+        assert_eq!(
+            "failed",
+            outcome(&Error::new(ErrorKind::PermissionDenied, "Key is disabled"))
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    io::{Error, ErrorKind},
+    sync::Arc,
+};
+
+use axum::{extract::State, http::HeaderMap, routing::post, Json, Router};
+use evm_signer_kms::evm_account::{
+    events::SigningContext,
+    hash::{eip191_hash_message, keccak256},
+    transaction::{AccountAddress, Bytes},
+    typed_data::TypedData,
+};
+use futures_util::future;
+use serde_json::{json, Map, Value};
+
+use super::{signing_context, Signer};
+
+// Error codes defined by the JSON-RPC 2.0 specification.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+// Code of errors of the signer itself, as used by nodes.
+const SERVER_ERROR: i64 = -32000;
+
+/// Error responded in the `error` member.
+#[derive(Debug, PartialEq)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<Error> for RpcError {
+    fn from(error: Error) -> Self {
+        let code = match error.kind() {
+            ErrorKind::InvalidInput | ErrorKind::InvalidData => INVALID_PARAMS,
+            _ => SERVER_ERROR,
+        };
+
+        Self::new(code, error.to_string())
+    }
+}
+
+/// Route of the API, which is merged into the router of the service.
+pub fn routes() -> Router<Arc<Signer>> {
+    Router::new().route("/", post(handle))
+}
+
+/// Handles a request or a batch of requests, as specified by JSON-RPC 2.0. Requests of a batch
+/// share the correlation ID of the HTTP request.
+async fn handle(
+    State(signer): State<Arc<Signer>>,
+    headers: HeaderMap,
+    body: String,
+) -> Json<Value> {
+    let context = signing_context(&headers);
+    let request = match serde_json::from_str(&body) {
+        Ok(request) => request,
+        Err(error) => {
+            let error = RpcError::new(PARSE_ERROR, error.to_string());
+            return Json(response(Value::Null, Err(error)));
+        }
+    };
+
+    match request {
+        Value::Array(requests) => {
+            let responses = requests
+                .into_iter()
+                .map(|request| handle_request(&signer, &context, request));
+            Json(Value::Array(future::join_all(responses).await))
+        }
+        request => Json(handle_request(&signer, &context, request).await),
+    }
+}
+
+async fn handle_request(signer: &Signer, context: &SigningContext, request: Value) -> Value {
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let (Some(method), params) = (
+        request.get("method").and_then(Value::as_str),
+        request.get("params"),
+    ) else {
+        return response(id, Err(RpcError::new(INVALID_REQUEST, "Missing method")));
+    };
+    let params = match params {
+        Some(Value::Array(params)) => params.as_slice(),
+        None => &[],
+        Some(_) => {
+            let error = RpcError::new(INVALID_PARAMS, "Params must be an array");
+            return response(id, Err(error));
+        }
+    };
+
+    response(id, call(signer, context, method, params).await)
+}
+
+async fn call(
+    signer: &Signer,
+    context: &SigningContext,
+    method: &str,
+    params: &[Value],
+) -> Result<Value, RpcError> {
+    let evm_account = &signer.evm_account;
+    let address = evm_account.address();
+
+    match method {
+        "eth_accounts" => Ok(json!([evm_account.checksummed_address()])),
+        "eth_sign" => {
+            check_account(param(params, 0)?, &address)?;
+            let message = parse_bytes(param(params, 1)?)?;
+            let digest = eip191_hash_message(&message);
+
+            let result = evm_account.sign_digest_with_context(&digest, context).await;
+            let signature = signer.audit(method, context, result, |_| digest)?;
+            Ok(signature.to_string().into())
+        }
+        "eth_signTransaction" => {
+            let tx = to_transaction_json(param(params, 0)?, &address)?;
+
+            let result = evm_account
+                .sign_transaction_json_with_context(&tx.to_string(), signer.mode, context)
+                .await
+                .map(Bytes);
+            let raw_tx = signer.audit(method, context, result, |raw_tx| keccak256(&raw_tx.0))?;
+            Ok(raw_tx.to_string().into())
+        }
+        "eth_signTypedData_v4" => {
+            check_account(param(params, 0)?, &address)?;
+            let typed_data = parse_typed_data(param(params, 1)?)?;
+            let digest = typed_data.signing_hash()?;
+
+            let result = evm_account.sign_digest_with_context(&digest, context).await;
+            let signature = signer.audit(method, context, result, |_| digest)?;
+            Ok(signature.to_string().into())
+        }
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Method {} is not supported", method),
+        )),
+    }
+}
+
+fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": error.code, "message": error.message },
+        }),
+    }
+}
+
+fn param(params: &[Value], index: usize) -> Result<&Value, RpcError> {
+    params
+        .get(index)
+        .ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("Missing parameter {}", index + 1)))
+}
+
+fn parse_bytes(param: &Value) -> Result<Vec<u8>, RpcError> {
+    let hex_data = param
+        .as_str()
+        .ok_or_else(|| RpcError::new(INVALID_PARAMS, "Data must be a hex string"))?;
+
+    Ok(hex_data.parse::<Bytes>()?.0)
+}
+
+// Typed data is passed either as a JSON string, as specified, or as an object by some clients.
+fn parse_typed_data(param: &Value) -> Result<TypedData, RpcError> {
+    let typed_data = match param {
+        Value::String(typed_data) => typed_data.parse()?,
+        typed_data => typed_data.to_string().parse()?,
+    };
+
+    Ok(typed_data)
+}
+
+/// Requests can only be signed by the account of the service.
+fn check_account(param: &Value, address: &AccountAddress) -> Result<(), RpcError> {
+    let account: AccountAddress = param
+        .as_str()
+        .ok_or_else(|| RpcError::new(INVALID_PARAMS, "Account must be an address string"))?
+        .parse()?;
+
+    if account != *address {
+        return Err(RpcError::new(
+            SERVER_ERROR,
+            format!("Unknown account {}", account),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Converts the transaction object of `eth_signTransaction` to the transaction JSON format, e.g.
+/// renames `gas` to `gasLimit` and fills in the fields nodes default.
+fn to_transaction_json(param: &Value, address: &AccountAddress) -> Result<Value, RpcError> {
+    let mut tx: Map<String, Value> = param
+        .as_object()
+        .cloned()
+        .ok_or_else(|| RpcError::new(INVALID_PARAMS, "Transaction must be an object"))?;
+
+    if let Some(from) = tx.remove("from") {
+        check_account(&from, address)?;
+    }
+    if let Some(gas) = tx.remove("gas") {
+        tx.entry("gasLimit").or_insert(gas);
+    }
+    if let Some(input) = tx.remove("input") {
+        tx.entry("data").or_insert(input);
+    }
+    tx.entry("to").or_insert(Value::Null);
+    tx.entry("value").or_insert_with(|| "0x0".into());
+    tx.entry("data").or_insert_with(|| "0x".into());
+    if tx.contains_key("maxFeePerGas") {
+        tx.entry("accessList").or_insert_with(|| json!([]));
+    }
+
+    Ok(Value::Object(tx))
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    const TEST_ADDRESS: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+
+    #[test]
+    fn to_transaction_json_succeed() {
+        let address: AccountAddress = TEST_ADDRESS.parse().unwrap();
+        let input = json!({
+            "from": TEST_ADDRESS.to_lowercase(),
+            "gas": "0x5208",
+            "maxFeePerGas": "0x174876e800",
+            "maxPriorityFeePerGas": "0xb2d05e00",
+            "chainId": "0xaa36a7",
+            "nonce": "0x7",
+            "input": "0xabcd",
+        });
+
+        let right = to_transaction_json(&input, &address).unwrap();
+
+        assert_eq!(
+            json!({
+                "gasLimit": "0x5208",
+                "maxFeePerGas": "0x174876e800",
+                "maxPriorityFeePerGas": "0xb2d05e00",
+                "chainId": "0xaa36a7",
+                "nonce": "0x7",
+                "to": null,
+                "value": "0x0",
+                "data": "0xabcd",
+                "accessList": [],
+            }),
+            right
+        );
+    }
+
+    #[test]
+    fn to_transaction_json_unknown_account_fail() {
+        let address: AccountAddress = TEST_ADDRESS.parse().unwrap();
+        let input = json!({ "from": "0x0000000000000000000000000000000000000001" });
+
+        let right = to_transaction_json(&input, &address).unwrap_err();
+
+        assert_eq!(SERVER_ERROR, right.code);
+    }
+
+    #[test]
+    fn parse_typed_data_fail() {
+        let right = parse_typed_data(&json!("{}")).unwrap_err();
+
+        assert_eq!(INVALID_PARAMS, right.code);
+    }
+
+    #[test]
+    fn response_error_succeed() {
+        let input = RpcError::new(METHOD_NOT_FOUND, "Method eth_call is not supported");
+
+        let right = response(json!(1), Err(input));
+
+        assert_eq!(METHOD_NOT_FOUND, right["error"]["code"]);
+        assert_eq!(1, right["id"]);
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    io::{Error, ErrorKind},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::header::CONTENT_TYPE,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use super::{outcome, Signer};
+
+const CONTENT_TYPE_TEXT: &str = "text/plain; version=0.0.4";
+// Label of requests which matched no route, so that arbitrary paths do not create series
+const UNMATCHED_PATH: &str = "unmatched";
+// Upper bounds of the request duration buckets in seconds, as of the Prometheus client libraries
+const DURATION_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Request and signing metrics of the service, exposed at `/metrics` in the Prometheus text format.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    // By route and status code
+    requests: BTreeMap<(String, u16), u64>,
+    // By route
+    durations: BTreeMap<String, Histogram>,
+    // By operation and outcome
+    signings: BTreeMap<(String, &'static str), u64>,
+    // By operation and error kind, including policy denials
+    errors: BTreeMap<(String, String), u64>,
+}
+
+#[derive(Debug, Default)]
+struct Histogram {
+    buckets: [u64; DURATION_BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        for (bucket, upper_bound) in self.buckets.iter_mut().zip(DURATION_BUCKETS) {
+            if seconds <= upper_bound {
+                *bucket += 1;
+            }
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+impl Metrics {
+    /// Records the request to the route, which was responded with the status after `duration`.
+    pub fn record_request(&self, path: &str, status: u16, duration: Duration) {
+        let mut inner = self.lock();
+        *inner
+            .requests
+            .entry((path.to_string(), status))
+            .or_default() += 1;
+        inner
+            .durations
+            .entry(path.to_string())
+            .or_default()
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Records the outcome of the signing operation, along with the kind of its error, if any.
+    pub fn record_signing(&self, operation: &str, error: Option<&Error>) {
+        let mut inner = self.lock();
+        let outcome = error.map_or("signed", outcome);
+        *inner
+            .signings
+            .entry((operation.to_string(), outcome))
+            .or_default() += 1;
+        if let Some(error) = error {
+            *inner
+                .errors
+                .entry((operation.to_string(), error_class(error.kind())))
+                .or_default() += 1;
+        }
+    }
+
+    /// Renders the metrics in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let inner = self.lock();
+        let mut text = String::new();
+
+        header(
+            &mut text,
+            "evm_signer_kms_http_requests_total",
+            "counter",
+            "HTTP requests by route and status code.",
+        );
+        for ((path, status), count) in &inner.requests {
+            let _ = writeln!(
+                text,
+                "evm_signer_kms_http_requests_total{{path=\"{}\",status=\"{}\"}} {}",
+                escape(path),
+                status,
+                count
+            );
+        }
+
+        header(
+            &mut text,
+            "evm_signer_kms_http_request_duration_seconds",
+            "histogram",
+            "Duration of HTTP requests by route.",
+        );
+        for (path, histogram) in &inner.durations {
+            let path = escape(path);
+            for (count, upper_bound) in histogram.buckets.iter().zip(DURATION_BUCKETS) {
+                let _ = writeln!(
+                    text,
+                    "evm_signer_kms_http_request_duration_seconds_bucket{{path=\"{}\",le=\"{}\"}} {}",
+                    path, upper_bound, count
+                );
+            }
+            let _ = writeln!(
+                text,
+                "evm_signer_kms_http_request_duration_seconds_bucket{{path=\"{}\",le=\"+Inf\"}} {}",
+                path, histogram.count
+            );
+            let _ = writeln!(
+                text,
+                "evm_signer_kms_http_request_duration_seconds_sum{{path=\"{}\"}} {}",
+                path, histogram.sum
+            );
+            let _ = writeln!(
+                text,
+                "evm_signer_kms_http_request_duration_seconds_count{{path=\"{}\"}} {}",
+                path, histogram.count
+            );
+        }
+
+        header(
+            &mut text,
+            "evm_signer_kms_signings_total",
+            "counter",
+            "Signing operations by outcome, i.e. signed, rejected by the policy or failed.",
+        );
+        for ((operation, outcome), count) in &inner.signings {
+            let _ = writeln!(
+                text,
+                "evm_signer_kms_signings_total{{operation=\"{}\",outcome=\"{}\"}} {}",
+                escape(operation),
+                outcome,
+                count
+            );
+        }
+
+        header(
+            &mut text,
+            "evm_signer_kms_signing_errors_total",
+            "counter",
+            "Unsuccessful signing operations by error kind, e.g. permission_denied for policy \
+             violations and KMS access denials.",
+        );
+        for ((operation, kind), count) in &inner.errors {
+            let _ = writeln!(
+                text,
+                "evm_signer_kms_signing_errors_total{{operation=\"{}\",kind=\"{}\"}} {}",
+                escape(operation),
+                kind,
+                count
+            );
+        }
+
+        text
+    }
+
+    // Metrics are only updated with the lock held, so they are consistent after a panic
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Serves the metrics of the service.
+pub async fn metrics(State(signer): State<Arc<Signer>>) -> Response {
+    ([(CONTENT_TYPE, CONTENT_TYPE_TEXT)], signer.metrics.render()).into_response()
+}
+
+/// Middleware recording the route, status code and duration of every request.
+pub async fn track(State(signer): State<Arc<Signer>>, request: Request, next: Next) -> Response {
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or(UNMATCHED_PATH.to_string(), |path| path.as_str().to_string());
+    let started = Instant::now();
+
+    let response = next.run(request).await;
+    signer
+        .metrics
+        .record_request(&path, response.status().as_u16(), started.elapsed());
+
+    response
+}
+
+fn header(text: &mut String, name: &str, metric_type: &str, help: &str) {
+    let _ = writeln!(text, "# HELP {} {}", name, help);
+    let _ = writeln!(text, "# TYPE {} {}", name, metric_type);
+}
+
+// Snake case name of the error kind, e.g. `permission_denied`
+fn error_class(kind: ErrorKind) -> String {
+    let mut class = String::new();
+    for (index, character) in format!("{:?}", kind).chars().enumerate() {
+        if character.is_uppercase() && index > 0 {
+            class.push('_');
+        }
+        class.push(character.to_ascii_lowercase());
+    }
+
+    class
+}
+
+fn escape(label_value: &str) -> String {
+    label_value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use evm_signer_kms::evm_account::policy::PolicyError;
+
+    #[test]
+    fn render_requests_succeed() {
+        let input = Metrics::default();
+        input.record_request("/sign", 200, Duration::from_millis(30));
+        input.record_request("/sign", 200, Duration::from_millis(300));
+
+        let right = input.render();
+
+        assert!(
+            right.contains("evm_signer_kms_http_requests_total{path=\"/sign\",status=\"200\"} 2")
+        );
+        assert!(right.contains(
+            "evm_signer_kms_http_request_duration_seconds_bucket{path=\"/sign\",le=\"0.05\"} 1"
+        ));
+        assert!(right.contains(
+            "evm_signer_kms_http_request_duration_seconds_bucket{path=\"/sign\",le=\"+Inf\"} 2"
+        ));
+        assert!(
+            right.contains("evm_signer_kms_http_request_duration_seconds_count{path=\"/sign\"} 2")
+        );
+    }
+
+    #[test]
+    fn render_signings_succeed() {
+        let input = Metrics::default();
+        input.record_signing("sign", None);
+        input.record_signing("sign", Some(&PolicyError::ChainIdNotPermitted(1).into()));
+
+        let right = input.render();
+
+        assert!(right
+            .contains("evm_signer_kms_signings_total{operation=\"sign\",outcome=\"signed\"} 1"));
+        assert!(right
+            .contains("evm_signer_kms_signings_total{operation=\"sign\",outcome=\"rejected\"} 1"));
+        assert!(right.contains(
+            "evm_signer_kms_signing_errors_total{operation=\"sign\",kind=\"permission_denied\"} 1"
+        ));
+    }
+
+    #[test]
+    fn error_class_succeed() {
+        assert_eq!(
+            "permission_denied",
+            error_class(ErrorKind::PermissionDenied)
+        );
+        assert_eq!("other", error_class(ErrorKind::Other));
+    }
+
+    #[test]
+    fn escape_succeed() {
+        assert_eq!("a\\\"b\\\\c\\n", escape("a\"b\\c\n"));
+    }
+}
@@ -0,0 +1,121 @@
+use std::{
+    io::{Error, ErrorKind},
+    sync::Arc,
+};
+
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    routing::{get, post},
+    Json, Router,
+};
+use evm_signer_kms::evm_account::{
+    hash::keccak256,
+    transaction::{AccountAddress, Bytes},
+};
+use serde::Deserialize;
+
+use super::{signing_context, ApiError, Signer};
+
+// SEC1 prefix of uncompressed public keys.
+const UNCOMPRESSED_PREFIX: &str = "04";
+
+/// Body of signing requests.
+#[derive(Debug, Deserialize)]
+struct SignRequest {
+    data: Bytes,
+}
+
+/// Routes of the API, which are merged into the router of the service.
+pub fn routes() -> Router<Arc<Signer>> {
+    Router::new()
+        .route("/upcheck", get(upcheck))
+        .route("/api/v1/eth1/publicKeys", get(public_keys))
+        .route("/api/v1/eth1/sign/:identifier", post(sign))
+}
+
+async fn upcheck() -> &'static str {
+    "OK"
+}
+
+async fn public_keys(State(signer): State<Arc<Signer>>) -> Json<Vec<String>> {
+    Json(vec![public_key_hex(&signer.evm_account.public_key)])
+}
+
+/// Signs the Keccak-256 hash of `data` and responds with the signature in hex.
+async fn sign(
+    State(signer): State<Arc<Signer>>,
+    Path(identifier): Path<String>,
+    headers: HeaderMap,
+    Json(request): Json<SignRequest>,
+) -> Result<String, ApiError> {
+    let evm_account = &signer.evm_account;
+    if !matches_identifier(&identifier, &evm_account.public_key, &evm_account.address()) {
+        return Err(Error::new(
+            ErrorKind::NotFound,
+            format!("No key matches the identifier {}", identifier),
+        )
+        .into());
+    }
+    let digest = keccak256(&request.data.0);
+
+    let context = signing_context(&headers);
+
+    let result = evm_account
+        .sign_digest_with_context(&digest, &context)
+        .await;
+    let signature = signer.audit("web3signer-sign", &context, result, |_| digest)?;
+
+    Ok(signature.to_string())
+}
+
+fn public_key_hex(public_key: &[u8]) -> String {
+    Bytes::from(public_key).to_string()
+}
+
+/// The key is identified by its public key, with or without the SEC1 prefix, or its address.
+fn matches_identifier(identifier: &str, public_key: &[u8], address: &AccountAddress) -> bool {
+    let identifier = identifier.trim_start_matches("0x").to_lowercase();
+    let public_key = hex::encode(public_key);
+
+    identifier == public_key
+        || identifier.strip_prefix(UNCOMPRESSED_PREFIX) == Some(public_key.as_str())
+        || identifier == hex::encode(address.as_slice())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use evm_signer_kms::evm_account::hash::address_from_public_key;
+
+    const TEST_PUBLIC_KEY: [u8; 64] = [0xab; 64];
+
+    #[test]
+    fn matches_identifier_succeed() {
+        let address = address_from_public_key(&TEST_PUBLIC_KEY);
+        let public_key = public_key_hex(&TEST_PUBLIC_KEY);
+
+        assert!(matches_identifier(&public_key, &TEST_PUBLIC_KEY, &address));
+        assert!(matches_identifier(
+            &format!("0x04{}", &public_key[2..]),
+            &TEST_PUBLIC_KEY,
+            &address
+        ));
+        assert!(matches_identifier(
+            &address.to_string(),
+            &TEST_PUBLIC_KEY,
+            &address
+        ));
+    }
+
+    #[test]
+    fn matches_identifier_fail() {
+        let address = address_from_public_key(&TEST_PUBLIC_KEY);
+
+        assert!(!matches_identifier(
+            &public_key_hex(&[0xcd; 64]),
+            &TEST_PUBLIC_KEY,
+            &address
+        ));
+    }
+}
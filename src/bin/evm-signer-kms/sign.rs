@@ -0,0 +1,77 @@
+use std::{
+    io::{Error, ErrorKind},
+    path::PathBuf,
+};
+
+use clap::{Args, ValueEnum};
+use evm_signer_kms::evm_account::{
+    transaction::{decode_to_json, json::Mode, Bytes},
+    EvmAccount,
+};
+
+use crate::{config::Config, input, key::KeyArgs};
+
+#[derive(Args, Debug)]
+pub struct SignArgs {
+    #[command(flatten)]
+    key: KeyArgs,
+    /// Transaction JSON file, or `-` for the standard input.
+    #[arg(default_value = "-")]
+    input: PathBuf,
+    /// Handling of fields which are not part of the transaction format.
+    #[arg(long, value_enum, default_value_t = ModeArg::Lenient)]
+    mode: ModeArg,
+    /// Print the signed transaction as a JSON object, like `cast` does with `--json`.
+    #[arg(long)]
+    json: bool,
+}
+
+/// Strictness of transaction JSON deserialization (see `json::Mode`).
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ModeArg {
+    /// Reject unknown fields.
+    Strict,
+    /// Ignore unknown fields.
+    Lenient,
+    /// Ignore unknown fields and accept amounts with units, e.g. `100 gwei`.
+    Tolerant,
+}
+
+impl From<ModeArg> for Mode {
+    fn from(mode: ModeArg) -> Self {
+        match mode {
+            ModeArg::Strict => Mode::Strict,
+            ModeArg::Lenient => Mode::Lenient,
+            ModeArg::Tolerant => Mode::Tolerant,
+        }
+    }
+}
+
+pub async fn run(args: SignArgs, config: &Config) -> Result<(), Error> {
+    let tx_json = input::read_to_string(&args.input)?;
+    let kms_key = args.key.kms_key(config).await?;
+    let evm_account = EvmAccount::with_policy(&kms_key, config.signing_policy()).await?;
+
+    let raw_tx = sign_json(&evm_account, &tx_json, args.mode.into()).await?;
+    if args.json {
+        let pretty_json = serde_json::to_string_pretty(&decode_to_json(&raw_tx.0)?)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+        println!("{}", pretty_json);
+    } else {
+        println!("{}", raw_tx);
+    }
+
+    Ok(())
+}
+
+/// Signs the transaction JSON of any supported type and returns the raw signed transaction.
+pub async fn sign_json(
+    evm_account: &EvmAccount<'_>,
+    tx_json: &str,
+    mode: Mode,
+) -> Result<Bytes, Error> {
+    evm_account
+        .sign_transaction_json(tx_json, mode)
+        .await
+        .map(Bytes)
+}
@@ -0,0 +1,106 @@
+use std::{cell::RefCell, collections::VecDeque, io::Error, num::NonZeroUsize};
+
+use clap::Args;
+use evm_signer_kms::evm_account::{
+    batch::BatchOptions,
+    transaction::{decode_to_json, Bytes},
+    EvmAccount,
+};
+use futures_util::{future, stream, StreamExt};
+use tokio::io::{self, AsyncBufReadExt, BufReader};
+
+use crate::{config::Config, key::KeyArgs, sign::ModeArg};
+
+#[derive(Args, Debug)]
+pub struct SignStreamArgs {
+    #[command(flatten)]
+    key: KeyArgs,
+    /// Handling of fields which are not part of the transaction format.
+    #[arg(long, value_enum, default_value_t = ModeArg::Lenient)]
+    mode: ModeArg,
+    /// Maximum number of transactions being signed at the same time.
+    #[arg(long, default_value = "8")]
+    concurrency: NonZeroUsize,
+    /// Maximum number of KMS signing requests per second, or 0 for no cap.
+    #[arg(long, default_value = "0")]
+    max_requests_per_second: u32,
+    /// Print each signed transaction as a single-line JSON object instead of the raw encoding.
+    #[arg(long)]
+    json: bool,
+}
+
+/// Signs newline-delimited transaction JSON from the standard input and prints the raw signed
+/// transactions one per line, in the order of the input. Blank lines are skipped and signing
+/// stops at the first failure.
+pub async fn run(args: SignStreamArgs, config: &Config) -> Result<(), Error> {
+    let kms_key = args.key.kms_key(config).await?;
+    let evm_account = EvmAccount::with_policy(&kms_key, config.signing_policy()).await?;
+    let options = BatchOptions::new()
+        .with_concurrency(args.concurrency.get())
+        .with_max_requests_per_second(args.max_requests_per_second);
+    // Line numbers of the transactions being signed, as results are yielded in the same order
+    let line_numbers = RefCell::new(VecDeque::new());
+
+    let lines = stream::unfold(
+        BufReader::new(io::stdin()).lines(),
+        |mut lines| async move {
+            lines
+                .next_line()
+                .await
+                .transpose()
+                .map(|line| (line, lines))
+        },
+    );
+    let tx_jsons = lines
+        .enumerate()
+        .filter(|(_, line)| future::ready(!is_blank(line)))
+        .map(|(index, line)| {
+            line_numbers.borrow_mut().push_back(index + 1);
+            line
+        });
+    let raw_txs = evm_account.sign_transactions_json_stream(tx_jsons, args.mode.into(), &options);
+    futures_util::pin_mut!(raw_txs);
+
+    while let Some(raw_tx) = raw_txs.next().await {
+        let line_number = line_numbers.borrow_mut().pop_front().unwrap_or_default();
+        let raw_tx = Bytes(raw_tx.map_err(|error| with_line_number(line_number, error))?);
+        if args.json {
+            println!("{}", decode_to_json(&raw_tx.0)?);
+        } else {
+            println!("{}", raw_tx);
+        }
+    }
+
+    Ok(())
+}
+
+fn is_blank(line: &Result<String, Error>) -> bool {
+    matches!(line, Ok(line) if line.trim().is_empty())
+}
+
+fn with_line_number(line_number: usize, error: Error) -> Error {
+    Error::new(error.kind(), format!("Line {}: {}", line_number, error))
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn is_blank_succeed() {
+        assert!(is_blank(&Ok(" \t".to_string())));
+        assert!(!is_blank(&Ok("{}".to_string())));
+        assert!(!is_blank(&Err(Error::from(ErrorKind::InvalidData))));
+    }
+
+    #[test]
+    fn with_line_number_succeed() {
+        let input = Error::new(ErrorKind::InvalidData, "Missing field");
+
+        let right = with_line_number(3, input);
+
+        assert_eq!(ErrorKind::InvalidData, right.kind());
+        assert_eq!("Line 3: Missing field", right.to_string());
+    }
+}
@@ -0,0 +1,5 @@
+// Generates the Kotlin and Swift bindings of the `mobile` module, e.g.:
+// cargo run --features uniffi --bin uniffi-bindgen -- generate --library <lib> --language kotlin
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
@@ -1,44 +1,134 @@
-use std::{cmp::Ordering, io};
-
-use asn1::{BigInt, BitString, ParseError, Sequence};
-use eip2::wrap_s;
-use secp256k1::{
-    ecdsa::{RecoverableSignature, RecoveryId},
-    Message, Secp256k1,
+#[cfg(feature = "aws")]
+use {
+    asn1::{BigInt, BitString, ParseError, Sequence},
+    eip2::canonicalize,
+    futures_util::future,
+    std::{io, time::Instant},
+    subtle::ConstantTimeEq,
 };
-use sha3::{Digest, Keccak256};
 
+/// Encoding of contract calls according to the Solidity ABI, e.g. to build transaction `data`.
+///
+/// Transaction builders leave the nonce at `0`, so it has to be set before signing (e.g. with
+/// `fill_nonce`).
+#[cfg(feature = "abi")]
+pub mod abi;
+#[cfg(feature = "alloy")]
+mod alloy;
+/// Durable ledger of signing attempts written to S3.
+#[cfg(feature = "audit-s3")]
+pub mod audit;
+/// Concurrent signing of transaction batches, with results in the order of the input.
+#[cfg(feature = "batch")]
+pub mod batch;
+/// Registry of known chains, e.g. their names, native currencies and supported transaction types.
+pub mod chains;
+mod ecdsa;
+#[cfg(feature = "aws")]
 mod eip2;
+/// Events published after signing attempts, e.g. to SNS or EventBridge for monitoring.
+pub mod events;
+/// Keccak-256 hashing utilities, e.g. deriving addresses and hashing signed messages.
+pub mod hash;
 /// Implements abstraction over secp256k1 key pair in AWS KMS.
+#[cfg(feature = "aws")]
 pub mod kms_key;
+/// Ready-made AWS Lambda handler signing transactions of the event payload.
+#[cfg(feature = "lambda")]
+pub mod lambda;
+/// Signing metrics in the CloudWatch Embedded Metric Format, e.g. for Lambda and ECS deployments.
+#[cfg(feature = "emf")]
+pub mod metrics;
+/// Rules verified against transactions before they are signed.
+pub mod policy;
+/// Client-side limit of the rate of KMS sign calls, e.g. to keep batch jobs below the KMS quota.
+#[cfg(feature = "rate-limit")]
+pub mod rate_limit;
+/// Ethereum JSON-RPC client, e.g. for broadcasting signed transactions.
+#[cfg(feature = "rpc")]
+pub mod rpc;
+// Timers and tasks of the async runtime selected with the `rt-tokio` or `rt-async-std` feature.
+#[cfg(any(feature = "rpc", feature = "rate-limit", feature = "audit-s3"))]
+mod runtime;
+/// Representation of recoverable signatures and their compact formats.
+pub mod signature;
+/// Cache of signatures by public key and digest, so that retried payloads are not signed again.
+#[cfg(feature = "signature-cache")]
+pub mod signature_cache;
+// Signature test vector, shared with the tests of the binary and the benchmarks
+#[cfg(test)]
+mod signature_vector {
+    include!("../tests/data/signature_vector.rs");
+}
 /// Module implementing representations of EVM transactions.
 pub mod transaction;
+/// Hashing of typed structured data according to [`EIP-712`](https://eips.ethereum.org/EIPS/eip-712).
+pub mod typed_data;
+/// Conversions between ether denominations, e.g. wei, gwei and ether.
+pub mod units;
+/// Adapter of the account to the signing hooks of the `web3` crate.
+#[cfg(feature = "web3")]
+pub mod web3;
 
-use kms_key::KmsKey;
-use transaction::{SignedTransaction, Transaction};
+#[cfg(feature = "aws")]
+use {
+    events::{SigningContext, SigningEvent, SigningOutcome},
+    hash::{address_from_public_key, eip191_hash_message, keccak256, Keccak256Digest},
+    kms_key::KmsKey,
+    policy::SigningPolicy,
+    serde_json::Value,
+    signature::{Signature, SignatureError},
+    transaction::{
+        access_list_transaction::AccessListTransaction,
+        free_market_transaction::{FeeSettings, FreeMarketTransaction},
+        json::{self, detect_tx_type, Mode},
+        legacy_transaction::LegacyTransaction,
+        AccountAddress, SignedTransaction, Transaction, TxType, MIN_FEE_BUMP_PERCENT,
+    },
+    typed_data::TypedData,
+};
 
+// Type identifier, which is not part of the transaction formats.
+#[cfg(feature = "aws")]
+const TYPE_FIELD: &str = "type";
 const PUBLIC_KEY_LENGTH: usize = 64;
 const KECCAK_256_LENGTH: usize = 32;
 const SIGNATURE_COMPONENT_LENGTH: usize = 32;
 
 type PublicKey = [u8; PUBLIC_KEY_LENGTH];
-type Keccak256Digest = [u8; KECCAK_256_LENGTH];
 type SignatureComponent = [u8; SIGNATURE_COMPONENT_LENGTH];
 
-fn keccak256_digest(data: &[u8]) -> Keccak256Digest {
-    Into::<Keccak256Digest>::into(Keccak256::digest(data))
-}
-
 /// Representation of EVM account for signing transactions with AWS KMS keys.
+#[cfg(feature = "aws")]
 pub struct EvmAccount<'a> {
     /// Raw, uncompressed 64-byte public key derived from the private key stored in KMS.
     ///
     /// The key is eagerly decoded during the account instantiation and is used for signature
     /// verification during transaction signing.
     pub public_key: PublicKey,
+    // Derived from the public key once, as policy checks, events and logs request it constantly
+    address: AccountAddress,
+    checksummed_address: String,
     kms_key: &'a KmsKey<'a>,
+    policy: SigningPolicy,
+    #[cfg(feature = "notifications")]
+    notifier: Option<events::Notifier>,
+    #[cfg(feature = "emf")]
+    metrics: Option<metrics::EmfSink>,
+    #[cfg(any(feature = "tracing", feature = "emf"))]
+    slow_call_threshold: Option<std::time::Duration>,
+    #[cfg(feature = "audit-s3")]
+    audit_sink: Option<std::sync::Arc<audit::S3AuditSink>>,
+    #[cfg(feature = "rate-limit")]
+    rate_limiter: Option<std::sync::Arc<rate_limit::RateLimiter>>,
+    #[cfg(feature = "signature-cache")]
+    signature_cache: Option<std::sync::Arc<signature_cache::SignatureCache>>,
+    // Chain enforced by the alloy `Signer` and `TxSigner` implementations
+    #[cfg(feature = "alloy")]
+    chain_id: Option<u64>,
 }
 
+#[cfg(feature = "aws")]
 impl<'a> EvmAccount<'a> {
     fn decode_public_key(public_key_blob: &[u8]) -> Result<PublicKey, io::Error> {
         // Nested closures to have only one error mapping routine
@@ -71,33 +161,174 @@ impl<'a> EvmAccount<'a> {
     /// The constructor eagerly decodes the uncompressed public key from the KMS key, strips the
     /// `0x04` uncompressed elliptic curve prefix and stores it in the `public_key` field.
     pub async fn new(kms_key: &'a KmsKey<'a>) -> Result<EvmAccount<'a>, io::Error> {
+        Self::with_policy(kms_key, SigningPolicy::default()).await
+    }
+
+    /// Constructor for `EvmAccount` which enforces the provided `SigningPolicy`.
+    ///
+    /// Every transaction is verified against the policy before it is sent to KMS for signing,
+    /// e.g. to restrict a key to a set of permitted chain IDs.
+    ///
+    /// The key is validated with `KmsKey::validate` while its public key is fetched, so that a
+    /// disabled key or one of the wrong spec fails here rather than on the first signature. This
+    /// requires the `kms:DescribeKey` permission.
+    pub async fn with_policy(
+        kms_key: &'a KmsKey<'a>,
+        policy: SigningPolicy,
+    ) -> Result<EvmAccount<'a>, io::Error> {
+        let (public_key_der, validation) =
+            future::join(kms_key.get_public_key(), kms_key.validate()).await;
+        validation?;
+
+        Self::from_public_key_der(kms_key, policy, &public_key_der?)
+    }
+
+    /// Constructor like `with_policy` which skips the validation of the key, i.e. makes a single
+    /// KMS request, e.g. to minimize the cold start latency of Lambda functions or for credentials
+    /// without the `kms:DescribeKey` permission.
+    pub async fn with_policy_unvalidated(
+        kms_key: &'a KmsKey<'a>,
+        policy: SigningPolicy,
+    ) -> Result<EvmAccount<'a>, io::Error> {
         let public_key_der = kms_key.get_public_key().await?;
-        let public_key = Self::decode_public_key(&public_key_der)?;
+
+        Self::from_public_key_der(kms_key, policy, &public_key_der)
+    }
+
+    fn from_public_key_der(
+        kms_key: &'a KmsKey<'a>,
+        policy: SigningPolicy,
+        public_key_der: &[u8],
+    ) -> Result<EvmAccount<'a>, io::Error> {
+        let public_key = Self::decode_public_key(public_key_der)?;
+        let address = address_from_public_key(&public_key);
 
         Ok(EvmAccount {
             public_key,
+            address,
+            checksummed_address: address.to_string(),
             kms_key,
+            policy,
+            #[cfg(feature = "notifications")]
+            notifier: None,
+            #[cfg(feature = "emf")]
+            metrics: None,
+            #[cfg(any(feature = "tracing", feature = "emf"))]
+            slow_call_threshold: None,
+            #[cfg(feature = "audit-s3")]
+            audit_sink: None,
+            #[cfg(feature = "rate-limit")]
+            rate_limiter: None,
+            #[cfg(feature = "signature-cache")]
+            signature_cache: None,
+            #[cfg(feature = "alloy")]
+            chain_id: None,
         })
     }
 
-    fn to_signature_component(decoded_data: &[u8]) -> SignatureComponent {
-        let mut component = [0u8; SIGNATURE_COMPONENT_LENGTH];
+    /// Publishes an event to the notifier after each signing attempt, e.g. so that monitoring and
+    /// reconciliation systems learn about every signature of the account. Events are published in
+    /// the background and failures to deliver them are ignored.
+    ///
+    /// With `rt-tokio`, signing outside of a Tokio runtime (e.g. in `futures::executor::block_on`)
+    /// has no runtime to publish the event on, as the HTTP and AWS clients of the notifier need
+    /// one, so the event is dropped instead of panicking.
+    #[cfg(feature = "notifications")]
+    pub fn with_notifier(mut self, notifier: events::Notifier) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
 
-        match decoded_data.len().cmp(&SIGNATURE_COMPONENT_LENGTH) {
-            Ordering::Greater => {
-                // Drop the meaningless leading sign indicator zero byte
-                component.copy_from_slice(&decoded_data[1..]);
-            }
-            Ordering::Equal => {
-                component.copy_from_slice(decoded_data);
-            }
-            Ordering::Less => {
-                let slice = &mut component[1..];
-                slice.copy_from_slice(decoded_data);
-            }
+    /// Emits the metrics of each signing attempt to the sink, e.g. to report signing counts,
+    /// latencies and errors to CloudWatch.
+    #[cfg(feature = "emf")]
+    pub fn with_metrics(mut self, metrics: metrics::EmfSink) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Records each signing attempt in the audit sink. The sink is shared, so that its buffered
+    /// records can be flushed on shutdown.
+    #[cfg(feature = "audit-s3")]
+    pub fn with_audit_sink(mut self, audit_sink: std::sync::Arc<audit::S3AuditSink>) -> Self {
+        self.audit_sink = Some(audit_sink);
+        self
+    }
+
+    /// Waits for a token of the rate limiter before each KMS sign call. The limiter is shared, so
+    /// that all the accounts of a key stay within one rate.
+    #[cfg(feature = "rate-limit")]
+    pub fn with_rate_limiter(
+        mut self,
+        rate_limiter: std::sync::Arc<rate_limit::RateLimiter>,
+    ) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Returns the signature obtained for the same digest within the TTL of the cache instead of
+    /// calling KMS, e.g. so that callers retrying a payload get the same signature. The cache is
+    /// keyed by the public key, so it can be shared by accounts of different keys, even if their
+    /// key IDs are the same alias in different regions.
+    #[cfg(feature = "signature-cache")]
+    pub fn with_signature_cache(
+        mut self,
+        signature_cache: std::sync::Arc<signature_cache::SignatureCache>,
+    ) -> Self {
+        self.signature_cache = Some(signature_cache);
+        self
+    }
+
+    /// Reports KMS sign calls slower than the threshold, with the region and the key ID, as a
+    /// warning of the `tracing` feature and a metric of the `emf` feature, e.g. to catch a key in
+    /// another region adding latency to every signature.
+    #[cfg(any(feature = "tracing", feature = "emf"))]
+    pub fn with_slow_call_threshold(mut self, threshold: std::time::Duration) -> Self {
+        self.slow_call_threshold = Some(threshold);
+        self
+    }
+
+    /// Makes a cheap KMS request (`GetPublicKey`, which the account already depends on), so that
+    /// credentials are resolved and a TLS connection is established before the next signature,
+    /// e.g. after the connections were idle for longer than the pool timeout, or at the end of
+    /// the initialization of a Lambda function or a container before it takes traffic.
+    ///
+    /// The constructors already make such a request, so warming up right after them is redundant.
+    pub async fn warm_up(&self) -> Result<(), io::Error> {
+        self.kms_key.get_public_key().await.map(|_| ())
+    }
+
+    /// Returns the address of the account derived from its public key.
+    ///
+    /// The address is derived once, when the account is created.
+    pub fn address(&self) -> AccountAddress {
+        self.address
+    }
+
+    /// Returns the [`EIP-55`](https://eips.ethereum.org/EIPS/eip-55) checksummed address of the
+    /// account, e.g. for logs and responses, without encoding it again.
+    pub fn checksummed_address(&self) -> &str {
+        &self.checksummed_address
+    }
+
+    /// Returns the signing policy enforced by the account.
+    pub fn policy(&self) -> &SigningPolicy {
+        &self.policy
+    }
+
+    // Fits the big-endian DER integer into 32 bytes, i.e. drops the leading sign indicator zero
+    // byte, or pads integers which are shorter because their leading bytes are zero
+    fn to_signature_component(decoded_data: &[u8]) -> Result<SignatureComponent, SignatureError> {
+        let leading_zeros = decoded_data.iter().take_while(|byte| **byte == 0).count();
+        let significant = &decoded_data[leading_zeros..];
+        if significant.len() > SIGNATURE_COMPONENT_LENGTH {
+            return Err(SignatureError::ComponentTooLong);
         }
 
-        component
+        let mut component = [0u8; SIGNATURE_COMPONENT_LENGTH];
+        component[SIGNATURE_COMPONENT_LENGTH - significant.len()..].copy_from_slice(significant);
+
+        Ok(component)
     }
 
     fn parse_signature(
@@ -120,52 +351,48 @@ impl<'a> EvmAccount<'a> {
         })?;
 
         // Remove the leading sign indicator zero byte if present
-        let r = Self::to_signature_component(r.as_bytes());
-        let s = wrap_s(Self::to_signature_component(s.as_bytes()));
+        let r = Self::to_signature_component(r.as_bytes())?;
+        let s = Self::to_signature_component(s.as_bytes())?;
+        // Malformed signatures are rejected rather than encoded into transactions
+        let s = canonicalize(&r, s)?;
 
         Ok((r, s))
     }
 
     fn recover_public_key(
-        public_key: &[u8],
-        digest: &[u8],
+        public_key: &PublicKey,
+        digest: &Keccak256Digest,
         r: &SignatureComponent,
         s: &SignatureComponent,
-    ) -> Result<u32, secp256k1::Error> {
-        let secp_context = Secp256k1::verification_only();
-        // Compact signature is concatenation of 32-byte r and 32-byte s with no headers
-        let mut compact_signature = r.to_vec();
-        compact_signature.extend_from_slice(s);
-
-        let message = Message::from_digest_slice(digest)?;
-
-        // Possible v values are 0 or 1
+    ) -> Result<u32, io::Error> {
+        // Possible v values are 0 or 1. The keys are compared in constant time, so that the
+        // comparison leaks no timing signal of how much of the recovered key matches.
         for v in 0..2 {
-            let signature =
-                RecoverableSignature::from_compact(&compact_signature, RecoveryId::try_from(v)?)?;
-
-            // Uncompressed public key is 65 bytes long, beginning with 0x04 to indicate it is uncompressed
-            let pub_key_uncompressed_bytes = secp_context
-                .recover_ecdsa(&message, &signature)?
-                .serialize_uncompressed();
-
-            // Drop the 0x04 uncompressed EC prefix
-            if pub_key_uncompressed_bytes[1..] == *public_key {
-                return Ok(v as u32);
+            let recovered_public_key = ecdsa::recover_public_key(digest, r, s, v)?;
+            if bool::from(recovered_public_key[..].ct_eq(&public_key[..])) {
+                return Ok(v.into());
             }
         }
 
-        Err(secp256k1::Error::InvalidPublicKeySum)
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "No parity recovers the public key",
+        ))
     }
 
-    async fn sign_bytes(
-        &self,
-        digest: &[u8],
+    /// Fits the DER signature returned by KMS into the components and finds its parity, i.e. the
+    /// processing done for every signature obtained from KMS.
+    ///
+    /// Not part of the API, only public for the benchmarks.
+    #[doc(hidden)]
+    pub fn signature_from_der(
+        public_key: &PublicKey,
+        digest: &Keccak256Digest,
+        signature_der: &[u8],
     ) -> Result<(u32, SignatureComponent, SignatureComponent), io::Error> {
-        let signature = self.kms_key.sign(digest).await?;
-        let (r, s) = Self::parse_signature(&signature)?;
+        let (r, s) = Self::parse_signature(signature_der)?;
 
-        let v = Self::recover_public_key(&self.public_key, digest, &r, &s).map_err(|error| {
+        let v = Self::recover_public_key(public_key, digest, &r, &s).map_err(|error| {
             io::Error::new(
                 io::ErrorKind::InvalidData,
                 format!("Failed to recover public key: {}", error),
@@ -175,28 +402,500 @@ impl<'a> EvmAccount<'a> {
         Ok((v, r, s))
     }
 
+    async fn sign_bytes(
+        &self,
+        digest: &Keccak256Digest,
+    ) -> Result<(u32, SignatureComponent, SignatureComponent), io::Error> {
+        #[cfg(feature = "signature-cache")]
+        if let Some(signature) = self
+            .signature_cache
+            .as_ref()
+            .and_then(|cache| cache.get(&self.public_key, digest))
+        {
+            return Ok(signature);
+        }
+        // Waiting for the limiter does not count towards the latency of the call
+        #[cfg(feature = "rate-limit")]
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        #[cfg(any(feature = "tracing", feature = "emf"))]
+        let started = Instant::now();
+        // Boxed, as the SDK request nested in the instrumented signing futures is otherwise too
+        // deep to lay out, e.g. in batches and relay requests with the `tracing` feature
+        let signature = Box::pin(self.kms_key.sign(digest)).await?;
+        #[cfg(any(feature = "tracing", feature = "emf"))]
+        self.report_slow_call(started.elapsed());
+        let (v, r, s) = Self::signature_from_der(&self.public_key, digest, &signature)?;
+        #[cfg(feature = "signature-cache")]
+        if let Some(cache) = &self.signature_cache {
+            cache.insert(&self.public_key, digest, (v, r, s));
+        }
+
+        Ok((v, r, s))
+    }
+
+    #[cfg(any(feature = "tracing", feature = "emf"))]
+    fn report_slow_call(&self, latency: std::time::Duration) {
+        let Some(threshold) = self.slow_call_threshold else {
+            return;
+        };
+        if latency <= threshold {
+            return;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            key_id = %kms_key::redact_key_id(self.kms_key.key_id()),
+            region = self.kms_key.region(),
+            latency_ms = latency.as_millis() as u64,
+            threshold_ms = threshold.as_millis() as u64,
+            "Slow KMS sign call"
+        );
+        #[cfg(feature = "emf")]
+        if let Some(metrics) = &self.metrics {
+            metrics.emit_slow_call(self.kms_key.key_id(), self.kms_key.region(), latency);
+        }
+    }
+
     /// Signs the provided transaction with the EVM account's private key.
     ///
-    /// The method encodes the unsigned transaction, calculates its digest and signs it with the KMS
-    /// private key. It returns a `SignedTransaction` instance with (among others) the `r` and
-    /// `s` values, and signature parity.
+    /// The method verifies the transaction against the account's signing policy, encodes the
+    /// unsigned transaction, calculates its digest and signs it with the KMS private key. It
+    /// returns a `SignedTransaction` instance with (among others) the `r` and `s` values, and
+    /// signature parity.
     pub async fn sign_transaction<T: Transaction>(
         &self,
         tx: T,
     ) -> Result<SignedTransaction<T>, io::Error> {
+        self.sign_transaction_with_context(tx, &SigningContext::default())
+            .await
+    }
+
+    /// Signs the transaction like `sign_transaction`, attaching the context supplied by the caller
+    /// (e.g. a correlation ID) to the tracing span and the event of the attempt.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                key_id = %kms_key::redact_key_id(self.kms_key.key_id()),
+                tx_type = T::TX_TYPE.id(),
+                chain_id = tx.chain_id(),
+                nonce = tx.nonce(),
+                digest = tracing::field::Empty,
+                correlation_id = context.correlation_id.as_deref(),
+            ),
+            err
+        )
+    )]
+    pub async fn sign_transaction_with_context<T: Transaction>(
+        &self,
+        tx: T,
+        context: &SigningContext,
+    ) -> Result<SignedTransaction<T>, io::Error> {
+        let started = Instant::now();
+        if let Err(error) = self.policy.check(&tx) {
+            self.notify(started, context, || {
+                self.unsuccessful_event(&keccak256(tx.encode()), SigningOutcome::Rejected, &error)
+            })
+            .await;
+            return Err(error);
+        }
+
         let tx_encoding = tx.encode();
-        let digest = keccak256_digest(&tx_encoding);
-        let signed_bytes = self.sign_bytes(&digest);
+        let digest = keccak256(&tx_encoding);
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record(
+            "digest",
+            tracing::field::display(transaction::Bytes::from(digest.as_slice())),
+        );
+        let (v, r, s) = match self.sign_bytes(&digest).await {
+            Ok(signature) => signature,
+            Err(error) => {
+                self.notify(started, context, || {
+                    self.unsuccessful_event(&digest, SigningOutcome::Failed, &error)
+                })
+                .await;
+                return Err(error);
+            }
+        };
+
+        let signed_tx = SignedTransaction::with_encoding(tx, tx_encoding, digest, v, r, s);
+        self.notify(started, context, || {
+            let key_id = self.kms_key.key_id();
+            SigningEvent::signed(key_id, self.address(), &digest, Some(&signed_tx.hash()))
+        })
+        .await;
+
+        Ok(signed_tx)
+    }
+
+    /// Signs the transaction JSON of any supported type, detected with `json::detect_tx_type`,
+    /// and returns the encoding of the signed transaction.
+    ///
+    /// Useful for services which accept transactions of any type, e.g. the optional `type` field
+    /// is removed before the transaction is deserialized according to `mode`.
+    pub async fn sign_transaction_json(
+        &self,
+        tx_json: &str,
+        mode: Mode,
+    ) -> Result<Vec<u8>, io::Error> {
+        self.sign_transaction_json_with_context(tx_json, mode, &SigningContext::default())
+            .await
+    }
+
+    /// Signs the transaction JSON like `sign_transaction_json`, attaching the context supplied by
+    /// the caller (e.g. a correlation ID) to the tracing span and the event of the attempt.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                key_id = %kms_key::redact_key_id(self.kms_key.key_id()),
+                mode = ?mode,
+                tx_type = tracing::field::Empty,
+                correlation_id = context.correlation_id.as_deref(),
+            ),
+            err
+        )
+    )]
+    pub async fn sign_transaction_json_with_context(
+        &self,
+        tx_json: &str,
+        mode: Mode,
+        context: &SigningContext,
+    ) -> Result<Vec<u8>, io::Error> {
+        let mut tx: Value = serde_json::from_str(tx_json).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to parse transaction JSON: {}", error),
+            )
+        })?;
+        let tx_type = detect_tx_type(&tx)?;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("tx_type", tx_type.id());
+        if let Some(fields) = tx.as_object_mut() {
+            fields.remove(TYPE_FIELD);
+        }
+        let tx_json = tx.to_string();
+
+        match tx_type {
+            TxType::Legacy => {
+                self.sign_json::<LegacyTransaction>(&tx_json, mode, context)
+                    .await
+            }
+            TxType::Eip2930 => {
+                self.sign_json::<AccessListTransaction>(&tx_json, mode, context)
+                    .await
+            }
+            TxType::Eip1559 => {
+                self.sign_json::<FreeMarketTransaction>(&tx_json, mode, context)
+                    .await
+            }
+            tx_type => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("Unsupported transaction type: {:#04x}", tx_type.id()),
+            )),
+        }
+    }
+
+    async fn sign_json<T: Transaction>(
+        &self,
+        tx_json: &str,
+        mode: Mode,
+        context: &SigningContext,
+    ) -> Result<Vec<u8>, io::Error> {
+        let tx: T = json::from_str(tx_json, mode)?;
+
+        Ok(self
+            .sign_transaction_with_context(tx, context)
+            .await?
+            .encode())
+    }
+
+    /// Signs the message prefixed according to [`EIP-191`](https://eips.ethereum.org/EIPS/eip-191),
+    /// i.e. the signature produced by `personal_sign`.
+    pub async fn sign_message(&self, message: impl AsRef<[u8]>) -> Result<Signature, io::Error> {
+        self.sign_digest(&eip191_hash_message(message)).await
+    }
+
+    /// Signs the digest of typed structured data, e.g. computed with `hash::eip712_hash`, i.e. the
+    /// signature produced by `eth_signTypedData_v4`.
+    pub async fn sign_typed_data_hash(
+        &self,
+        digest: &Keccak256Digest,
+    ) -> Result<Signature, io::Error> {
+        self.sign_digest(digest).await
+    }
+
+    /// Signs the digest as is, e.g. the Keccak-256 hash of arbitrary data.
+    ///
+    /// The signing policy is not verified, as the digest could be of anything, including a
+    /// transaction the policy would reject. Only use it for digests of trusted data.
+    pub async fn sign_digest(&self, digest: &Keccak256Digest) -> Result<Signature, io::Error> {
+        self.sign_digest_with_context(digest, &SigningContext::default())
+            .await
+    }
+
+    /// Signs the digest like `sign_digest`, attaching the context supplied by the caller (e.g. a
+    /// correlation ID) to the tracing span and the event of the attempt.
+    ///
+    /// Messages and typed data are signed with context by signing their digests, i.e.
+    /// `hash::eip191_hash_message` and `TypedData::signing_hash` respectively.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            skip_all,
+            fields(
+                key_id = %kms_key::redact_key_id(self.kms_key.key_id()),
+                digest = %transaction::Bytes::from(digest.as_slice()),
+                correlation_id = context.correlation_id.as_deref(),
+            ),
+            err
+        )
+    )]
+    pub async fn sign_digest_with_context(
+        &self,
+        digest: &Keccak256Digest,
+        context: &SigningContext,
+    ) -> Result<Signature, io::Error> {
+        let started = Instant::now();
+        let (v, r, s) = match self.sign_bytes(digest).await {
+            Ok(signature) => signature,
+            Err(error) => {
+                self.notify(started, context, || {
+                    self.unsuccessful_event(digest, SigningOutcome::Failed, &error)
+                })
+                .await;
+                return Err(error);
+            }
+        };
+
+        self.notify(started, context, || {
+            SigningEvent::signed(self.kms_key.key_id(), self.address(), digest, None)
+        })
+        .await;
+
+        Ok(Signature::new(r, s, v as u8))
+    }
+
+    // Publishes the event of the signing attempt, which started at `started`, with the context of
+    // the caller if a notifier is attached, emits its metrics and records it in the audit sink if
+    // attached. A failure to publish does not fail the attempt, as its outcome is already decided.
+    // The notifier publishes in the background, so that a slow destination does not delay signing.
+    // Without a runtime to spawn on, the event is dropped, as publishing would need one too.
+    async fn notify(
+        &self,
+        started: Instant,
+        context: &SigningContext,
+        event: impl FnOnce() -> SigningEvent,
+    ) {
+        #[cfg(any(feature = "notifications", feature = "emf", feature = "audit-s3"))]
+        if self.has_event_sinks() {
+            let event = event().with_context(context);
+            #[cfg(feature = "emf")]
+            if let Some(metrics) = &self.metrics {
+                metrics.emit(&event, started.elapsed());
+            }
+            #[cfg(feature = "notifications")]
+            if let Some(notifier) = &self.notifier {
+                let (notifier, event) = (notifier.clone(), event.clone());
+                let _ = runtime::spawn(async move {
+                    let _ = notifier.publish(&event).await;
+                });
+            }
+            #[cfg(feature = "audit-s3")]
+            if let Some(audit_sink) = &self.audit_sink {
+                audit_sink.record(&event);
+            }
+        }
+        #[cfg(not(feature = "emf"))]
+        let _ = started;
+        #[cfg(not(any(feature = "notifications", feature = "emf", feature = "audit-s3")))]
+        let _ = (context, event);
+    }
+
+    // Events are only built if they are consumed
+    #[cfg(any(feature = "notifications", feature = "emf", feature = "audit-s3"))]
+    fn has_event_sinks(&self) -> bool {
+        #[cfg(feature = "notifications")]
+        if self.notifier.is_some() {
+            return true;
+        }
+        #[cfg(feature = "emf")]
+        if self.metrics.is_some() {
+            return true;
+        }
+        #[cfg(feature = "audit-s3")]
+        if self.audit_sink.is_some() {
+            return true;
+        }
 
-        let (v, r, s) = signed_bytes.await?;
+        false
+    }
+
+    fn unsuccessful_event(
+        &self,
+        digest: &Keccak256Digest,
+        outcome: SigningOutcome,
+        error: &io::Error,
+    ) -> SigningEvent {
+        SigningEvent::unsuccessful(
+            self.kms_key.key_id(),
+            self.address(),
+            digest,
+            outcome,
+            error,
+        )
+    }
+
+    /// Signs the typed structured data, i.e. the signature produced by `eth_signTypedData_v4`.
+    pub async fn sign_typed_data(&self, typed_data: &TypedData) -> Result<Signature, io::Error> {
+        self.sign_typed_data_hash(&typed_data.signing_hash()?).await
+    }
+
+    /// Re-signs the transaction with the same nonce and fees raised by `bump_percent`, e.g. to
+    /// replace a transaction stuck in the mempool.
+    ///
+    /// Fees are raised by at least `MIN_FEE_BUMP_PERCENT`, as nodes reject replacements with a
+    /// lower increase.
+    pub async fn replace_with_higher_fee<T: Transaction>(
+        &self,
+        signed_tx: SignedTransaction<T>,
+        bump_percent: u32,
+    ) -> Result<SignedTransaction<T>, io::Error> {
+        let mut tx = signed_tx.into_tx();
+        tx.bump_fees(bump_percent.max(MIN_FEE_BUMP_PERCENT));
 
-        Ok(SignedTransaction::new(tx, &tx_encoding, digest, v, r, s))
+        self.sign_transaction(tx).await
+    }
+
+    /// Signs a zero-value transfer from the account to itself with the given nonce, which voids
+    /// the pending transaction with the same nonce once included.
+    ///
+    /// The fees have to exceed those of the pending transaction by at least
+    /// `MIN_FEE_BUMP_PERCENT` for nodes to accept the replacement.
+    pub async fn cancel(
+        &self,
+        nonce: u128,
+        fee_settings: FeeSettings,
+    ) -> Result<SignedTransaction<FreeMarketTransaction>, io::Error> {
+        let tx = FreeMarketTransaction::cancellation(self.address(), nonce, fee_settings);
+
+        self.sign_transaction(tx).await
     }
 }
 
-#[cfg(test)]
+#[cfg(all(feature = "rpc", feature = "aws"))]
+impl EvmAccount<'_> {
+    /// Returns the next nonce of the account, including transactions pending in the mempool of the
+    /// node.
+    pub async fn next_nonce(&self, provider: &impl rpc::Provider) -> Result<u128, io::Error> {
+        rpc::nonce::next_nonce(&self.address(), provider).await
+    }
+
+    /// Sets the nonce of the transaction to the next nonce of the account.
+    pub async fn fill_nonce<T: Transaction>(
+        &self,
+        tx: &mut T,
+        provider: &impl rpc::Provider,
+    ) -> Result<(), io::Error> {
+        rpc::nonce::fill_nonce(tx, &self.address(), provider).await
+    }
+
+    /// Estimates the gas consumed by the transaction sent from the account and sets its gas limit
+    /// to the estimate increased by `margin_percent` (see `rpc::gas::estimate_gas`).
+    pub async fn estimate_gas<T: Transaction>(
+        &self,
+        tx: &mut T,
+        provider: &impl rpc::Provider,
+        margin_percent: u32,
+    ) -> Result<u128, io::Error> {
+        rpc::gas::estimate_gas(tx, &self.address(), provider, margin_percent).await
+    }
+
+    /// Executes the transaction sent from the account with `eth_call` without signing it, e.g. to
+    /// warn about transactions which would revert.
+    pub async fn simulate<T: Transaction>(
+        &self,
+        tx: &T,
+        provider: &impl rpc::Provider,
+    ) -> Result<rpc::simulation::Simulation, io::Error> {
+        rpc::simulation::simulate(tx, &self.address(), provider).await
+    }
+
+    /// Simulates the transaction and signs it only if the execution does not revert.
+    ///
+    /// Reverts are returned as errors carrying the `rpc::simulation::Revert` with the decoded
+    /// revert reason.
+    pub async fn sign_simulated_transaction<T: Transaction>(
+        &self,
+        tx: T,
+        provider: &impl rpc::Provider,
+    ) -> Result<SignedTransaction<T>, io::Error> {
+        self.simulate(&tx, provider).await?.into_result()?;
+
+        self.sign_transaction(tx).await
+    }
+
+    /// Verifies that the balance of the account covers the maximum cost of the transaction (see
+    /// `rpc::balance::check_balance`).
+    pub async fn check_balance<T: Transaction>(
+        &self,
+        tx: &T,
+        provider: &impl rpc::Provider,
+    ) -> Result<(), io::Error> {
+        rpc::balance::check_balance(tx, &self.address(), provider).await
+    }
+
+    /// Signs the transaction only if the balance of the account covers its maximum cost.
+    ///
+    /// Shortfalls are returned as errors carrying the `rpc::balance::InsufficientFunds`.
+    pub async fn sign_funded_transaction<T: Transaction>(
+        &self,
+        tx: T,
+        provider: &impl rpc::Provider,
+    ) -> Result<SignedTransaction<T>, io::Error> {
+        self.check_balance(&tx, provider).await?;
+
+        self.sign_transaction(tx).await
+    }
+
+    /// Fills in the transaction, signs it and broadcasts it, returning its hash.
+    ///
+    /// The nonce is always set to the next nonce of the account, whereas the gas limit is only
+    /// estimated if it is zero (see `rpc::gas::DEFAULT_GAS_MARGIN_PERCENT`) and the fees are only
+    /// suggested if they are zero (see `rpc::fees::FillFees`).
+    pub async fn sign_and_send<T>(
+        &self,
+        mut tx: T,
+        provider: &impl rpc::Provider,
+    ) -> Result<hash::Keccak256Digest, io::Error>
+    where
+        T: Transaction + rpc::fees::FillFees,
+    {
+        self.fill_nonce(&mut tx, provider).await?;
+        if tx.max_fee_per_gas() == transaction::U256::ZERO {
+            tx.fill_suggested_fees(provider).await?;
+        }
+        if tx.gas_limit() == 0 {
+            self.estimate_gas(&mut tx, provider, rpc::gas::DEFAULT_GAS_MARGIN_PERCENT)
+                .await?;
+        }
+
+        let signed_tx = self.sign_transaction(tx).await?;
+
+        rpc::broadcast::broadcast(&signed_tx, provider).await
+    }
+}
+
+#[cfg(all(test, feature = "aws"))]
 mod unit_tests {
-    use super::{EvmAccount, KECCAK_256_LENGTH, PUBLIC_KEY_LENGTH, SIGNATURE_COMPONENT_LENGTH};
+    use super::{
+        EvmAccount, SignatureError, KECCAK_256_LENGTH, PUBLIC_KEY_LENGTH,
+        SIGNATURE_COMPONENT_LENGTH,
+    };
 
     const TEST_KEY_DER: [u8; 88] = [
         0x30, 0x56, 0x30, 0x10, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x05,
@@ -273,6 +972,57 @@ mod unit_tests {
         assert_eq!(s, TEST_S_1);
     }
 
+    #[test]
+    fn to_signature_component_succeed() {
+        let mut input = vec![0x00];
+        input.extend_from_slice(&TEST_R_1);
+
+        assert_eq!(
+            TEST_R_1,
+            EvmAccount::to_signature_component(&input).unwrap()
+        );
+        assert_eq!(
+            TEST_R_1,
+            EvmAccount::to_signature_component(&TEST_R_1).unwrap()
+        );
+    }
+
+    #[test]
+    fn to_signature_component_short_succeed() {
+        let input = [0xab; 30];
+
+        let right = EvmAccount::to_signature_component(&input).unwrap();
+
+        assert_eq!([0x00, 0x00], right[..2]);
+        assert_eq!(input, right[2..]);
+    }
+
+    #[test]
+    fn to_signature_component_fail() {
+        let input = [0xab; 33];
+
+        let right = EvmAccount::to_signature_component(&input).unwrap_err();
+
+        assert_eq!(SignatureError::ComponentTooLong, right);
+    }
+
+    #[test]
+    fn signature_from_der_succeed() {
+        // DER sequence of the integers, the high bit of `s` requiring a leading zero byte
+        let mut input = vec![0x30, 0x45, 0x02, 0x20];
+        input.extend_from_slice(&TEST_R_2);
+        input.extend_from_slice(&[0x02, 0x21, 0x00]);
+        input.extend_from_slice(&TEST_S_2);
+
+        let (v, r, s) =
+            EvmAccount::signature_from_der(&TEST_PUBLIC_KEY, &TEST_DIGEST, &input).unwrap();
+
+        // The high `s` is canonicalized, which flips the parity of the signature
+        assert_eq!(TEST_R_2, r);
+        assert_ne!(TEST_S_2, s);
+        assert_eq!(1, v);
+    }
+
     #[test]
     fn recover_public_key() {
         let r = TEST_R_2;
@@ -0,0 +1,910 @@
+use std::{
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+    iter,
+    str::FromStr,
+};
+
+pub use ethnum::I256;
+
+/// Decoding of calldata into function calls described by an ABI JSON, e.g. for audit logs.
+pub mod decoder;
+/// Contract creation transactions with encoded constructor arguments.
+pub mod deploy;
+/// Transactions and calldata of [`ERC-1155`](https://eips.ethereum.org/EIPS/eip-1155)
+/// multi-token transfers.
+pub mod erc1155;
+/// Transactions and calldata of [`ERC-20`](https://eips.ethereum.org/EIPS/eip-20) token
+/// transfers and approvals.
+pub mod erc20;
+/// Transactions and calldata of [`ERC-721`](https://eips.ethereum.org/EIPS/eip-721) NFT
+/// transfers and approvals.
+pub mod erc721;
+/// Batching of calls into a single [`Multicall3`](https://www.multicall3.com) transaction.
+pub mod multicall;
+/// Off-chain signed token approvals of Uniswap [`Permit2`](https://github.com/Uniswap/permit2).
+pub mod permit2;
+/// Offline registry of function selectors, labelling calldata without a full ABI.
+pub mod selectors;
+/// Wrapping and unwrapping of the native currency with the canonical wrapped native token (e.g.
+/// WETH) of the chain.
+pub mod weth;
+
+use super::{
+    hash::keccak256,
+    transaction::{AccountAddress, Bytes, U256},
+};
+
+/// Length of the function selector prefixing the calldata.
+pub const SELECTOR_LENGTH: usize = 4;
+const WORD_LENGTH: usize = 32;
+// Addresses are right-aligned in ABI encoded words.
+const ADDRESS_OFFSET: usize = WORD_LENGTH - 20;
+const MAX_INTEGER_BITS: usize = 256;
+const MAX_FIXED_BYTES_LENGTH: usize = 32;
+
+/// Solidity type of a function parameter, e.g. parsed from `uint256[]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParamType {
+    /// `address`
+    Address,
+    /// `bool`
+    Bool,
+    /// `uint<M>`, where `M` is the number of bits.
+    Uint(usize),
+    /// `int<M>`, where `M` is the number of bits.
+    Int(usize),
+    /// `bytes<M>`, where `M` is the number of bytes.
+    FixedBytes(usize),
+    /// `bytes`
+    Bytes,
+    /// `string`
+    String,
+    /// `T[]`
+    Array(Box<ParamType>),
+    /// `T[k]`
+    FixedArray(Box<ParamType>, usize),
+    /// `(T1,T2,...)`
+    Tuple(Vec<ParamType>),
+}
+
+impl ParamType {
+    /// Returns `true` if the encoding of the type has variable length, i.e. it is encoded in the
+    /// tail of the enclosing tuple.
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            ParamType::Bytes | ParamType::String | ParamType::Array(_) => true,
+            ParamType::FixedArray(param, _) => param.is_dynamic(),
+            ParamType::Tuple(params) => params.iter().any(ParamType::is_dynamic),
+            _ => false,
+        }
+    }
+
+    // Size of the value in the head of the enclosing tuple.
+    fn head_size(&self) -> usize {
+        match self {
+            _ if self.is_dynamic() => WORD_LENGTH,
+            ParamType::FixedArray(param, length) => param.head_size().saturating_mul(*length),
+            ParamType::Tuple(params) => params
+                .iter()
+                .fold(0, |size, param| size.saturating_add(param.head_size())),
+            _ => WORD_LENGTH,
+        }
+    }
+
+    /// Returns `true` if the token is a valid value of the type.
+    pub fn matches(&self, token: &Token) -> bool {
+        match (self, token) {
+            (ParamType::Address, Token::Address(_))
+            | (ParamType::Bool, Token::Bool(_))
+            | (ParamType::Bytes, Token::Bytes(_))
+            | (ParamType::String, Token::String(_)) => true,
+            (ParamType::Uint(bits), Token::Uint(value)) => {
+                *bits == MAX_INTEGER_BITS || *value >> *bits as u32 == U256::ZERO
+            }
+            (ParamType::Int(bits), Token::Int(value)) => {
+                let sign_bits = *value >> (*bits as u32).saturating_sub(1);
+                *bits == MAX_INTEGER_BITS || sign_bits == I256::ZERO || sign_bits == I256::MINUS_ONE
+            }
+            (ParamType::FixedBytes(length), Token::FixedBytes(bytes)) => bytes.len() == *length,
+            (ParamType::Array(param), Token::Array(tokens)) => {
+                tokens.iter().all(|token| param.matches(token))
+            }
+            (ParamType::FixedArray(param, length), Token::FixedArray(tokens)) => {
+                tokens.len() == *length && tokens.iter().all(|token| param.matches(token))
+            }
+            (ParamType::Tuple(params), Token::Tuple(tokens)) => matches_all(params, tokens),
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for ParamType {
+    type Err = Error;
+
+    /// Parses the Solidity type, e.g. `address`, `uint256[2]` or `(address,bytes)[]`.
+    fn from_str(param: &str) -> Result<Self, Self::Err> {
+        let param = param.trim();
+
+        if let Some(array) = param.strip_suffix(']') {
+            let (inner, length) = array.rsplit_once('[').ok_or_else(|| invalid_type(param))?;
+            let inner = Box::new(inner.parse()?);
+
+            return match length {
+                "" => Ok(ParamType::Array(inner)),
+                length => length
+                    .parse()
+                    .map(|length| ParamType::FixedArray(inner, length))
+                    .map_err(|_| invalid_type(param)),
+            };
+        }
+
+        if let Some(tuple) = param
+            .strip_prefix('(')
+            .and_then(|tuple| tuple.strip_suffix(')'))
+        {
+            return parse_params(tuple).map(ParamType::Tuple);
+        }
+
+        match param {
+            "address" => Ok(ParamType::Address),
+            "bool" => Ok(ParamType::Bool),
+            "bytes" => Ok(ParamType::Bytes),
+            "string" => Ok(ParamType::String),
+            "uint" => Ok(ParamType::Uint(MAX_INTEGER_BITS)),
+            "int" => Ok(ParamType::Int(MAX_INTEGER_BITS)),
+            _ => parse_sized(param).ok_or_else(|| invalid_type(param)),
+        }
+    }
+}
+
+impl Display for ParamType {
+    /// Formats the canonical type, i.e. as used to compute function selectors.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamType::Address => f.write_str("address"),
+            ParamType::Bool => f.write_str("bool"),
+            ParamType::Uint(bits) => write!(f, "uint{}", bits),
+            ParamType::Int(bits) => write!(f, "int{}", bits),
+            ParamType::FixedBytes(length) => write!(f, "bytes{}", length),
+            ParamType::Bytes => f.write_str("bytes"),
+            ParamType::String => f.write_str("string"),
+            ParamType::Array(param) => write!(f, "{}[]", param),
+            ParamType::FixedArray(param, length) => write!(f, "{}[{}]", param, length),
+            ParamType::Tuple(params) => write!(f, "({})", join_params(params)),
+        }
+    }
+}
+
+/// Value of a function argument.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    /// Value of `address`.
+    Address(AccountAddress),
+    /// Value of `bool`.
+    Bool(bool),
+    /// Value of `uint<M>`.
+    Uint(U256),
+    /// Value of `int<M>`.
+    Int(I256),
+    /// Value of `bytes<M>`.
+    FixedBytes(Vec<u8>),
+    /// Value of `bytes`.
+    Bytes(Vec<u8>),
+    /// Value of `string`.
+    String(String),
+    /// Value of `T[]`.
+    Array(Vec<Token>),
+    /// Value of `T[k]`.
+    FixedArray(Vec<Token>),
+    /// Value of `(T1,T2,...)`.
+    Tuple(Vec<Token>),
+}
+
+impl Token {
+    fn is_dynamic(&self) -> bool {
+        match self {
+            Token::Bytes(_) | Token::String(_) | Token::Array(_) => true,
+            Token::FixedArray(tokens) | Token::Tuple(tokens) => {
+                tokens.iter().any(Token::is_dynamic)
+            }
+            _ => false,
+        }
+    }
+
+    // Size of the token in the head of the enclosing tuple.
+    fn head_size(&self) -> usize {
+        match self {
+            _ if self.is_dynamic() => WORD_LENGTH,
+            Token::FixedArray(tokens) | Token::Tuple(tokens) => {
+                tokens.iter().map(Token::head_size).sum()
+            }
+            _ => WORD_LENGTH,
+        }
+    }
+}
+
+impl Display for Token {
+    /// Formats the value for humans, e.g. addresses checksummed, integers in decimal and bytes in
+    /// hex.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Address(address) => write!(f, "{}", address),
+            Token::Bool(value) => write!(f, "{}", value),
+            Token::Uint(value) => write!(f, "{}", value),
+            Token::Int(value) => write!(f, "{}", value),
+            Token::FixedBytes(bytes) | Token::Bytes(bytes) => {
+                write!(f, "{}", Bytes::from(bytes.as_slice()))
+            }
+            Token::String(value) => write!(f, "{:?}", value),
+            Token::Array(tokens) | Token::FixedArray(tokens) => {
+                write!(f, "[{}]", join_tokens(tokens))
+            }
+            Token::Tuple(tokens) => write!(f, "({})", join_tokens(tokens)),
+        }
+    }
+}
+
+impl From<AccountAddress> for Token {
+    fn from(address: AccountAddress) -> Self {
+        Token::Address(address)
+    }
+}
+
+impl From<bool> for Token {
+    fn from(value: bool) -> Self {
+        Token::Bool(value)
+    }
+}
+
+impl From<U256> for Token {
+    fn from(value: U256) -> Self {
+        Token::Uint(value)
+    }
+}
+
+impl From<I256> for Token {
+    fn from(value: I256) -> Self {
+        Token::Int(value)
+    }
+}
+
+impl From<Bytes> for Token {
+    fn from(bytes: Bytes) -> Self {
+        Token::Bytes(bytes.0)
+    }
+}
+
+impl From<&str> for Token {
+    fn from(value: &str) -> Self {
+        Token::String(value.to_string())
+    }
+}
+
+impl From<String> for Token {
+    fn from(value: String) -> Self {
+        Token::String(value)
+    }
+}
+
+/// Function signature, e.g. `transfer(address,uint256)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Function {
+    /// Name of the function.
+    pub name: String,
+    /// Types of the function parameters.
+    pub inputs: Vec<ParamType>,
+}
+
+impl Function {
+    /// Computes the function selector, i.e. the first 4 bytes of the digest of the canonical
+    /// signature.
+    pub fn selector(&self) -> [u8; SELECTOR_LENGTH] {
+        let mut selector = [0u8; SELECTOR_LENGTH];
+        selector.copy_from_slice(&keccak256(self.to_string())[..SELECTOR_LENGTH]);
+
+        selector
+    }
+
+    /// Encodes the call of the function with the arguments, i.e. the selector followed by the
+    /// encoded arguments. Fails if the arguments do not match the parameter types.
+    pub fn encode_call(&self, args: &[Token]) -> Result<Bytes, Error> {
+        if !matches_all(&self.inputs, args) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Arguments do not match the signature `{}`", self),
+            ));
+        }
+
+        Ok(encode_with_selector(self.selector(), args))
+    }
+}
+
+impl FromStr for Function {
+    type Err = Error;
+
+    /// Parses the signature, e.g. `transfer(address,uint256)`. Whitespace and the `uint` and `int`
+    /// aliases are accepted, whereas parameter names are not.
+    fn from_str(signature: &str) -> Result<Self, Self::Err> {
+        let invalid_signature = || {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("Invalid function signature `{}`", signature),
+            )
+        };
+
+        let (name, params) = signature
+            .trim()
+            .strip_suffix(')')
+            .and_then(|signature| signature.split_once('('))
+            .ok_or_else(invalid_signature)?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(invalid_signature());
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            inputs: parse_params(params)?,
+        })
+    }
+}
+
+impl Display for Function {
+    /// Formats the canonical signature, e.g. `transfer(address,uint256)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", self.name, join_params(&self.inputs))
+    }
+}
+
+/// Encodes the call of the function with the arguments into transaction `data`, e.g.:
+///
+/// ```rust
+/// use evm_signer_kms::evm_account::{abi::{encode_call, Token}, transaction::U256};
+///
+/// let recipient = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573".parse().unwrap();
+/// let data = encode_call(
+///     "transfer(address,uint256)",
+///     &[Token::Address(recipient), Token::Uint(U256::new(1_000_000))],
+/// )
+/// .unwrap();
+///
+/// assert_eq!(data.0[..4], [0xa9, 0x05, 0x9c, 0xbb]);
+/// ```
+pub fn encode_call(signature: &str, args: &[Token]) -> Result<Bytes, Error> {
+    signature.parse::<Function>()?.encode_call(args)
+}
+
+/// Computes the selector of the function signature.
+pub fn selector(signature: &str) -> Result<[u8; SELECTOR_LENGTH], Error> {
+    signature
+        .parse::<Function>()
+        .map(|function| function.selector())
+}
+
+/// Encodes the call of the function with the selector, e.g. a known constant, without checking the
+/// arguments against its signature.
+pub fn encode_with_selector(selector: [u8; SELECTOR_LENGTH], args: &[Token]) -> Bytes {
+    let mut data = selector.to_vec();
+    data.extend_from_slice(&encode(args));
+
+    Bytes(data)
+}
+
+/// Encodes the tokens as the tuple of their types, e.g. constructor arguments.
+pub fn encode(tokens: &[Token]) -> Vec<u8> {
+    let head_size: usize = tokens.iter().map(Token::head_size).sum();
+    let mut head = Vec::with_capacity(head_size);
+    let mut tail = vec![];
+
+    for token in tokens {
+        if token.is_dynamic() {
+            head.extend_from_slice(&usize_word(head_size + tail.len()));
+            tail.extend_from_slice(&encode_token(token));
+        } else {
+            head.extend_from_slice(&encode_token(token));
+        }
+    }
+    head.extend_from_slice(&tail);
+
+    head
+}
+
+/// Decodes the tuple of the types from the data, e.g. calldata stripped of its selector.
+///
+/// Fails if the data is truncated, offsets point out of it or values do not fit their types.
+pub fn decode(params: &[ParamType], data: &[u8]) -> Result<Vec<Token>, Error> {
+    let mut budget = data.len().div_ceil(WORD_LENGTH);
+
+    decode_tuple(params, data, 0, &mut budget)
+}
+
+fn encode_token(token: &Token) -> Vec<u8> {
+    match token {
+        Token::Address(address) => {
+            let mut word = vec![0u8; WORD_LENGTH];
+            word[ADDRESS_OFFSET..].copy_from_slice(address.as_slice());
+            word
+        }
+        Token::Bool(value) => usize_word(usize::from(*value)).to_vec(),
+        Token::Uint(value) => value.to_be_bytes().to_vec(),
+        Token::Int(value) => value.to_be_bytes().to_vec(),
+        Token::FixedBytes(bytes) => padded(bytes),
+        Token::Bytes(bytes) => encode_bytes(bytes),
+        Token::String(value) => encode_bytes(value.as_bytes()),
+        Token::Array(tokens) => {
+            let mut encoding = usize_word(tokens.len()).to_vec();
+            encoding.extend_from_slice(&encode(tokens));
+            encoding
+        }
+        Token::FixedArray(tokens) | Token::Tuple(tokens) => encode(tokens),
+    }
+}
+
+// Decodes the tuple starting at `base`, which the offsets of its dynamic values are relative to.
+//
+// The `budget` is the number of words left to decode. Offsets of dynamic values may point to the
+// same data, which would otherwise decode nested arrays to exponentially more values than the
+// data holds.
+fn decode_tuple<'a>(
+    params: impl IntoIterator<Item = &'a ParamType>,
+    data: &[u8],
+    base: usize,
+    budget: &mut usize,
+) -> Result<Vec<Token>, Error> {
+    let mut head = base;
+
+    params
+        .into_iter()
+        .map(|param| {
+            let position = if param.is_dynamic() {
+                base.checked_add(read_usize(data, head)?)
+                    .ok_or_else(|| invalid_data("Offset out of range"))?
+            } else {
+                head
+            };
+            head = head.saturating_add(param.head_size());
+
+            decode_param(param, data, position, budget)
+        })
+        .collect()
+}
+
+fn decode_param(
+    param: &ParamType,
+    data: &[u8],
+    position: usize,
+    budget: &mut usize,
+) -> Result<Token, Error> {
+    // Each value takes a word, except arrays of fixed length and tuples which take the words of
+    // their elements
+    if !matches!(param, ParamType::FixedArray(..) | ParamType::Tuple(_)) {
+        spend(budget, 1)?;
+    }
+
+    let token = match param {
+        ParamType::Address => {
+            let word = read_word(data, position)?;
+            if word[..ADDRESS_OFFSET].iter().any(|byte| *byte != 0) {
+                return Err(invalid_data("Address is not zero padded"));
+            }
+            Token::Address(AccountAddress::try_from(&word[ADDRESS_OFFSET..])?)
+        }
+        ParamType::Bool => match U256::from_be_bytes(*read_word(data, position)?) {
+            value if value == U256::ZERO => Token::Bool(false),
+            value if value == U256::ONE => Token::Bool(true),
+            _ => return Err(invalid_data("Invalid boolean")),
+        },
+        ParamType::Uint(_) => Token::Uint(U256::from_be_bytes(*read_word(data, position)?)),
+        ParamType::Int(_) => Token::Int(I256::from_be_bytes(*read_word(data, position)?)),
+        ParamType::FixedBytes(length) => {
+            Token::FixedBytes(read_word(data, position)?[..*length].to_vec())
+        }
+        ParamType::Bytes => Token::Bytes(read_bytes(data, position, budget)?.to_vec()),
+        ParamType::String => Token::String(
+            String::from_utf8(read_bytes(data, position, budget)?.to_vec())
+                .map_err(|_| invalid_data("String is not valid UTF-8"))?,
+        ),
+        ParamType::Array(param) => {
+            let length = read_usize(data, position)?;
+            // Every element takes at least one word, which bounds the allocation below
+            if length > data.len() / WORD_LENGTH {
+                return Err(invalid_data("Array length out of range"));
+            }
+            let params = iter::repeat_n(param.as_ref(), length);
+            Token::Array(decode_tuple(params, data, position + WORD_LENGTH, budget)?)
+        }
+        ParamType::FixedArray(param, length) => {
+            // The elements are in the head, so it must fit in the data
+            param
+                .head_size()
+                .checked_mul(*length)
+                .and_then(|size| position.checked_add(size))
+                .filter(|end| *end <= data.len())
+                .ok_or_else(|| invalid_data("Fixed array length out of range"))?;
+            let params = iter::repeat_n(param.as_ref(), *length);
+            Token::FixedArray(decode_tuple(params, data, position, budget)?)
+        }
+        ParamType::Tuple(params) => Token::Tuple(decode_tuple(params, data, position, budget)?),
+    };
+
+    if !param.matches(&token) {
+        return Err(invalid_data(&format!("Value out of range of `{}`", param)));
+    }
+
+    Ok(token)
+}
+
+fn read_word(data: &[u8], position: usize) -> Result<&[u8; WORD_LENGTH], Error> {
+    position
+        .checked_add(WORD_LENGTH)
+        .and_then(|end| data.get(position..end))
+        .and_then(|word| word.try_into().ok())
+        .ok_or_else(|| invalid_data("Data is too short"))
+}
+
+fn read_usize(data: &[u8], position: usize) -> Result<usize, Error> {
+    usize::try_from(U256::from_be_bytes(*read_word(data, position)?))
+        .map_err(|_| invalid_data("Length or offset out of range"))
+}
+
+fn read_bytes<'a>(data: &'a [u8], position: usize, budget: &mut usize) -> Result<&'a [u8], Error> {
+    let length = read_usize(data, position)?;
+    let start = position + WORD_LENGTH;
+
+    let words = length.div_ceil(WORD_LENGTH);
+    // The bytes are padded to words, which must be in the data too
+    words
+        .checked_mul(WORD_LENGTH)
+        .and_then(|size| start.checked_add(size))
+        .filter(|end| *end <= data.len())
+        .ok_or_else(|| invalid_data("Data is too short"))?;
+    spend(budget, words)?;
+
+    Ok(&data[start..start + length])
+}
+
+// Takes the decoded words from the budget, failing if the data does not hold them.
+fn spend(budget: &mut usize, words: usize) -> Result<(), Error> {
+    *budget = budget
+        .checked_sub(words)
+        .ok_or_else(|| invalid_data("Offsets decode more values than the data holds"))?;
+
+    Ok(())
+}
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut encoding = usize_word(bytes.len()).to_vec();
+    encoding.extend_from_slice(&padded(bytes));
+
+    encoding
+}
+
+// Pads the bytes with zeros on the right to a multiple of the word length.
+fn padded(bytes: &[u8]) -> Vec<u8> {
+    let mut padded = bytes.to_vec();
+    padded.resize(bytes.len().div_ceil(WORD_LENGTH) * WORD_LENGTH, 0);
+
+    padded
+}
+
+fn usize_word(value: usize) -> [u8; WORD_LENGTH] {
+    U256::from(value as u64).to_be_bytes()
+}
+
+fn matches_all(params: &[ParamType], tokens: &[Token]) -> bool {
+    params.len() == tokens.len()
+        && params
+            .iter()
+            .zip(tokens)
+            .all(|(param, token)| param.matches(token))
+}
+
+// Splits the comma separated parameters, ignoring commas nested in tuples.
+fn parse_params(params: &str) -> Result<Vec<ParamType>, Error> {
+    if params.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut parsed = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, character) in params.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1).ok_or_else(|| invalid_type(params))?,
+            ',' if depth == 0 => {
+                parsed.push(params[start..index].parse()?);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parsed.push(params[start..].parse()?);
+
+    Ok(parsed)
+}
+
+fn parse_sized(param: &str) -> Option<ParamType> {
+    if let Some(bits) = param.strip_prefix("uint") {
+        parse_bits(bits).map(ParamType::Uint)
+    } else if let Some(bits) = param.strip_prefix("int") {
+        parse_bits(bits).map(ParamType::Int)
+    } else if let Some(length) = param.strip_prefix("bytes") {
+        length
+            .parse()
+            .ok()
+            .filter(|length| (1..=MAX_FIXED_BYTES_LENGTH).contains(length))
+            .map(ParamType::FixedBytes)
+    } else {
+        None
+    }
+}
+
+fn parse_bits(bits: &str) -> Option<usize> {
+    bits.parse()
+        .ok()
+        .filter(|bits| bits % 8 == 0 && (8..=MAX_INTEGER_BITS).contains(bits))
+}
+
+fn join_params(params: &[ParamType]) -> String {
+    params
+        .iter()
+        .map(ParamType::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn join_tokens(tokens: &[Token]) -> String {
+    tokens
+        .iter()
+        .map(Token::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Invalid ABI encoding: {}", message),
+    )
+}
+
+fn invalid_type(param: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!("Invalid ABI type `{}`", param),
+    )
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    const TEST_ADDRESS_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+
+    fn words(hex_words: &[&str]) -> Vec<u8> {
+        hex_words
+            .iter()
+            .flat_map(|word| hex::decode(format!("{:0>64}", word)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn param_type_parse_succeed() {
+        let input = "(address, uint)[2][]";
+
+        let right: ParamType = input.parse().unwrap();
+
+        assert_eq!(
+            ParamType::Array(Box::new(ParamType::FixedArray(
+                Box::new(ParamType::Tuple(vec![
+                    ParamType::Address,
+                    ParamType::Uint(256)
+                ])),
+                2
+            ))),
+            right
+        );
+        assert_eq!("(address,uint256)[2][]", right.to_string());
+    }
+
+    #[test]
+    fn param_type_parse_invalid_size_fail() {
+        assert!("uint7".parse::<ParamType>().is_err());
+        assert!("uint264".parse::<ParamType>().is_err());
+        assert!("bytes0".parse::<ParamType>().is_err());
+        assert!("bytes33".parse::<ParamType>().is_err());
+        assert!("uint256[x]".parse::<ParamType>().is_err());
+        assert!("mapping".parse::<ParamType>().is_err());
+    }
+
+    #[test]
+    fn function_parse_succeed() {
+        let right: Function = " transfer(address, uint) ".parse().unwrap();
+
+        assert_eq!("transfer(address,uint256)", right.to_string());
+        assert_eq!([0xa9, 0x05, 0x9c, 0xbb], right.selector());
+    }
+
+    #[test]
+    fn function_parse_fail() {
+        assert!("transfer".parse::<Function>().is_err());
+        assert!("(address)".parse::<Function>().is_err());
+        assert!("transfer(address))".parse::<Function>().is_err());
+    }
+
+    #[test]
+    fn encode_call_transfer_succeed() {
+        let input = [
+            Token::Address(TEST_ADDRESS_STR.parse().unwrap()),
+            Token::Uint(U256::new(1_000_000)),
+        ];
+        let mut left = vec![0xa9, 0x05, 0x9c, 0xbb];
+        left.extend_from_slice(&words(&[
+            "a9d89186caa663c8ef0352fd1db3596280625573",
+            "f4240",
+        ]));
+
+        let right = encode_call("transfer(address,uint256)", &input).unwrap();
+
+        assert_eq!(left, right.0);
+    }
+
+    #[test]
+    fn encode_call_mismatch_fail() {
+        let input = [Token::Uint(U256::new(1))];
+
+        assert!(encode_call("transfer(address,uint256)", &input).is_err());
+        assert!(encode_call("approve(address)", &input).is_err());
+        assert!(encode_call("set(uint8)", &[Token::Uint(U256::new(256))]).is_err());
+        assert!(encode_call("set(int8)", &[Token::Int(I256::new(-129))]).is_err());
+    }
+
+    #[test]
+    fn encode_call_int_succeed() {
+        let input = [Token::Int(I256::new(-128))];
+
+        let right = encode_call("set(int8)", &input).unwrap();
+
+        assert_eq!(&[0xffu8; 31], &right.0[4..35]);
+        assert_eq!(0x80, right.0[35]);
+    }
+
+    #[test]
+    fn encode_string_succeed() {
+        let input = [Token::String("hello".to_string())];
+
+        let right = encode(&input);
+
+        assert_eq!(
+            words(&[
+                "20",
+                "5",
+                "68656c6c6f000000000000000000000000000000000000000000000000000000"
+            ]),
+            right
+        );
+    }
+
+    #[test]
+    fn encode_dynamic_array_succeed() {
+        let input = [Token::Array(vec![
+            Token::Uint(U256::new(1)),
+            Token::Uint(U256::new(2)),
+        ])];
+
+        let right = encode(&input);
+
+        assert_eq!(words(&["20", "2", "1", "2"]), right);
+    }
+
+    #[test]
+    fn encode_static_tuple_head_succeed() {
+        let input = [
+            Token::Tuple(vec![Token::Uint(U256::new(1)), Token::Bool(true)]),
+            Token::Bytes(vec![0xab]),
+        ];
+
+        let right = encode(&input);
+
+        assert_eq!(
+            words(&[
+                "1",
+                "1",
+                "60",
+                "1",
+                "ab00000000000000000000000000000000000000000000000000000000000000"
+            ]),
+            right
+        );
+    }
+
+    #[test]
+    fn encode_empty_succeed() {
+        assert!(encode(&[]).is_empty());
+    }
+
+    #[test]
+    fn decode_round_trip_succeed() {
+        let params: Vec<ParamType> = ["(address,uint8)[]", "string", "bytes4[2]", "int16", "bool"]
+            .iter()
+            .map(|param| param.parse().unwrap())
+            .collect();
+        let left = vec![
+            Token::Array(vec![Token::Tuple(vec![
+                Token::Address(TEST_ADDRESS_STR.parse().unwrap()),
+                Token::Uint(U256::new(7)),
+            ])]),
+            Token::String("hello".to_string()),
+            Token::FixedArray(vec![
+                Token::FixedBytes(vec![0xa9, 0x05, 0x9c, 0xbb]),
+                Token::FixedBytes(vec![0x09, 0x5e, 0xa7, 0xb3]),
+            ]),
+            Token::Int(I256::new(-300)),
+            Token::Bool(true),
+        ];
+
+        let right = decode(&params, &encode(&left)).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn decode_truncated_fail() {
+        let input = encode(&[Token::String("hello".to_string())]);
+
+        let error = decode(&[ParamType::String], &input[..input.len() - 1]).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn decode_out_of_range_fail() {
+        let input = encode(&[Token::Uint(U256::new(256))]);
+
+        assert!(decode(&[ParamType::Uint(8)], &input).is_err());
+        assert!(decode(&[ParamType::Bool], &input).is_err());
+    }
+
+    #[test]
+    fn decode_array_length_out_of_range_fail() {
+        let input = words(&["20", "ffffffffffff"]);
+
+        assert!(decode(&["uint256[]".parse().unwrap()], &input).is_err());
+    }
+
+    #[test]
+    fn decode_shared_offsets_fail() {
+        // The three elements of the outer array all point to the same inner array
+        let input = words(&["20", "3", "60", "60", "60", "3", "1", "1", "1"]);
+
+        let error = decode(&["uint256[][]".parse().unwrap()], &input).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn decode_fixed_array_length_out_of_range_fail() {
+        let input = words(&["1", "2"]);
+        let param = ParamType::FixedArray(Box::new(ParamType::Uint(256)), usize::MAX);
+
+        assert!(decode(std::slice::from_ref(&param), &input).is_err());
+        assert!(decode(&[ParamType::FixedArray(Box::new(param), 2)], &input).is_err());
+    }
+
+    #[test]
+    fn token_display_succeed() {
+        let input = Token::Tuple(vec![
+            Token::Address(TEST_ADDRESS_STR.parse().unwrap()),
+            Token::Array(vec![Token::Int(I256::new(-1)), Token::Int(I256::new(2))]),
+            Token::String("hi".to_string()),
+            Token::Bytes(vec![0xab]),
+        ]);
+
+        assert_eq!(
+            format!("({}, [-1, 2], \"hi\", 0xab)", TEST_ADDRESS_STR),
+            input.to_string()
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use std::{
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+
+use serde::Deserialize;
+
+use super::{decode, Function, ParamType, Token, SELECTOR_LENGTH};
+
+const CONSTRUCTOR_ITEM_TYPE: &str = "constructor";
+const FUNCTION_ITEM_TYPE: &str = "function";
+const TUPLE_TYPE: &str = "tuple";
+
+/// Constructor and functions of a contract ABI, e.g. as emitted by `solc --abi`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Abi {
+    constructor_inputs: Vec<ParamType>,
+    functions: Vec<AbiFunction>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct AbiFunction {
+    function: Function,
+    input_names: Vec<String>,
+    selector: [u8; SELECTOR_LENGTH],
+}
+
+impl Abi {
+    /// Types of the constructor parameters, which are empty if the ABI declares no constructor.
+    pub fn constructor_inputs(&self) -> &[ParamType] {
+        &self.constructor_inputs
+    }
+
+    /// Looks up the function with the selector.
+    pub fn function(&self, selector: &[u8; SELECTOR_LENGTH]) -> Option<&Function> {
+        self.find(selector).map(|function| &function.function)
+    }
+
+    fn find(&self, selector: &[u8; SELECTOR_LENGTH]) -> Option<&AbiFunction> {
+        self.functions
+            .iter()
+            .find(|function| function.selector == *selector)
+    }
+}
+
+impl FromStr for Abi {
+    type Err = Error;
+
+    /// Parses the ABI JSON array, ignoring items other than the constructor and functions (e.g.
+    /// events and errors).
+    fn from_str(abi: &str) -> Result<Self, Self::Err> {
+        let items: Vec<AbiItem> =
+            serde_json::from_str(abi).map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+
+        let constructor_inputs = items
+            .iter()
+            .find(|item| item.item_type == CONSTRUCTOR_ITEM_TYPE)
+            .map(|item| item.inputs.iter().map(AbiParam::param_type).collect())
+            .transpose()?
+            .unwrap_or_default();
+
+        let functions = items
+            .into_iter()
+            .filter(|item| item.item_type == FUNCTION_ITEM_TYPE)
+            .map(|item| {
+                let inputs = item
+                    .inputs
+                    .iter()
+                    .map(AbiParam::param_type)
+                    .collect::<Result<_, _>>()?;
+                let function = Function {
+                    name: item.name,
+                    inputs,
+                };
+
+                Ok(AbiFunction {
+                    selector: function.selector(),
+                    input_names: item.inputs.into_iter().map(|input| input.name).collect(),
+                    function,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            constructor_inputs,
+            functions,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct AbiItem {
+    #[serde(rename = "type")]
+    item_type: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    inputs: Vec<AbiParam>,
+}
+
+#[derive(Deserialize)]
+struct AbiParam {
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "type")]
+    param_type: String,
+    #[serde(default)]
+    components: Vec<AbiParam>,
+}
+
+impl AbiParam {
+    // Tuples are declared as `tuple` (or e.g. `tuple[]`) with the types in `components`.
+    fn param_type(&self) -> Result<ParamType, Error> {
+        match self.param_type.strip_prefix(TUPLE_TYPE) {
+            Some(array_suffix) => {
+                let components = self
+                    .components
+                    .iter()
+                    .map(|component| component.param_type().map(|param| param.to_string()))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                format!("({}){}", components.join(","), array_suffix).parse()
+            }
+            None => self.param_type.parse(),
+        }
+    }
+}
+
+/// Argument of a decoded function call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedArg {
+    /// Name of the parameter in the ABI, which may be empty.
+    pub name: String,
+    /// Type of the parameter.
+    pub param_type: ParamType,
+    /// Value of the argument.
+    pub value: Token,
+}
+
+/// Function call decoded from transaction `data`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedCall {
+    /// Called function.
+    pub function: Function,
+    /// Arguments of the call, in the order of the parameters.
+    pub args: Vec<DecodedArg>,
+}
+
+impl Display for DecodedCall {
+    /// Formats the call for humans, e.g. `transfer(to: 0xa9d8...5573, amount: 1000000)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| {
+                if arg.name.is_empty() {
+                    arg.value.to_string()
+                } else {
+                    format!("{}: {}", arg.name, arg.value)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        write!(f, "{}({})", self.function.name, args.join(", "))
+    }
+}
+
+/// Decodes the transaction `data` into the call of a function of the ABI, e.g. to display it in
+/// approval flows.
+///
+/// Fails with `ErrorKind::NotFound` if no function of the ABI matches the selector.
+pub fn decode_calldata(abi: &Abi, data: &[u8]) -> Result<DecodedCall, Error> {
+    if data.len() < SELECTOR_LENGTH {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Calldata is shorter than a function selector",
+        ));
+    }
+    let (selector, encoded_args) = data.split_at(SELECTOR_LENGTH);
+    let mut selector_bytes = [0u8; SELECTOR_LENGTH];
+    selector_bytes.copy_from_slice(selector);
+
+    let function = abi.find(&selector_bytes).ok_or_else(|| {
+        Error::new(
+            ErrorKind::NotFound,
+            format!("No function with selector 0x{}", hex::encode(selector)),
+        )
+    })?;
+    let values = decode(&function.function.inputs, encoded_args)?;
+
+    let args = function
+        .input_names
+        .iter()
+        .zip(&function.function.inputs)
+        .zip(values)
+        .map(|((name, param_type), value)| DecodedArg {
+            name: name.clone(),
+            param_type: param_type.clone(),
+            value,
+        })
+        .collect();
+
+    Ok(DecodedCall {
+        function: function.function.clone(),
+        args,
+    })
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::{abi::encode_call, transaction::U256};
+
+    const TEST_ADDRESS_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+    const TEST_ABI: &str = r#"[
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                {"name": "to", "type": "address"},
+                {"name": "amount", "type": "uint256"}
+            ],
+            "outputs": [{"name": "", "type": "bool"}],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "submit",
+            "inputs": [
+                {
+                    "name": "orders",
+                    "type": "tuple[]",
+                    "components": [
+                        {"name": "maker", "type": "address"},
+                        {"name": "amount", "type": "uint96"}
+                    ]
+                }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "Transfer",
+            "inputs": [
+                {"name": "from", "type": "address", "indexed": true},
+                {"name": "to", "type": "address", "indexed": true},
+                {"name": "value", "type": "uint256", "indexed": false}
+            ]
+        }
+    ]"#;
+
+    #[test]
+    fn abi_parse_succeed() {
+        let right: Abi = TEST_ABI.parse().unwrap();
+
+        assert_eq!(2, right.functions.len());
+        assert!(right.constructor_inputs().is_empty());
+        assert_eq!(
+            "submit((address,uint96)[])",
+            right.functions[1].function.to_string()
+        );
+        assert!(right.function(&[0xa9, 0x05, 0x9c, 0xbb]).is_some());
+    }
+
+    #[test]
+    fn abi_parse_invalid_type_fail() {
+        let input = r#"[{"type": "function", "name": "f", "inputs": [{"type": "uint7"}]}]"#;
+
+        assert!(input.parse::<Abi>().is_err());
+    }
+
+    #[test]
+    fn decode_calldata_succeed() {
+        let abi: Abi = TEST_ABI.parse().unwrap();
+        let input = encode_call(
+            "transfer(address,uint256)",
+            &[
+                Token::Address(TEST_ADDRESS_STR.parse().unwrap()),
+                Token::Uint(U256::new(1_000_000)),
+            ],
+        )
+        .unwrap();
+
+        let right = decode_calldata(&abi, &input).unwrap();
+
+        assert_eq!("transfer", right.function.name);
+        assert_eq!("to", right.args[0].name);
+        assert_eq!(Token::Uint(U256::new(1_000_000)), right.args[1].value);
+        assert_eq!(
+            format!("transfer(to: {}, amount: 1000000)", TEST_ADDRESS_STR),
+            right.to_string()
+        );
+    }
+
+    #[test]
+    fn decode_calldata_tuple_array_succeed() {
+        let abi: Abi = TEST_ABI.parse().unwrap();
+        let input = encode_call(
+            "submit((address,uint96)[])",
+            &[Token::Array(vec![Token::Tuple(vec![
+                Token::Address(TEST_ADDRESS_STR.parse().unwrap()),
+                Token::Uint(U256::new(5)),
+            ])])],
+        )
+        .unwrap();
+
+        let right = decode_calldata(&abi, &input).unwrap();
+
+        assert_eq!(
+            format!("submit(orders: [({}, 5)])", TEST_ADDRESS_STR),
+            right.to_string()
+        );
+    }
+
+    #[test]
+    fn decode_calldata_unknown_selector_fail() {
+        let abi: Abi = TEST_ABI.parse().unwrap();
+
+        let error = decode_calldata(&abi, &[0xde, 0xad, 0xbe, 0xef]).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn decode_calldata_too_short_fail() {
+        let abi: Abi = TEST_ABI.parse().unwrap();
+
+        assert!(decode_calldata(&abi, &[0xa9, 0x05]).is_err());
+    }
+}
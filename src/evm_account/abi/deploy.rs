@@ -0,0 +1,180 @@
+use std::io::{Error, ErrorKind};
+
+use super::{decoder::Abi, encode, matches_all, Token, ADDRESS_OFFSET};
+use crate::evm_account::{
+    hash::keccak256,
+    transaction::{
+        free_market_transaction::{FeeSettings, FreeMarketTransaction},
+        AccountAddress, Bytes, U256,
+    },
+};
+
+/// Contract creation transaction along with the address the contract is deployed at.
+#[derive(Debug)]
+pub struct Deployment {
+    /// Transaction creating the contract, i.e. with no recipient.
+    pub transaction: FreeMarketTransaction,
+    /// Address of the contract, provided the transaction is included with its nonce.
+    pub contract_address: AccountAddress,
+}
+
+/// Builds the transaction deploying the contract bytecode with the constructor arguments encoded
+/// according to the ABI, which is sent by the deployer with the nonce.
+///
+/// Fails with `ErrorKind::InvalidInput` if the arguments do not match the constructor.
+pub fn deploy(
+    bytecode: Bytes,
+    constructor_args: &[Token],
+    abi: &Abi,
+    deployer: AccountAddress,
+    nonce: u128,
+    gas_limit: u128,
+    fee_settings: FeeSettings,
+) -> Result<Deployment, Error> {
+    let data = deployment_data(bytecode, constructor_args, abi)?;
+
+    let transaction = FreeMarketTransaction {
+        gas_limit,
+        max_fee_per_gas: fee_settings.max_fee_per_gas,
+        max_priority_fee_per_gas: fee_settings.max_priority_fee_per_gas,
+        chain_id: fee_settings.chain_id,
+        nonce,
+        to: None,
+        value: U256::ZERO,
+        data,
+        access_list: vec![],
+    };
+
+    Ok(Deployment {
+        transaction,
+        contract_address: contract_address(deployer, nonce),
+    })
+}
+
+/// Calldata of the contract creation, i.e. the bytecode followed by the encoded constructor
+/// arguments.
+pub fn deployment_data(
+    bytecode: Bytes,
+    constructor_args: &[Token],
+    abi: &Abi,
+) -> Result<Bytes, Error> {
+    if !matches_all(abi.constructor_inputs(), constructor_args) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "Arguments do not match the constructor",
+        ));
+    }
+
+    let mut data = bytecode.0;
+    data.extend_from_slice(&encode(constructor_args));
+
+    Ok(Bytes(data))
+}
+
+/// Computes the address of the contract created by the deployer with the nonce, i.e. the last 20
+/// bytes of the digest of the RLP encoded `[deployer, nonce]`.
+pub fn contract_address(deployer: AccountAddress, nonce: u128) -> AccountAddress {
+    let mut rlp_stream = rlp::RlpStream::new_list(2);
+    rlp_stream.append(&deployer.as_slice()).append(&nonce);
+
+    let digest = keccak256(rlp_stream.out());
+    let mut address = AccountAddress::default();
+    // The digest is a word, which ends with the address like ABI encoded addresses
+    address.0.copy_from_slice(&digest[ADDRESS_OFFSET..]);
+
+    address
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    const TEST_DEPLOYER_STR: &str = "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0";
+    const TEST_ADDRESS_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+    const TEST_ABI: &str = r#"[
+        {
+            "type": "constructor",
+            "inputs": [
+                {"name": "owner", "type": "address"},
+                {"name": "supply", "type": "uint256"}
+            ],
+            "stateMutability": "nonpayable"
+        }
+    ]"#;
+
+    fn fee_settings() -> FeeSettings {
+        FeeSettings {
+            chain_id: 11155111,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
+        }
+    }
+
+    #[test]
+    fn contract_address_succeed() {
+        let deployer = TEST_DEPLOYER_STR.parse().unwrap();
+
+        for (nonce, left) in [
+            (0, "0xcd234A471b72ba2F1Ccf0A70FCABA648a5eeCD8d"),
+            (1, "0x343c43A37D37dfF08AE8C4A11544c718AbB4fCF8"),
+            (128, "0x08e190dcB7b73F5fcDAbb43e102215c83659A76D"),
+        ] {
+            assert_eq!(
+                left.parse::<AccountAddress>().unwrap(),
+                contract_address(deployer, nonce)
+            );
+        }
+    }
+
+    #[test]
+    fn deploy_succeed() {
+        let abi: Abi = TEST_ABI.parse().unwrap();
+        let deployer = TEST_DEPLOYER_STR.parse().unwrap();
+        let owner: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let args = [owner.into(), U256::new(1_000).into()];
+        let bytecode = Bytes(vec![0x60, 0x80, 0x60, 0x40]);
+        let mut left = bytecode.0.clone();
+        left.extend_from_slice(&encode(&args));
+
+        let right = deploy(
+            bytecode,
+            &args,
+            &abi,
+            deployer,
+            1,
+            1_000_000,
+            fee_settings(),
+        )
+        .unwrap();
+
+        assert_eq!(None, right.transaction.to);
+        assert_eq!(1, right.transaction.nonce);
+        assert_eq!(Bytes(left), right.transaction.data);
+        assert_eq!(
+            "0x343c43A37D37dfF08AE8C4A11544c718AbB4fCF8"
+                .parse::<AccountAddress>()
+                .unwrap(),
+            right.contract_address
+        );
+    }
+
+    #[test]
+    fn deployment_data_without_constructor_succeed() {
+        let abi: Abi = "[]".parse().unwrap();
+        let bytecode = Bytes(vec![0x60, 0x80, 0x60, 0x40]);
+
+        let right = deployment_data(bytecode.clone(), &[], &abi).unwrap();
+
+        assert_eq!(bytecode, right);
+    }
+
+    #[test]
+    fn deployment_data_mismatched_args_fail() {
+        let abi: Abi = TEST_ABI.parse().unwrap();
+        let input = [U256::new(1_000).into()];
+
+        let error = deployment_data(Bytes::default(), &input, &abi).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+}
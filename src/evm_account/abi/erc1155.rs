@@ -0,0 +1,204 @@
+use super::{encode_with_selector, Token, SELECTOR_LENGTH};
+use crate::evm_account::transaction::{
+    free_market_transaction::{FeeSettings, FreeMarketTransaction},
+    AccountAddress, Bytes, U256,
+};
+
+/// Gas limit of the built single transfers, covering transfers to contracts implementing
+/// `onERC1155Received`. Can be tightened with `rpc::gas::estimate_gas`.
+pub const GAS_LIMIT: u128 = 150_000;
+
+/// Additional gas limit of the built batch transfers per transferred token ID.
+pub const BATCH_GAS_LIMIT_PER_ID: u128 = 50_000;
+
+// Selectors of `safeTransferFrom(address,address,uint256,uint256,bytes)` and
+// `safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)`.
+const SAFE_TRANSFER_FROM_SELECTOR: [u8; SELECTOR_LENGTH] = [0xf2, 0x42, 0x43, 0x2a];
+const SAFE_BATCH_TRANSFER_FROM_SELECTOR: [u8; SELECTOR_LENGTH] = [0x2e, 0xb2, 0xc2, 0xd6];
+
+/// Builds the transaction transferring `amount` of the token from the account (`from`) to the
+/// recipient.
+///
+/// Any `data` is passed to `onERC1155Received` of the recipient contract.
+pub fn safe_transfer_from(
+    contract: AccountAddress,
+    from: AccountAddress,
+    to: AccountAddress,
+    id: U256,
+    amount: U256,
+    data: Option<Bytes>,
+    fee_settings: FeeSettings,
+) -> FreeMarketTransaction {
+    FreeMarketTransaction::contract_call(
+        contract,
+        safe_transfer_from_data(from, to, id, amount, data),
+        GAS_LIMIT,
+        fee_settings,
+    )
+}
+
+/// Builds the transaction transferring the `(id, amount)` pairs of tokens from the account
+/// (`from`) to the recipient at once.
+///
+/// Any `data` is passed to `onERC1155BatchReceived` of the recipient contract.
+pub fn safe_batch_transfer_from(
+    contract: AccountAddress,
+    from: AccountAddress,
+    to: AccountAddress,
+    transfers: &[(U256, U256)],
+    data: Option<Bytes>,
+    fee_settings: FeeSettings,
+) -> FreeMarketTransaction {
+    let gas_limit = GAS_LIMIT + BATCH_GAS_LIMIT_PER_ID * transfers.len() as u128;
+
+    FreeMarketTransaction::contract_call(
+        contract,
+        safe_batch_transfer_from_data(from, to, transfers, data),
+        gas_limit,
+        fee_settings,
+    )
+}
+
+/// Calldata of `safeTransferFrom(address,address,uint256,uint256,bytes)`, with empty `data` if
+/// none is provided.
+pub fn safe_transfer_from_data(
+    from: AccountAddress,
+    to: AccountAddress,
+    id: U256,
+    amount: U256,
+    data: Option<Bytes>,
+) -> Bytes {
+    encode_with_selector(
+        SAFE_TRANSFER_FROM_SELECTOR,
+        &[
+            from.into(),
+            to.into(),
+            id.into(),
+            amount.into(),
+            data.unwrap_or_default().into(),
+        ],
+    )
+}
+
+/// Calldata of `safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)` with the IDs and
+/// amounts of the `(id, amount)` pairs, and empty `data` if none is provided.
+pub fn safe_batch_transfer_from_data(
+    from: AccountAddress,
+    to: AccountAddress,
+    transfers: &[(U256, U256)],
+    data: Option<Bytes>,
+) -> Bytes {
+    let (ids, amounts) = transfers
+        .iter()
+        .map(|&(id, amount)| (Token::from(id), Token::from(amount)))
+        .unzip();
+
+    encode_with_selector(
+        SAFE_BATCH_TRANSFER_FROM_SELECTOR,
+        &[
+            from.into(),
+            to.into(),
+            Token::Array(ids),
+            Token::Array(amounts),
+            data.unwrap_or_default().into(),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::abi::encode_call;
+
+    const TEST_CONTRACT_STR: &str = "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238";
+    const TEST_ADDRESS_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+    const TEST_SENDER_STR: &str = "0x70AD754Ff670077411dF598FCfFd61c48299f12F";
+
+    fn fee_settings() -> FeeSettings {
+        FeeSettings {
+            chain_id: 1,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
+        }
+    }
+
+    #[test]
+    fn safe_transfer_from_succeed() {
+        let contract = TEST_CONTRACT_STR.parse().unwrap();
+        let from: AccountAddress = TEST_SENDER_STR.parse().unwrap();
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let left = encode_call(
+            "safeTransferFrom(address,address,uint256,uint256,bytes)",
+            &[
+                from.into(),
+                to.into(),
+                U256::new(42).into(),
+                U256::new(10).into(),
+                Bytes::default().into(),
+            ],
+        )
+        .unwrap();
+
+        let right = safe_transfer_from(
+            contract,
+            from,
+            to,
+            U256::new(42),
+            U256::new(10),
+            None,
+            fee_settings(),
+        );
+
+        assert_eq!(Some(contract), right.to);
+        assert_eq!(GAS_LIMIT, right.gas_limit);
+        assert_eq!(left, right.data);
+    }
+
+    #[test]
+    fn safe_batch_transfer_from_succeed() {
+        let contract = TEST_CONTRACT_STR.parse().unwrap();
+        let from: AccountAddress = TEST_SENDER_STR.parse().unwrap();
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let data = Bytes(vec![0xab, 0xcd]);
+        let left = encode_call(
+            "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+            &[
+                from.into(),
+                to.into(),
+                Token::Array(vec![U256::new(1).into(), U256::new(2).into()]),
+                Token::Array(vec![U256::new(10).into(), U256::new(20).into()]),
+                data.clone().into(),
+            ],
+        )
+        .unwrap();
+        let input = [(U256::new(1), U256::new(10)), (U256::new(2), U256::new(20))];
+
+        let right =
+            safe_batch_transfer_from(contract, from, to, &input, Some(data), fee_settings());
+
+        assert_eq!(Some(contract), right.to);
+        assert_eq!(GAS_LIMIT + 2 * BATCH_GAS_LIMIT_PER_ID, right.gas_limit);
+        assert_eq!(left, right.data);
+    }
+
+    #[test]
+    fn safe_batch_transfer_from_data_empty_succeed() {
+        let from: AccountAddress = TEST_SENDER_STR.parse().unwrap();
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let left = encode_call(
+            "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+            &[
+                from.into(),
+                to.into(),
+                Token::Array(vec![]),
+                Token::Array(vec![]),
+                Bytes::default().into(),
+            ],
+        )
+        .unwrap();
+
+        let right = safe_batch_transfer_from_data(from, to, &[], None);
+
+        assert_eq!(left, right);
+    }
+}
@@ -0,0 +1,142 @@
+use super::{encode_with_selector, SELECTOR_LENGTH};
+use crate::evm_account::transaction::{
+    free_market_transaction::{FeeSettings, FreeMarketTransaction},
+    AccountAddress, Bytes, U256,
+};
+
+/// Gas limit of the built transactions, covering `transfer` and `approve` of common tokens. Can
+/// be tightened with `rpc::gas::estimate_gas`.
+pub const GAS_LIMIT: u128 = 100_000;
+
+// Selectors of `transfer(address,uint256)`, `approve(address,uint256)` and
+// `transferFrom(address,address,uint256)`.
+const TRANSFER_SELECTOR: [u8; SELECTOR_LENGTH] = [0xa9, 0x05, 0x9c, 0xbb];
+const APPROVE_SELECTOR: [u8; SELECTOR_LENGTH] = [0x09, 0x5e, 0xa7, 0xb3];
+const TRANSFER_FROM_SELECTOR: [u8; SELECTOR_LENGTH] = [0x23, 0xb8, 0x72, 0xdd];
+
+/// Builds the transaction transferring `amount` (in the smallest unit of the token) of the token
+/// to the recipient.
+pub fn transfer(
+    token: AccountAddress,
+    to: AccountAddress,
+    amount: U256,
+    fee_settings: FeeSettings,
+) -> FreeMarketTransaction {
+    FreeMarketTransaction::contract_call(token, transfer_data(to, amount), GAS_LIMIT, fee_settings)
+}
+
+/// Builds the transaction allowing the spender to transfer up to `amount` of the token from the
+/// account.
+pub fn approve(
+    token: AccountAddress,
+    spender: AccountAddress,
+    amount: U256,
+    fee_settings: FeeSettings,
+) -> FreeMarketTransaction {
+    FreeMarketTransaction::contract_call(
+        token,
+        approve_data(spender, amount),
+        GAS_LIMIT,
+        fee_settings,
+    )
+}
+
+/// Builds the transaction transferring `amount` of the token from the owner to the recipient
+/// within the allowance of the account.
+pub fn transfer_from(
+    token: AccountAddress,
+    from: AccountAddress,
+    to: AccountAddress,
+    amount: U256,
+    fee_settings: FeeSettings,
+) -> FreeMarketTransaction {
+    FreeMarketTransaction::contract_call(
+        token,
+        transfer_from_data(from, to, amount),
+        GAS_LIMIT,
+        fee_settings,
+    )
+}
+
+/// Calldata of `transfer(address,uint256)`.
+pub fn transfer_data(to: AccountAddress, amount: U256) -> Bytes {
+    encode_with_selector(TRANSFER_SELECTOR, &[to.into(), amount.into()])
+}
+
+/// Calldata of `approve(address,uint256)`.
+pub fn approve_data(spender: AccountAddress, amount: U256) -> Bytes {
+    encode_with_selector(APPROVE_SELECTOR, &[spender.into(), amount.into()])
+}
+
+/// Calldata of `transferFrom(address,address,uint256)`.
+pub fn transfer_from_data(from: AccountAddress, to: AccountAddress, amount: U256) -> Bytes {
+    encode_with_selector(
+        TRANSFER_FROM_SELECTOR,
+        &[from.into(), to.into(), amount.into()],
+    )
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::abi::encode_call;
+
+    const TEST_TOKEN_STR: &str = "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238";
+    const TEST_ADDRESS_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+
+    fn fee_settings() -> FeeSettings {
+        FeeSettings {
+            chain_id: 11155111,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
+        }
+    }
+
+    #[test]
+    fn transfer_succeed() {
+        let token = TEST_TOKEN_STR.parse().unwrap();
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let amount = U256::new(1_000_000);
+
+        let right = transfer(token, to, amount, fee_settings());
+
+        assert_eq!(Some(token), right.to);
+        assert_eq!(U256::ZERO, right.value);
+        assert_eq!(GAS_LIMIT, right.gas_limit);
+        assert_eq!(11155111, right.chain_id);
+        assert_eq!(
+            "0xa9059cbb000000000000000000000000a9d89186caa663c8ef0352fd1db3596280625573\
+             00000000000000000000000000000000000000000000000000000000000f4240",
+            right.data.to_string()
+        );
+    }
+
+    #[test]
+    fn approve_data_succeed() {
+        let spender: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let left = encode_call(
+            "approve(address,uint256)",
+            &[spender.into(), U256::MAX.into()],
+        )
+        .unwrap();
+
+        let right = approve_data(spender, U256::MAX);
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn transfer_from_data_succeed() {
+        let from: AccountAddress = TEST_TOKEN_STR.parse().unwrap();
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let left = encode_call(
+            "transferFrom(address,address,uint256)",
+            &[from.into(), to.into(), U256::ONE.into()],
+        )
+        .unwrap();
+
+        let right = transfer_from_data(from, to, U256::ONE);
+
+        assert_eq!(left, right);
+    }
+}
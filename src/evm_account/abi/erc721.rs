@@ -0,0 +1,190 @@
+use super::{encode_with_selector, Token, SELECTOR_LENGTH};
+use crate::evm_account::transaction::{
+    free_market_transaction::{FeeSettings, FreeMarketTransaction},
+    AccountAddress, Bytes, U256,
+};
+
+/// Gas limit of the built transactions, covering transfers to contracts implementing
+/// `onERC721Received`. Can be tightened with `rpc::gas::estimate_gas`.
+pub const GAS_LIMIT: u128 = 150_000;
+
+// Selectors of `transferFrom(address,address,uint256)`,
+// `safeTransferFrom(address,address,uint256)`, `safeTransferFrom(address,address,uint256,bytes)`
+// and `setApprovalForAll(address,bool)`.
+const TRANSFER_FROM_SELECTOR: [u8; SELECTOR_LENGTH] = [0x23, 0xb8, 0x72, 0xdd];
+const SAFE_TRANSFER_FROM_SELECTOR: [u8; SELECTOR_LENGTH] = [0x42, 0x84, 0x2e, 0x0e];
+const SAFE_TRANSFER_FROM_WITH_DATA_SELECTOR: [u8; SELECTOR_LENGTH] = [0xb8, 0x8d, 0x4f, 0xde];
+const SET_APPROVAL_FOR_ALL_SELECTOR: [u8; SELECTOR_LENGTH] = [0xa2, 0x2c, 0xb4, 0x65];
+
+/// Builds the transaction transferring the token from the account (`from`) to the recipient,
+/// which reverts if the recipient is a contract not accepting NFTs.
+///
+/// Any `data` is passed to `onERC721Received` of the recipient contract.
+pub fn safe_transfer_from(
+    contract: AccountAddress,
+    from: AccountAddress,
+    to: AccountAddress,
+    token_id: U256,
+    data: Option<Bytes>,
+    fee_settings: FeeSettings,
+) -> FreeMarketTransaction {
+    FreeMarketTransaction::contract_call(
+        contract,
+        safe_transfer_from_data(from, to, token_id, data),
+        GAS_LIMIT,
+        fee_settings,
+    )
+}
+
+/// Builds the transaction transferring the token from the account (`from`) to the recipient
+/// without checking whether the recipient can handle NFTs.
+pub fn transfer_from(
+    contract: AccountAddress,
+    from: AccountAddress,
+    to: AccountAddress,
+    token_id: U256,
+    fee_settings: FeeSettings,
+) -> FreeMarketTransaction {
+    FreeMarketTransaction::contract_call(
+        contract,
+        transfer_from_data(from, to, token_id),
+        GAS_LIMIT,
+        fee_settings,
+    )
+}
+
+/// Builds the transaction allowing (or disallowing) the operator to transfer all tokens of the
+/// account in the collection.
+pub fn set_approval_for_all(
+    contract: AccountAddress,
+    operator: AccountAddress,
+    approved: bool,
+    fee_settings: FeeSettings,
+) -> FreeMarketTransaction {
+    FreeMarketTransaction::contract_call(
+        contract,
+        set_approval_for_all_data(operator, approved),
+        GAS_LIMIT,
+        fee_settings,
+    )
+}
+
+/// Calldata of `safeTransferFrom(address,address,uint256)` or, if `data` is provided,
+/// `safeTransferFrom(address,address,uint256,bytes)`.
+pub fn safe_transfer_from_data(
+    from: AccountAddress,
+    to: AccountAddress,
+    token_id: U256,
+    data: Option<Bytes>,
+) -> Bytes {
+    let mut args = vec![from.into(), to.into(), token_id.into()];
+
+    match data {
+        Some(data) => {
+            args.push(Token::from(data));
+            encode_with_selector(SAFE_TRANSFER_FROM_WITH_DATA_SELECTOR, &args)
+        }
+        None => encode_with_selector(SAFE_TRANSFER_FROM_SELECTOR, &args),
+    }
+}
+
+/// Calldata of `transferFrom(address,address,uint256)`.
+pub fn transfer_from_data(from: AccountAddress, to: AccountAddress, token_id: U256) -> Bytes {
+    encode_with_selector(
+        TRANSFER_FROM_SELECTOR,
+        &[from.into(), to.into(), token_id.into()],
+    )
+}
+
+/// Calldata of `setApprovalForAll(address,bool)`.
+pub fn set_approval_for_all_data(operator: AccountAddress, approved: bool) -> Bytes {
+    encode_with_selector(
+        SET_APPROVAL_FOR_ALL_SELECTOR,
+        &[operator.into(), approved.into()],
+    )
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::abi::encode_call;
+
+    const TEST_CONTRACT_STR: &str = "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238";
+    const TEST_ADDRESS_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+    const TEST_SENDER_STR: &str = "0x70AD754Ff670077411dF598FCfFd61c48299f12F";
+
+    fn fee_settings() -> FeeSettings {
+        FeeSettings {
+            chain_id: 1,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
+        }
+    }
+
+    #[test]
+    fn safe_transfer_from_succeed() {
+        let contract = TEST_CONTRACT_STR.parse().unwrap();
+        let from: AccountAddress = TEST_SENDER_STR.parse().unwrap();
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let left = encode_call(
+            "safeTransferFrom(address,address,uint256)",
+            &[from.into(), to.into(), U256::new(42).into()],
+        )
+        .unwrap();
+
+        let right = safe_transfer_from(contract, from, to, U256::new(42), None, fee_settings());
+
+        assert_eq!(Some(contract), right.to);
+        assert_eq!(GAS_LIMIT, right.gas_limit);
+        assert_eq!(left, right.data);
+    }
+
+    #[test]
+    fn safe_transfer_from_data_with_data_succeed() {
+        let from: AccountAddress = TEST_SENDER_STR.parse().unwrap();
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let data = Bytes(vec![0xab, 0xcd]);
+        let left = encode_call(
+            "safeTransferFrom(address,address,uint256,bytes)",
+            &[
+                from.into(),
+                to.into(),
+                U256::new(42).into(),
+                data.clone().into(),
+            ],
+        )
+        .unwrap();
+
+        let right = safe_transfer_from_data(from, to, U256::new(42), Some(data));
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn transfer_from_data_succeed() {
+        let from: AccountAddress = TEST_SENDER_STR.parse().unwrap();
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let left = encode_call(
+            "transferFrom(address,address,uint256)",
+            &[from.into(), to.into(), U256::ONE.into()],
+        )
+        .unwrap();
+
+        let right = transfer_from_data(from, to, U256::ONE);
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn set_approval_for_all_data_succeed() {
+        let operator: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+
+        let right = set_approval_for_all_data(operator, true);
+
+        assert_eq!(
+            "0xa22cb465000000000000000000000000a9d89186caa663c8ef0352fd1db3596280625573\
+             0000000000000000000000000000000000000000000000000000000000000001",
+            right.to_string()
+        );
+    }
+}
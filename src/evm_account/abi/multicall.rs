@@ -0,0 +1,254 @@
+use super::{encode_with_selector, Token, SELECTOR_LENGTH};
+use crate::evm_account::transaction::{
+    free_market_transaction::{FeeSettings, FreeMarketTransaction},
+    AccountAddress, Bytes, U256,
+};
+
+/// Address of the [`Multicall3`](https://www.multicall3.com) contract, deployed at the same address
+/// on most chains.
+pub const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+
+// Selectors of `aggregate3((address,bool,bytes)[])` and
+// `aggregate3Value((address,bool,uint256,bytes)[])`.
+const AGGREGATE3_SELECTOR: [u8; SELECTOR_LENGTH] = [0x82, 0xad, 0x56, 0xcb];
+const AGGREGATE3_VALUE_SELECTOR: [u8; SELECTOR_LENGTH] = [0x17, 0x4d, 0xea, 0x71];
+
+/// Call aggregated by `Multicall3`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call3 {
+    /// Called contract.
+    pub target: AccountAddress,
+    /// Whether the batch proceeds if the call reverts. Otherwise the whole batch reverts.
+    pub allow_failure: bool,
+    /// Value sent with the call, in Wei.
+    pub value: U256,
+    /// Calldata of the call.
+    pub data: Bytes,
+}
+
+impl Call3 {
+    /// Builds the call of the contract with no value attached, reverting the batch on failure.
+    pub fn new(target: AccountAddress, data: Bytes) -> Self {
+        Self {
+            target,
+            allow_failure: false,
+            value: U256::ZERO,
+            data,
+        }
+    }
+
+    /// Attaches the value to the call.
+    pub fn with_value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Lets the batch proceed if the call reverts.
+    pub fn allowing_failure(mut self) -> Self {
+        self.allow_failure = true;
+        self
+    }
+}
+
+/// Builder of the transaction aggregating calls into a single `Multicall3` call, so that a batch
+/// takes a single nonce.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Multicall {
+    address: AccountAddress,
+    calls: Vec<Call3>,
+}
+
+impl Default for Multicall {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Multicall {
+    /// Creates an empty batch for the canonical `Multicall3` deployment.
+    pub fn new() -> Self {
+        Self::with_address(
+            MULTICALL3_ADDRESS
+                .parse()
+                .expect("Multicall3 address is valid"),
+        )
+    }
+
+    /// Creates an empty batch for the `Multicall3` contract deployed at the address.
+    pub fn with_address(address: AccountAddress) -> Self {
+        Self {
+            address,
+            calls: vec![],
+        }
+    }
+
+    /// Appends the call to the batch.
+    pub fn push(&mut self, call: Call3) -> &mut Self {
+        self.calls.push(call);
+        self
+    }
+
+    /// Calls in the batch.
+    pub fn calls(&self) -> &[Call3] {
+        &self.calls
+    }
+
+    /// Number of calls in the batch.
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Whether the batch has no calls.
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Total value of the calls, which the transaction has to carry.
+    ///
+    /// Saturates at `U256::MAX`, which no account can fund.
+    pub fn value(&self) -> U256 {
+        self.calls
+            .iter()
+            .fold(U256::ZERO, |total, call| total.saturating_add(call.value))
+    }
+
+    /// Calldata of the batch: `aggregate3` if no call carries value and `aggregate3Value`
+    /// otherwise.
+    pub fn data(&self) -> Bytes {
+        let with_value = self.value() != U256::ZERO;
+        let calls = self
+            .calls
+            .iter()
+            .map(|call| {
+                let mut fields = vec![call.target.into(), call.allow_failure.into()];
+                if with_value {
+                    fields.push(call.value.into());
+                }
+                fields.push(call.data.clone().into());
+
+                Token::Tuple(fields)
+            })
+            .collect();
+        let selector = if with_value {
+            AGGREGATE3_VALUE_SELECTOR
+        } else {
+            AGGREGATE3_SELECTOR
+        };
+
+        encode_with_selector(selector, &[Token::Array(calls)])
+    }
+
+    /// Builds the transaction calling `Multicall3` with the batch and carrying its total value.
+    pub fn build(&self, gas_limit: u128, fee_settings: FeeSettings) -> FreeMarketTransaction {
+        let mut tx = FreeMarketTransaction::contract_call(
+            self.address,
+            self.data(),
+            gas_limit,
+            fee_settings,
+        );
+        tx.value = self.value();
+
+        tx
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::abi::{encode_call, erc20};
+
+    const TEST_TOKEN_STR: &str = "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238";
+    const TEST_ADDRESS_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+
+    fn fee_settings() -> FeeSettings {
+        FeeSettings {
+            chain_id: 11155111,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
+        }
+    }
+
+    #[test]
+    fn data_aggregate3_succeed() {
+        let token: AccountAddress = TEST_TOKEN_STR.parse().unwrap();
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let transfer = erc20::transfer_data(to, U256::ONE);
+        let left = encode_call(
+            "aggregate3((address,bool,bytes)[])",
+            &[Token::Array(vec![
+                Token::Tuple(vec![token.into(), false.into(), transfer.clone().into()]),
+                Token::Tuple(vec![to.into(), true.into(), Bytes::default().into()]),
+            ])],
+        )
+        .unwrap();
+        let mut multicall = Multicall::new();
+        multicall
+            .push(Call3::new(token, transfer))
+            .push(Call3::new(to, Bytes::default()).allowing_failure());
+
+        let right = multicall.data();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn data_aggregate3_value_succeed() {
+        let token: AccountAddress = TEST_TOKEN_STR.parse().unwrap();
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let left = encode_call(
+            "aggregate3Value((address,bool,uint256,bytes)[])",
+            &[Token::Array(vec![
+                Token::Tuple(vec![
+                    token.into(),
+                    false.into(),
+                    U256::ZERO.into(),
+                    Bytes::default().into(),
+                ]),
+                Token::Tuple(vec![
+                    to.into(),
+                    true.into(),
+                    U256::new(5).into(),
+                    Bytes::default().into(),
+                ]),
+            ])],
+        )
+        .unwrap();
+        let mut multicall = Multicall::new();
+        multicall.push(Call3::new(token, Bytes::default())).push(
+            Call3::new(to, Bytes::default())
+                .with_value(U256::new(5))
+                .allowing_failure(),
+        );
+
+        let right = multicall.data();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn build_succeed() {
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let mut multicall = Multicall::new();
+        multicall
+            .push(Call3::new(to, Bytes::default()).with_value(U256::new(2)))
+            .push(Call3::new(to, Bytes::default()).with_value(U256::new(3)));
+
+        let right = multicall.build(200_000, fee_settings());
+
+        assert_eq!(Some(MULTICALL3_ADDRESS.parse().unwrap()), right.to);
+        assert_eq!(U256::new(5), right.value);
+        assert_eq!(200_000, right.gas_limit);
+        assert_eq!(multicall.data(), right.data);
+    }
+
+    #[test]
+    fn value_saturates_succeed() {
+        let to: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let mut multicall = Multicall::new();
+        multicall
+            .push(Call3::new(to, Bytes::default()).with_value(U256::MAX))
+            .push(Call3::new(to, Bytes::default()).with_value(U256::ONE));
+
+        assert_eq!(U256::MAX, multicall.value());
+    }
+}
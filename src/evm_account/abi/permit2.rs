@@ -0,0 +1,404 @@
+use std::io::{Error, ErrorKind};
+
+use super::{encode, encode_with_selector, ParamType, Token, SELECTOR_LENGTH};
+#[cfg(feature = "aws")]
+use crate::evm_account::EvmAccount;
+use crate::evm_account::{
+    hash::{eip712_hash, keccak256, Keccak256Digest},
+    signature::Signature,
+    transaction::{AccountAddress, Bytes, U256},
+};
+
+/// Address of the Uniswap [`Permit2`](https://github.com/Uniswap/permit2) contract, deployed at the
+/// same address on most chains.
+pub const PERMIT2_ADDRESS: &str = "0x000000000022D473030F116dDEE9F6B43aC78BA3";
+
+const PERMIT2_NAME: &str = "Permit2";
+const DOMAIN_TYPE: &str = "EIP712Domain(string name,uint256 chainId,address verifyingContract)";
+const PERMIT_DETAILS_TYPE: &str =
+    "PermitDetails(address token,uint160 amount,uint48 expiration,uint48 nonce)";
+const PERMIT_SINGLE_TYPE: &str =
+    "PermitSingle(PermitDetails details,address spender,uint256 sigDeadline)";
+const PERMIT_BATCH_TYPE: &str =
+    "PermitBatch(PermitDetails[] details,address spender,uint256 sigDeadline)";
+
+// Bits of `uint160` amounts and `uint48` expirations and nonces.
+const AMOUNT_BITS: usize = 160;
+const EXPIRATION_BITS: usize = 48;
+
+// Selectors of `permit(address,((address,uint160,uint48,uint48),address,uint256),bytes)` and
+// `permit(address,((address,uint160,uint48,uint48)[],address,uint256),bytes)`.
+const PERMIT_SINGLE_SELECTOR: [u8; SELECTOR_LENGTH] = [0x2b, 0x67, 0xb5, 0x70];
+const PERMIT_BATCH_SELECTOR: [u8; SELECTOR_LENGTH] = [0x2a, 0x2d, 0x80, 0xd1];
+
+/// Allowance of a token granted by a permit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PermitDetails {
+    /// Approved token.
+    pub token: AccountAddress,
+    /// Approved amount, which must fit in `uint160`.
+    pub amount: U256,
+    /// Timestamp at which the allowance expires, which must fit in `uint48`.
+    pub expiration: u64,
+    /// Nonce of the owner, token and spender in Permit2, which must fit in `uint48`.
+    pub nonce: u64,
+}
+
+impl PermitDetails {
+    /// Creates the allowance of `amount` of the token until `expiration`.
+    pub fn new(token: AccountAddress, amount: U256, expiration: u64, nonce: u64) -> Self {
+        Self {
+            token,
+            amount,
+            expiration,
+            nonce,
+        }
+    }
+
+    fn to_token(&self) -> Result<Token, Error> {
+        self.fields().map(Token::Tuple)
+    }
+
+    fn fields(&self) -> Result<Vec<Token>, Error> {
+        let fields = vec![
+            self.token.into(),
+            self.amount.into(),
+            U256::from(self.expiration).into(),
+            U256::from(self.nonce).into(),
+        ];
+        let params = [
+            ParamType::Address,
+            ParamType::Uint(AMOUNT_BITS),
+            ParamType::Uint(EXPIRATION_BITS),
+            ParamType::Uint(EXPIRATION_BITS),
+        ];
+        if !params
+            .iter()
+            .zip(&fields)
+            .all(|(param, field)| param.matches(field))
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Permit amount, expiration or nonce is out of range",
+            ));
+        }
+
+        Ok(fields)
+    }
+
+    fn struct_hash(&self) -> Result<Keccak256Digest, Error> {
+        Ok(hash_struct(&type_hash(PERMIT_DETAILS_TYPE), self.fields()?))
+    }
+}
+
+/// Permit of a single token allowance, signed off-chain by the owner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PermitSingle {
+    /// Granted allowance.
+    pub details: PermitDetails,
+    /// Spender of the allowance.
+    pub spender: AccountAddress,
+    /// Timestamp after which the signature is invalid.
+    pub sig_deadline: U256,
+}
+
+impl PermitSingle {
+    /// Creates the permit granting the allowance to the spender.
+    pub fn new(details: PermitDetails, spender: AccountAddress, sig_deadline: U256) -> Self {
+        Self {
+            details,
+            spender,
+            sig_deadline,
+        }
+    }
+
+    /// Computes the EIP-712 digest of the permit on the chain. Fails if the allowance is out of
+    /// range.
+    pub fn signing_hash(&self, chain_id: u64) -> Result<Keccak256Digest, Error> {
+        let struct_hash = hash_struct(
+            &type_hash(&format!("{}{}", PERMIT_SINGLE_TYPE, PERMIT_DETAILS_TYPE)),
+            vec![
+                digest_token(&self.details.struct_hash()?),
+                self.spender.into(),
+                self.sig_deadline.into(),
+            ],
+        );
+
+        Ok(eip712_hash(&domain_separator(chain_id), &struct_hash))
+    }
+
+    /// Signs the permit on the chain with the account of the owner.
+    #[cfg(feature = "aws")]
+    pub async fn sign(&self, account: &EvmAccount<'_>, chain_id: u64) -> Result<Signature, Error> {
+        account
+            .sign_typed_data_hash(&self.signing_hash(chain_id)?)
+            .await
+    }
+
+    /// Calldata of the Permit2 `permit` call submitting the permit signed by the owner.
+    pub fn permit_data(
+        &self,
+        owner: AccountAddress,
+        signature: &Signature,
+    ) -> Result<Bytes, Error> {
+        let permit = Token::Tuple(vec![
+            self.details.to_token()?,
+            self.spender.into(),
+            self.sig_deadline.into(),
+        ]);
+
+        Ok(encode_with_selector(
+            PERMIT_SINGLE_SELECTOR,
+            &[
+                owner.into(),
+                permit,
+                Token::Bytes(signature.to_bytes().to_vec()),
+            ],
+        ))
+    }
+}
+
+/// Permit of multiple token allowances, signed off-chain by the owner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PermitBatch {
+    /// Granted allowances.
+    pub details: Vec<PermitDetails>,
+    /// Spender of the allowances.
+    pub spender: AccountAddress,
+    /// Timestamp after which the signature is invalid.
+    pub sig_deadline: U256,
+}
+
+impl PermitBatch {
+    /// Creates the permit granting the allowances to the spender.
+    pub fn new(details: Vec<PermitDetails>, spender: AccountAddress, sig_deadline: U256) -> Self {
+        Self {
+            details,
+            spender,
+            sig_deadline,
+        }
+    }
+
+    /// Computes the EIP-712 digest of the permit on the chain. Fails if any allowance is out of
+    /// range.
+    pub fn signing_hash(&self, chain_id: u64) -> Result<Keccak256Digest, Error> {
+        let details_hashes = self
+            .details
+            .iter()
+            .map(PermitDetails::struct_hash)
+            .collect::<Result<Vec<_>, _>>()?
+            .concat();
+        let struct_hash = hash_struct(
+            &type_hash(&format!("{}{}", PERMIT_BATCH_TYPE, PERMIT_DETAILS_TYPE)),
+            vec![
+                digest_token(&keccak256(details_hashes)),
+                self.spender.into(),
+                self.sig_deadline.into(),
+            ],
+        );
+
+        Ok(eip712_hash(&domain_separator(chain_id), &struct_hash))
+    }
+
+    /// Signs the permit on the chain with the account of the owner.
+    #[cfg(feature = "aws")]
+    pub async fn sign(&self, account: &EvmAccount<'_>, chain_id: u64) -> Result<Signature, Error> {
+        account
+            .sign_typed_data_hash(&self.signing_hash(chain_id)?)
+            .await
+    }
+
+    /// Calldata of the Permit2 `permit` call submitting the permit signed by the owner.
+    pub fn permit_data(
+        &self,
+        owner: AccountAddress,
+        signature: &Signature,
+    ) -> Result<Bytes, Error> {
+        let details = self
+            .details
+            .iter()
+            .map(PermitDetails::to_token)
+            .collect::<Result<_, _>>()?;
+        let permit = Token::Tuple(vec![
+            Token::Array(details),
+            self.spender.into(),
+            self.sig_deadline.into(),
+        ]);
+
+        Ok(encode_with_selector(
+            PERMIT_BATCH_SELECTOR,
+            &[
+                owner.into(),
+                permit,
+                Token::Bytes(signature.to_bytes().to_vec()),
+            ],
+        ))
+    }
+}
+
+/// Computes the EIP-712 domain separator of Permit2 on the chain.
+pub fn domain_separator(chain_id: u64) -> Keccak256Digest {
+    let permit2: AccountAddress = PERMIT2_ADDRESS.parse().expect("Permit2 address is valid");
+
+    hash_struct(
+        &type_hash(DOMAIN_TYPE),
+        vec![
+            digest_token(&keccak256(PERMIT2_NAME)),
+            U256::from(chain_id).into(),
+            permit2.into(),
+        ],
+    )
+}
+
+fn type_hash(encoded_type: &str) -> Keccak256Digest {
+    keccak256(encoded_type)
+}
+
+// Hashes the type hash followed by the fields, which are all static (i.e. one word each).
+fn hash_struct(type_hash: &Keccak256Digest, fields: Vec<Token>) -> Keccak256Digest {
+    let mut tokens = vec![digest_token(type_hash)];
+    tokens.extend(fields);
+
+    keccak256(encode(&tokens))
+}
+
+fn digest_token(digest: &Keccak256Digest) -> Token {
+    Token::FixedBytes(digest.to_vec())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::abi::encode_call;
+
+    const TEST_TOKEN_STR: &str = "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238";
+    const TEST_SPENDER_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+    const TEST_OWNER_STR: &str = "0x70AD754Ff670077411dF598FCfFd61c48299f12F";
+    const TEST_CHAIN_ID: u64 = 11155111;
+    const TEST_DEADLINE: u64 = 1_700_000_000;
+
+    fn permit_details() -> PermitDetails {
+        PermitDetails::new(
+            TEST_TOKEN_STR.parse().unwrap(),
+            U256::new(1_000_000),
+            TEST_DEADLINE,
+            0,
+        )
+    }
+
+    #[test]
+    fn domain_separator_succeed() {
+        let left = "94c1dec87927751697bfc9ebf6fc4ca506bed30308b518f0e9d6c5f74bbafdb8";
+
+        let right = hex::encode(domain_separator(TEST_CHAIN_ID));
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn permit_single_signing_hash_succeed() {
+        let left = "23947a7afe0826e5a4fc7d247b15337879186077b845a49da61d938005567bc4";
+        let input = PermitSingle::new(
+            permit_details(),
+            TEST_SPENDER_STR.parse().unwrap(),
+            U256::from(TEST_DEADLINE),
+        );
+
+        let right = hex::encode(input.signing_hash(TEST_CHAIN_ID).unwrap());
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn permit_batch_signing_hash_succeed() {
+        let left = "61a1e146bab07a6fe9a614b45c43ce982b615a3d0d45da0979043018ed1e0ae0";
+        let input = PermitBatch::new(
+            vec![
+                permit_details(),
+                PermitDetails::new(
+                    TEST_SPENDER_STR.parse().unwrap(),
+                    U256::new(5),
+                    TEST_DEADLINE,
+                    1,
+                ),
+            ],
+            TEST_SPENDER_STR.parse().unwrap(),
+            U256::from(TEST_DEADLINE),
+        );
+
+        let right = hex::encode(input.signing_hash(TEST_CHAIN_ID).unwrap());
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn signing_hash_amount_out_of_range_fail() {
+        let mut details = permit_details();
+        details.amount = U256::ONE << 160;
+        let input = PermitSingle::new(details, TEST_SPENDER_STR.parse().unwrap(), U256::ZERO);
+
+        let error = input.signing_hash(TEST_CHAIN_ID).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn signing_hash_expiration_out_of_range_fail() {
+        let mut details = permit_details();
+        details.expiration = 1 << 48;
+        let input = PermitBatch::new(vec![details], TEST_SPENDER_STR.parse().unwrap(), U256::ZERO);
+
+        assert!(input.signing_hash(TEST_CHAIN_ID).is_err());
+    }
+
+    #[test]
+    fn permit_data_succeed() {
+        let owner: AccountAddress = TEST_OWNER_STR.parse().unwrap();
+        let spender: AccountAddress = TEST_SPENDER_STR.parse().unwrap();
+        let signature = Signature::new([0x11; 32], [0x22; 32], 1);
+        let input = PermitSingle::new(permit_details(), spender, U256::from(TEST_DEADLINE));
+        let left = encode_call(
+            "permit(address,((address,uint160,uint48,uint48),address,uint256),bytes)",
+            &[
+                owner.into(),
+                Token::Tuple(vec![
+                    Token::Tuple(vec![
+                        Token::Address(TEST_TOKEN_STR.parse().unwrap()),
+                        U256::new(1_000_000).into(),
+                        U256::from(TEST_DEADLINE).into(),
+                        U256::ZERO.into(),
+                    ]),
+                    spender.into(),
+                    U256::from(TEST_DEADLINE).into(),
+                ]),
+                Token::Bytes(signature.to_bytes().to_vec()),
+            ],
+        )
+        .unwrap();
+
+        let right = input.permit_data(owner, &signature).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn permit_batch_data_succeed() {
+        let owner: AccountAddress = TEST_OWNER_STR.parse().unwrap();
+        let spender: AccountAddress = TEST_SPENDER_STR.parse().unwrap();
+        let signature = Signature::new([0x11; 32], [0x22; 32], 0);
+        let input = PermitBatch::new(vec![], spender, U256::ONE);
+        let left = encode_call(
+            "permit(address,((address,uint160,uint48,uint48)[],address,uint256),bytes)",
+            &[
+                owner.into(),
+                Token::Tuple(vec![Token::Array(vec![]), spender.into(), U256::ONE.into()]),
+                Token::Bytes(signature.to_bytes().to_vec()),
+            ],
+        )
+        .unwrap();
+
+        let right = input.permit_data(owner, &signature).unwrap();
+
+        assert_eq!(left, right);
+    }
+}
@@ -0,0 +1,187 @@
+use std::{collections::HashMap, io::Error};
+
+use super::{
+    decode,
+    decoder::{DecodedArg, DecodedCall},
+    Function, SELECTOR_LENGTH,
+};
+
+/// Signatures of common token, ownership, proxy and batching functions known to
+/// `SelectorRegistry::new`.
+pub const BUILT_IN_SIGNATURES: &[&str] = &[
+    "transfer(address,uint256)",
+    "approve(address,uint256)",
+    "transferFrom(address,address,uint256)",
+    "increaseAllowance(address,uint256)",
+    "decreaseAllowance(address,uint256)",
+    "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+    "mint(address,uint256)",
+    "burn(uint256)",
+    "safeTransferFrom(address,address,uint256)",
+    "safeTransferFrom(address,address,uint256,bytes)",
+    "setApprovalForAll(address,bool)",
+    "safeTransferFrom(address,address,uint256,uint256,bytes)",
+    "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+    "deposit()",
+    "withdraw(uint256)",
+    "setOwner(address)",
+    "transferOwnership(address)",
+    "renounceOwnership()",
+    "upgradeTo(address)",
+    "upgradeToAndCall(address,bytes)",
+    "multicall(bytes[])",
+    "aggregate3((address,bool,bytes)[])",
+    "aggregate3Value((address,bool,uint256,bytes)[])",
+];
+
+/// Offline lookup of function signatures by selector, labelling calldata without a full ABI.
+#[derive(Clone, Debug)]
+pub struct SelectorRegistry {
+    functions: HashMap<[u8; SELECTOR_LENGTH], Function>,
+}
+
+impl Default for SelectorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SelectorRegistry {
+    /// Creates the registry of the built-in signatures.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for signature in BUILT_IN_SIGNATURES {
+            registry
+                .register(signature)
+                .expect("Built-in signatures are valid");
+        }
+
+        registry
+    }
+
+    /// Creates the registry with no signatures.
+    pub fn empty() -> Self {
+        Self {
+            functions: HashMap::new(),
+        }
+    }
+
+    /// Registers the function signature, e.g. `setFee(uint24)`, replacing any signature with the
+    /// same selector. Fails if the signature is invalid.
+    pub fn register(&mut self, signature: &str) -> Result<&mut Self, Error> {
+        let function: Function = signature.parse()?;
+        self.functions.insert(function.selector(), function);
+
+        Ok(self)
+    }
+
+    /// Looks up the function with the selector.
+    pub fn lookup(&self, selector: &[u8; SELECTOR_LENGTH]) -> Option<&Function> {
+        self.functions.get(selector)
+    }
+
+    /// Looks up the function called by the calldata, e.g. to label it as `transfer`.
+    pub fn function(&self, data: &[u8]) -> Option<&Function> {
+        let selector = data.get(..SELECTOR_LENGTH)?.try_into().ok()?;
+
+        self.lookup(selector)
+    }
+
+    /// Decodes the calldata of a registered function, with unnamed arguments. Returns `None` if the
+    /// function is not registered and fails if the arguments do not match its signature.
+    pub fn decode_calldata(&self, data: &[u8]) -> Option<Result<DecodedCall, Error>> {
+        let function = self.function(data)?;
+
+        Some(
+            decode(&function.inputs, &data[SELECTOR_LENGTH..]).map(|values| DecodedCall {
+                function: function.clone(),
+                args: function
+                    .inputs
+                    .iter()
+                    .zip(values)
+                    .map(|(param_type, value)| DecodedArg {
+                        name: String::new(),
+                        param_type: param_type.clone(),
+                        value,
+                    })
+                    .collect(),
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::{
+        abi::{encode_call, Token},
+        transaction::{AccountAddress, U256},
+    };
+
+    const TEST_ADDRESS_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+
+    #[test]
+    fn lookup_built_in_succeed() {
+        let registry = SelectorRegistry::new();
+
+        let right = registry.lookup(&[0xa9, 0x05, 0x9c, 0xbb]).unwrap();
+
+        assert_eq!("transfer(address,uint256)", right.to_string());
+        assert_eq!(
+            "setOwner",
+            registry.lookup(&[0x13, 0xaf, 0x40, 0x35]).unwrap().name
+        );
+    }
+
+    #[test]
+    fn register_succeed() {
+        let mut registry = SelectorRegistry::empty();
+        let input = encode_call("setFee(uint24)", &[Token::Uint(U256::new(500))]).unwrap();
+        assert!(registry.function(&input.0).is_none());
+
+        registry.register("setFee(uint24)").unwrap();
+
+        assert_eq!("setFee", registry.function(&input.0).unwrap().name);
+    }
+
+    #[test]
+    fn register_invalid_signature_fail() {
+        let mut registry = SelectorRegistry::empty();
+
+        assert!(registry.register("setFee(uint7)").is_err());
+    }
+
+    #[test]
+    fn function_too_short_fail() {
+        let registry = SelectorRegistry::new();
+
+        assert!(registry.function(&[0xa9, 0x05]).is_none());
+    }
+
+    #[test]
+    fn decode_calldata_succeed() {
+        let registry = SelectorRegistry::new();
+        let spender: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let input = encode_call(
+            "approve(address,uint256)",
+            &[spender.into(), U256::MAX.into()],
+        )
+        .unwrap();
+
+        let right = registry.decode_calldata(&input.0).unwrap().unwrap();
+
+        assert_eq!(
+            format!("approve({}, {})", TEST_ADDRESS_STR, U256::MAX),
+            right.to_string()
+        );
+    }
+
+    #[test]
+    fn decode_calldata_unknown_fail() {
+        let registry = SelectorRegistry::new();
+
+        assert!(registry
+            .decode_calldata(&[0xde, 0xad, 0xbe, 0xef])
+            .is_none());
+    }
+}
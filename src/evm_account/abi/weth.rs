@@ -0,0 +1,160 @@
+use std::io::{Error, ErrorKind};
+
+use super::{encode_with_selector, SELECTOR_LENGTH};
+use crate::evm_account::{
+    chains::ChainRegistry,
+    transaction::{
+        free_market_transaction::{FeeSettings, FreeMarketTransaction},
+        AccountAddress, Bytes, U256,
+    },
+};
+
+/// Gas limit of the built transactions, covering `deposit` and `withdraw` of WETH9 style
+/// contracts. Can be tightened with `rpc::gas::estimate_gas`.
+pub const GAS_LIMIT: u128 = 60_000;
+
+// Selectors of `deposit()` and `withdraw(uint256)`.
+const DEPOSIT_SELECTOR: [u8; SELECTOR_LENGTH] = [0xd0, 0xe3, 0x0d, 0xb0];
+const WITHDRAW_SELECTOR: [u8; SELECTOR_LENGTH] = [0x2e, 0x1a, 0x7d, 0x4d];
+
+/// Builds the transaction wrapping `amount` (in Wei) of the native currency with the canonical
+/// wrapped native token of the chain of `fee_settings`.
+///
+/// Fails with `ErrorKind::NotFound` if the registry knows no wrapped native token of the chain.
+pub fn wrap(
+    registry: &ChainRegistry,
+    amount: U256,
+    fee_settings: FeeSettings,
+) -> Result<FreeMarketTransaction, Error> {
+    let weth = wrapped_native_token(registry, fee_settings.chain_id)?;
+
+    Ok(deposit(weth, amount, fee_settings))
+}
+
+/// Builds the transaction unwrapping `amount` (in Wei) of the canonical wrapped native token of
+/// the chain of `fee_settings`.
+///
+/// Fails with `ErrorKind::NotFound` if the registry knows no wrapped native token of the chain.
+pub fn unwrap(
+    registry: &ChainRegistry,
+    amount: U256,
+    fee_settings: FeeSettings,
+) -> Result<FreeMarketTransaction, Error> {
+    let weth = wrapped_native_token(registry, fee_settings.chain_id)?;
+
+    Ok(withdraw(weth, amount, fee_settings))
+}
+
+/// Builds the transaction calling `deposit()` of the WETH contract with `amount` attached.
+pub fn deposit(
+    weth: AccountAddress,
+    amount: U256,
+    fee_settings: FeeSettings,
+) -> FreeMarketTransaction {
+    let mut tx =
+        FreeMarketTransaction::contract_call(weth, deposit_data(), GAS_LIMIT, fee_settings);
+    tx.value = amount;
+
+    tx
+}
+
+/// Builds the transaction calling `withdraw(uint256)` of the WETH contract.
+pub fn withdraw(
+    weth: AccountAddress,
+    amount: U256,
+    fee_settings: FeeSettings,
+) -> FreeMarketTransaction {
+    FreeMarketTransaction::contract_call(weth, withdraw_data(amount), GAS_LIMIT, fee_settings)
+}
+
+/// Calldata of `deposit()`.
+pub fn deposit_data() -> Bytes {
+    encode_with_selector(DEPOSIT_SELECTOR, &[])
+}
+
+/// Calldata of `withdraw(uint256)`.
+pub fn withdraw_data(amount: U256) -> Bytes {
+    encode_with_selector(WITHDRAW_SELECTOR, &[amount.into()])
+}
+
+fn wrapped_native_token(registry: &ChainRegistry, chain_id: u64) -> Result<AccountAddress, Error> {
+    registry
+        .get(chain_id)
+        .and_then(|chain| chain.wrapped_native_token)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("No wrapped native token known for chain {}", chain_id),
+            )
+        })
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::evm_account::{abi::encode_call, chains::Chain, transaction::TxType};
+
+    const TEST_WETH_STR: &str = "0xfFf9976782d46CC05630D1f6eBAb18b2324d6B14";
+
+    fn fee_settings(chain_id: u64) -> FeeSettings {
+        FeeSettings {
+            chain_id,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
+        }
+    }
+
+    #[test]
+    fn wrap_succeed() {
+        let registry = ChainRegistry::new();
+
+        let right = wrap(&registry, U256::new(1_000), fee_settings(11155111)).unwrap();
+
+        assert_eq!(Some(TEST_WETH_STR.parse().unwrap()), right.to);
+        assert_eq!(U256::new(1_000), right.value);
+        assert_eq!("0xd0e30db0", right.data.to_string());
+    }
+
+    #[test]
+    fn unwrap_succeed() {
+        let registry = ChainRegistry::new();
+        let left = encode_call("withdraw(uint256)", &[U256::new(1_000).into()]).unwrap();
+
+        let right = unwrap(&registry, U256::new(1_000), fee_settings(11155111)).unwrap();
+
+        assert_eq!(Some(TEST_WETH_STR.parse().unwrap()), right.to);
+        assert_eq!(U256::ZERO, right.value);
+        assert_eq!(left, right.data);
+    }
+
+    #[test]
+    fn wrap_custom_chain_succeed() {
+        let weth: AccountAddress = TEST_WETH_STR.parse().unwrap();
+        let mut registry = ChainRegistry::new();
+        registry.register(
+            Chain::new(
+                31337,
+                "Anvil",
+                "ETH",
+                vec![TxType::Eip1559],
+                Duration::from_secs(1),
+            )
+            .with_wrapped_native_token(weth),
+        );
+
+        let right = wrap(&registry, U256::ONE, fee_settings(31337)).unwrap();
+
+        assert_eq!(Some(weth), right.to);
+    }
+
+    #[test]
+    fn wrap_unknown_chain_fail() {
+        let registry = ChainRegistry::new();
+
+        let error = wrap(&registry, U256::ONE, fee_settings(31337)).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,96 @@
+use alloy_primitives::{PrimitiveSignature, U256};
+#[cfg(feature = "aws")]
+use {
+    super::EvmAccount,
+    alloy_consensus::SignableTransaction,
+    alloy_network::TxSigner,
+    alloy_primitives::{Address, ChainId, B256},
+    alloy_signer::{Error, Result, Signer},
+    async_trait::async_trait,
+};
+
+use super::signature::Signature;
+
+impl From<Signature> for PrimitiveSignature {
+    fn from(signature: Signature) -> Self {
+        Self::new(
+            U256::from_be_bytes(*signature.r()),
+            U256::from_be_bytes(*signature.s()),
+            signature.y_parity() != 0,
+        )
+    }
+}
+
+/// Signs hashes and [`EIP-191`](https://eips.ethereum.org/EIPS/eip-191) messages for alloy.
+///
+/// Hashes are signed as is, so the signing policy only applies to transactions signed with
+/// `TxSigner`.
+#[cfg(feature = "aws")]
+#[async_trait]
+impl<'a> Signer for EvmAccount<'a> {
+    async fn sign_hash(&self, hash: &B256) -> Result<PrimitiveSignature> {
+        let signature = self.sign_digest(&hash.0).await.map_err(Error::other)?;
+
+        Ok(signature.into())
+    }
+
+    fn address(&self) -> Address {
+        EvmAccount::address(self).into()
+    }
+
+    fn chain_id(&self) -> Option<ChainId> {
+        self.chain_id
+    }
+
+    fn set_chain_id(&mut self, chain_id: Option<ChainId>) {
+        self.chain_id = chain_id;
+    }
+}
+
+/// Signs transactions of alloy providers and fillers, e.g. wrapped in `EthereumWallet`.
+///
+/// The transaction is bound to the chain of the signer, if any, and verified against the signing
+/// policy before it is sent to KMS.
+#[cfg(feature = "aws")]
+#[async_trait]
+impl<'a> TxSigner<PrimitiveSignature> for EvmAccount<'a> {
+    fn address(&self) -> Address {
+        EvmAccount::address(self).into()
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<PrimitiveSignature>,
+    ) -> Result<PrimitiveSignature> {
+        if let Some(chain_id) = self.chain_id {
+            if !tx.set_chain_id_checked(chain_id) {
+                return Err(Error::TransactionChainIdMismatch {
+                    signer: chain_id,
+                    tx: tx.chain_id().unwrap_or_default(),
+                });
+            }
+        }
+        self.policy
+            .check_fields(tx.chain_id(), tx.input())
+            .map_err(Error::other)?;
+
+        Signer::sign_hash(self, &tx.signature_hash()).await
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn signature_into_primitive_signature_succeed() {
+        let input = Signature::new([0x11; 32], [0x22; 32], 1);
+
+        let right = PrimitiveSignature::from(input);
+
+        assert_eq!(U256::from_be_bytes([0x11; 32]), right.r());
+        assert_eq!(U256::from_be_bytes([0x22; 32]), right.s());
+        assert!(right.v());
+        assert_eq!(input.to_bytes(), right.as_bytes());
+    }
+}
@@ -0,0 +1,320 @@
+use std::{
+    io::{Error, Write},
+    mem,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aws_config::SdkConfig;
+use aws_sdk_s3::{primitives::ByteStream, types::ChecksumAlgorithm, Client};
+use flate2::{write::GzEncoder, Compression};
+
+use super::{events::SigningEvent, hash::keccak256, runtime};
+
+const DEFAULT_BATCH_SIZE: usize = 100;
+const DEFAULT_MAX_BACKLOG: usize = 10_000;
+const OBJECT_SUFFIX: &str = ".jsonl.gz";
+const CONTENT_TYPE: &str = "application/gzip";
+// Bytes of the content hash in object keys, which makes them unique
+const CONTENT_HASH_LENGTH: usize = 8;
+const MILLIS_PER_DAY: u64 = 86_400_000;
+
+/// Durable, immutable ledger of signing attempts in S3, attached to an account with
+/// `EvmAccount::with_audit_sink`.
+///
+/// Signing events are batched into gzip compressed JSON Lines objects, e.g.
+/// `audit/2024/11/05/1730764800000-3f2a9c1d0b7e4a65.jsonl.gz`. Object keys are partitioned by UTC
+/// date and end with the hash of the content, so they are never reused. Objects are written with
+/// `If-None-Match: *` and a SHA-256 checksum, as required by buckets with S3 Object Lock, so
+/// records cannot be overwritten.
+///
+/// Full batches are written in the background, so that signing does not wait for S3. Buffered
+/// events are lost if the process exits before they are written, so `flush` should be called on
+/// shutdown. A batch which fails to be written is kept and retried with the next one, up to a
+/// backlog of 10,000 events (see `with_max_backlog`), beyond which the oldest events are dropped
+/// and counted in `dropped_events`.
+#[derive(Debug)]
+pub struct S3AuditSink {
+    ledger: Arc<Ledger>,
+    batch_size: usize,
+    max_backlog: usize,
+}
+
+// State shared with the background writes
+#[derive(Debug)]
+struct Ledger {
+    client: Client,
+    bucket: String,
+    prefix: String,
+    backlog: Mutex<Vec<SigningEvent>>,
+    dropped_events: AtomicU64,
+}
+
+impl S3AuditSink {
+    /// Creates a sink writing objects to the bucket under the key prefix, e.g. `audit/`, in
+    /// batches of 100 events.
+    pub fn new(config: &SdkConfig, bucket: impl Into<String>, prefix: impl Into<String>) -> Self {
+        let mut prefix = prefix.into();
+        if !prefix.is_empty() && !prefix.ends_with('/') {
+            prefix.push('/');
+        }
+
+        Self {
+            ledger: Arc::new(Ledger {
+                client: Client::new(config),
+                bucket: bucket.into(),
+                prefix,
+                backlog: Mutex::new(Vec::new()),
+                dropped_events: AtomicU64::new(0),
+            }),
+            batch_size: DEFAULT_BATCH_SIZE,
+            max_backlog: DEFAULT_MAX_BACKLOG,
+        }
+    }
+
+    /// Sets the number of events written per object, e.g. `1` to write every event immediately.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Sets the maximum number of events which are yet to be written, e.g. while S3 is
+    /// unavailable, beyond which the oldest events are dropped.
+    pub fn with_max_backlog(mut self, max_backlog: usize) -> Self {
+        self.max_backlog = max_backlog.max(1);
+        self
+    }
+
+    /// Adds the event to the batch and writes the batch in the background once it is full.
+    ///
+    /// Without a runtime to write on (e.g. with `rt-tokio` outside of a Tokio runtime), the batch
+    /// is kept and written with the next one or by `flush`.
+    pub fn record(&self, event: &SigningEvent) {
+        let full = {
+            let mut backlog = self.ledger.lock();
+            backlog.push(event.clone());
+            self.ledger.truncate(&mut backlog, self.max_backlog);
+            backlog.len() >= self.batch_size
+        };
+
+        if full {
+            let (ledger, max_backlog) = (self.ledger.clone(), self.max_backlog);
+            let _ = runtime::spawn(async move {
+                let _ = ledger.flush(max_backlog).await;
+            });
+        }
+    }
+
+    /// Writes the buffered events, if any, regardless of the batch size.
+    pub async fn flush(&self) -> Result<(), Error> {
+        self.ledger.flush(self.max_backlog).await
+    }
+
+    /// Number of events dropped because the backlog was full, e.g. as S3 was unavailable for too
+    /// long. Dropped events are missing from the ledger.
+    pub fn dropped_events(&self) -> u64 {
+        self.ledger.dropped_events.load(Ordering::Relaxed)
+    }
+}
+
+impl Ledger {
+    async fn flush(&self, max_backlog: usize) -> Result<(), Error> {
+        let batch = mem::take(&mut *self.lock());
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        self.write(batch, max_backlog).await
+    }
+
+    async fn write(&self, batch: Vec<SigningEvent>, max_backlog: usize) -> Result<(), Error> {
+        let body = compress(&batch)?;
+        let key = object_key(&self.prefix, SystemTime::now(), &body);
+
+        let result = self
+            .client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .if_none_match("*")
+            .checksum_algorithm(ChecksumAlgorithm::Sha256)
+            .content_type(CONTENT_TYPE)
+            .body(ByteStream::from(body))
+            .send()
+            .await;
+
+        if let Err(error) = result {
+            // Events recorded in the meantime are kept after the ones of the batch
+            let mut backlog = self.lock();
+            backlog.splice(0..0, batch);
+            self.truncate(&mut backlog, max_backlog);
+            return Err(Error::other(format!(
+                "Error writing audit object: {:?}",
+                error
+            )));
+        }
+
+        Ok(())
+    }
+
+    // Drops the oldest events beyond the maximum backlog
+    fn truncate(&self, backlog: &mut Vec<SigningEvent>, max_backlog: usize) {
+        let excess = backlog.len().saturating_sub(max_backlog);
+        if excess > 0 {
+            backlog.drain(..excess);
+            self.dropped_events
+                .fetch_add(excess as u64, Ordering::Relaxed);
+        }
+    }
+
+    // The backlog is left consistent by every operation, so it is still usable after a panic
+    fn lock(&self) -> MutexGuard<'_, Vec<SigningEvent>> {
+        self.backlog.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// Serializes the events as JSON Lines and compresses them with gzip
+fn compress(events: &[SigningEvent]) -> Result<Vec<u8>, Error> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    for event in events {
+        serde_json::to_writer(&mut encoder, event)?;
+        encoder.write_all(b"\n")?;
+    }
+
+    encoder.finish()
+}
+
+fn object_key(prefix: &str, timestamp: SystemTime, body: &[u8]) -> String {
+    let millis = timestamp
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    let (year, month, day) = civil_date(millis / MILLIS_PER_DAY);
+    let content_hash = hex::encode(&keccak256(body)[..CONTENT_HASH_LENGTH]);
+
+    format!(
+        "{}{:04}/{:02}/{:02}/{}-{}{}",
+        prefix, year, month, day, millis, content_hash, OBJECT_SUFFIX
+    )
+}
+
+// Gregorian date of the day since the Unix epoch, see
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_date(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use aws_config::BehaviorVersion;
+    use flate2::read::GzDecoder;
+    use std::{io::Read, time::Duration};
+
+    const TEST_ADDRESS: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+
+    fn test_event() -> SigningEvent {
+        SigningEvent::signed(
+            "alias/treasury",
+            TEST_ADDRESS.parse().unwrap(),
+            &[0xab; 32],
+            None,
+        )
+    }
+
+    fn test_sink() -> S3AuditSink {
+        let config = SdkConfig::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .build();
+
+        S3AuditSink::new(&config, "audit-bucket", "audit")
+    }
+
+    #[test]
+    fn civil_date_succeed() {
+        assert_eq!((1970, 1, 1), civil_date(0));
+        assert_eq!((2000, 2, 29), civil_date(11_016));
+        assert_eq!((2024, 11, 5), civil_date(20_032));
+    }
+
+    #[test]
+    fn object_key_succeed() {
+        let input = UNIX_EPOCH + Duration::from_millis(1_730_764_800_000);
+
+        let right = object_key("audit/", input, b"body");
+
+        assert!(right.starts_with("audit/2024/11/05/1730764800000-"));
+        assert!(right.ends_with(".jsonl.gz"));
+        assert_ne!(right, object_key("audit/", input, b"other body"));
+    }
+
+    #[test]
+    fn compress_succeed() {
+        let input = vec![test_event(), test_event()];
+
+        let mut right = String::new();
+        GzDecoder::new(compress(&input).unwrap().as_slice())
+            .read_to_string(&mut right)
+            .unwrap();
+
+        let lines: Vec<&str> = right.lines().collect();
+        assert_eq!(2, lines.len());
+        assert_eq!(serde_json::to_string(&input[0]).unwrap(), lines[0]);
+    }
+
+    #[test]
+    fn new_prefix_succeed() {
+        let right = test_sink();
+
+        assert_eq!("audit/", right.ledger.prefix);
+    }
+
+    #[test]
+    fn record_buffers_succeed() {
+        let input = test_sink().with_batch_size(2);
+
+        input.record(&test_event());
+
+        assert_eq!(1, input.ledger.lock().len());
+    }
+
+    #[test]
+    fn record_full_backlog_succeed() {
+        let input = test_sink().with_batch_size(3).with_max_backlog(2);
+
+        for _ in 0..3 {
+            input.record(&test_event());
+        }
+
+        assert_eq!(2, input.ledger.lock().len());
+        assert_eq!(1, input.dropped_events());
+    }
+
+    #[cfg(feature = "rt-tokio")]
+    #[test]
+    fn record_outside_runtime_succeed() {
+        let input = test_sink().with_batch_size(1);
+
+        input.record(&test_event());
+
+        assert_eq!(1, input.ledger.lock().len());
+    }
+}
@@ -0,0 +1,222 @@
+use std::{future::Future, io::Error, num::NonZeroU32, time::Instant};
+
+use futures_util::{stream, Stream, StreamExt};
+
+use super::{
+    rate_limit::{self, RateLimiter},
+    transaction::json::Mode,
+    EvmAccount,
+};
+
+const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Concurrency and rate of KMS requests when signing a batch of transactions, e.g.:
+///
+/// ```rust
+/// use evm_signer_kms::evm_account::batch::BatchOptions;
+///
+/// let options = BatchOptions::new()
+///     .with_concurrency(16)
+///     .with_max_requests_per_second(50);
+/// ```
+///
+/// The request rate should stay below the KMS quota of the key's account and region, so that
+/// other signers sharing the quota are not throttled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchOptions {
+    concurrency: usize,
+    max_requests_per_second: Option<NonZeroU32>,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BatchOptions {
+    /// Creates options signing 8 transactions at the same time, with no cap on the request rate.
+    pub fn new() -> Self {
+        Self {
+            concurrency: DEFAULT_CONCURRENCY,
+            max_requests_per_second: None,
+        }
+    }
+
+    /// Sets the maximum number of transactions being signed at the same time.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Caps the rate of signing requests, which are spread evenly over each second. `0` removes
+    /// the cap.
+    pub fn with_max_requests_per_second(mut self, max_requests_per_second: u32) -> Self {
+        self.max_requests_per_second = NonZeroU32::new(max_requests_per_second);
+        self
+    }
+
+    /// Maximum number of transactions being signed at the same time.
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Cap of the rate of signing requests, if any.
+    pub fn max_requests_per_second(&self) -> Option<NonZeroU32> {
+        self.max_requests_per_second
+    }
+}
+
+impl<'a> EvmAccount<'a> {
+    /// Signs the transaction JSON objects like `sign_transaction_json`, concurrently according to
+    /// the options, and returns the results in the order of the input. A failed transaction does
+    /// not abort the batch.
+    pub async fn sign_transactions_json<I>(
+        &self,
+        tx_jsons: I,
+        mode: Mode,
+        options: &BatchOptions,
+    ) -> Vec<Result<Vec<u8>, Error>>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let tx_jsons: Vec<Result<String, Error>> = tx_jsons
+            .into_iter()
+            .map(|tx_json| Ok(tx_json.into()))
+            .collect();
+
+        ordered(stream::iter(tx_jsons), options, |tx_json| async move {
+            self.sign_transaction_json(&tx_json, mode).await
+        })
+        .collect()
+        .await
+    }
+
+    /// Signs the stream of transaction JSON objects like `sign_transactions_json`, yielding each
+    /// result in the order of the input as soon as it and the preceding ones are signed.
+    ///
+    /// Errors of the input, e.g. of reading a file, are passed through in place of the
+    /// transaction, so that the caller decides whether to stop.
+    pub fn sign_transactions_json_stream<S>(
+        &'a self,
+        tx_jsons: S,
+        mode: Mode,
+        options: &BatchOptions,
+    ) -> impl Stream<Item = Result<Vec<u8>, Error>> + 'a
+    where
+        S: Stream<Item = Result<String, Error>> + 'a,
+    {
+        ordered(tx_jsons, options, move |tx_json| async move {
+            self.sign_transaction_json(&tx_json, mode).await
+        })
+    }
+}
+
+// Runs `f` on the items concurrently, at the rate allowed by the options, and yields the results
+// in the order of the items. Failed items are not passed to `f`, nor do they take up the rate.
+fn ordered<'s, S, F, Fut, T>(
+    items: S,
+    options: &BatchOptions,
+    f: F,
+) -> impl Stream<Item = Result<T, Error>> + 's
+where
+    S: Stream<Item = Result<String, Error>> + 's,
+    F: Fn(String) -> Fut + 's,
+    Fut: Future<Output = Result<T, Error>> + 's,
+    T: 's,
+{
+    // A bucket of a single token spreads the requests evenly
+    let rate_limiter = options
+        .max_requests_per_second
+        .map(|max_requests_per_second| {
+            RateLimiter::new(max_requests_per_second).with_burst(NonZeroU32::MIN)
+        });
+
+    items
+        .scan(rate_limiter, |rate_limiter, item| {
+            let slot = rate_limiter
+                .as_ref()
+                .filter(|_| item.is_ok())
+                .map(|rate_limiter| rate_limiter.reserve(Instant::now()));
+            std::future::ready(Some((item, slot)))
+        })
+        .map(move |(item, slot)| {
+            let future = item.map(&f);
+            async move {
+                if let Some(slot) = slot {
+                    rate_limit::wait_until(slot).await;
+                }
+                future?.await
+            }
+        })
+        .buffered(options.concurrency)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::runtime;
+    use std::{io::ErrorKind, time::Duration};
+
+    #[test]
+    fn with_concurrency_succeed() {
+        let right = BatchOptions::new().with_concurrency(0);
+
+        assert_eq!(1, right.concurrency());
+    }
+
+    #[test]
+    fn with_max_requests_per_second_succeed() {
+        let right = BatchOptions::new().with_max_requests_per_second(0);
+
+        assert_eq!(None, right.max_requests_per_second());
+    }
+
+    #[test]
+    fn ordered_succeed() {
+        let input = stream::iter(vec![
+            Ok("30".to_string()),
+            Err(Error::new(ErrorKind::InvalidData, "Unreadable line")),
+            Ok("x".to_string()),
+            Ok("0".to_string()),
+        ]);
+
+        let right: Vec<Result<u64, Error>> = tokio_test::block_on(
+            ordered(input, &BatchOptions::new(), |item| async move {
+                let millis: u64 = item
+                    .parse()
+                    .map_err(|error| Error::new(ErrorKind::InvalidInput, error))?;
+                runtime::sleep(Duration::from_millis(millis)).await;
+                Ok(millis)
+            })
+            .collect(),
+        );
+
+        assert_eq!(30, *right[0].as_ref().unwrap());
+        assert_eq!(
+            ErrorKind::InvalidData,
+            right[1].as_ref().unwrap_err().kind()
+        );
+        assert_eq!(
+            ErrorKind::InvalidInput,
+            right[2].as_ref().unwrap_err().kind()
+        );
+        assert_eq!(0, *right[3].as_ref().unwrap());
+    }
+
+    #[test]
+    fn ordered_rate_succeed() {
+        let input = stream::iter((0..5).map(|index| Ok(index.to_string())));
+        let options = BatchOptions::new().with_max_requests_per_second(100);
+
+        let right = tokio_test::block_on(async {
+            let started = Instant::now();
+            let count = ordered(input, &options, |_| async { Ok(()) }).count().await;
+            (count, started.elapsed())
+        });
+
+        assert_eq!(5, right.0);
+        assert!(right.1 >= Duration::from_millis(40));
+    }
+}
@@ -0,0 +1,391 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+    time::Duration,
+};
+
+use super::transaction::{AccountAddress, Transaction, TxType};
+
+// Decimals of the native currency on virtually every EVM chain, i.e. 1 ether = 10^18 wei.
+const NATIVE_DECIMALS: u8 = 18;
+
+const ALL_TX_TYPES: &[TxType] = &[
+    TxType::Legacy,
+    TxType::Eip2930,
+    TxType::Eip1559,
+    TxType::Eip4844,
+    TxType::Eip7702,
+];
+const NON_BLOB_TX_TYPES: &[TxType] = &[TxType::Legacy, TxType::Eip2930, TxType::Eip1559];
+
+// Predeployed WETH of OP Stack chains.
+const OP_STACK_WETH: &str = "0x4200000000000000000000000000000000000006";
+
+const BUILT_IN_CHAINS: &[Chain] = &[
+    Chain::built_in(
+        1,
+        "Ethereum",
+        "ETH",
+        ALL_TX_TYPES,
+        12_000,
+        Some("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+    ),
+    Chain::built_in(
+        11155111,
+        "Sepolia",
+        "ETH",
+        ALL_TX_TYPES,
+        12_000,
+        Some("0xfFf9976782d46CC05630D1f6eBAb18b2324d6B14"),
+    ),
+    Chain::built_in(17000, "Holesky", "ETH", ALL_TX_TYPES, 12_000, None),
+    Chain::built_in(
+        10,
+        "OP Mainnet",
+        "ETH",
+        NON_BLOB_TX_TYPES,
+        2_000,
+        Some(OP_STACK_WETH),
+    ),
+    Chain::built_in(
+        11155420,
+        "OP Sepolia",
+        "ETH",
+        NON_BLOB_TX_TYPES,
+        2_000,
+        Some(OP_STACK_WETH),
+    ),
+    Chain::built_in(
+        8453,
+        "Base",
+        "ETH",
+        NON_BLOB_TX_TYPES,
+        2_000,
+        Some(OP_STACK_WETH),
+    ),
+    Chain::built_in(
+        84532,
+        "Base Sepolia",
+        "ETH",
+        NON_BLOB_TX_TYPES,
+        2_000,
+        Some(OP_STACK_WETH),
+    ),
+    Chain::built_in(
+        42161,
+        "Arbitrum One",
+        "ETH",
+        NON_BLOB_TX_TYPES,
+        250,
+        Some("0x82aF49447D8a07e3bd95BD0d56f35241523fBab1"),
+    ),
+    Chain::built_in(
+        421614,
+        "Arbitrum Sepolia",
+        "ETH",
+        NON_BLOB_TX_TYPES,
+        250,
+        None,
+    ),
+    Chain::built_in(
+        137,
+        "Polygon",
+        "POL",
+        NON_BLOB_TX_TYPES,
+        2_000,
+        Some("0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270"),
+    ),
+    Chain::built_in(80002, "Polygon Amoy", "POL", NON_BLOB_TX_TYPES, 2_000, None),
+    Chain::built_in(
+        56,
+        "BNB Smart Chain",
+        "BNB",
+        NON_BLOB_TX_TYPES,
+        3_000,
+        Some("0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c"),
+    ),
+    Chain::built_in(
+        43114,
+        "Avalanche C-Chain",
+        "AVAX",
+        NON_BLOB_TX_TYPES,
+        2_000,
+        Some("0xB31f66AA3C1e785363F0875A1B74E27b85FD66c7"),
+    ),
+    Chain::built_in(
+        100,
+        "Gnosis",
+        "XDAI",
+        NON_BLOB_TX_TYPES,
+        5_000,
+        Some("0xe91D153E0b41518A2Ce8Dd3D7944Fa863463a97d"),
+    ),
+];
+
+/// Properties of an EVM chain, e.g. for validating transactions and displaying amounts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Chain {
+    /// Chain ID (see [`EIP-155`](https://eips.ethereum.org/EIPS/eip-155)).
+    pub id: u64,
+    /// Human readable name of the chain.
+    pub name: Cow<'static, str>,
+    /// Symbol of the native currency, e.g. `ETH`.
+    pub currency_symbol: Cow<'static, str>,
+    /// Decimals of the native currency.
+    pub decimals: u8,
+    /// Transaction types accepted by the chain.
+    pub tx_types: Cow<'static, [TxType]>,
+    /// Typical time between blocks.
+    pub block_time: Duration,
+    /// Canonical wrapped native currency contract (e.g. WETH), if any.
+    pub wrapped_native_token: Option<AccountAddress>,
+}
+
+impl Chain {
+    /// Creates a new chain with the native currency using 18 decimals.
+    pub fn new(
+        id: u64,
+        name: impl Into<Cow<'static, str>>,
+        currency_symbol: impl Into<Cow<'static, str>>,
+        tx_types: impl Into<Cow<'static, [TxType]>>,
+        block_time: Duration,
+    ) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            currency_symbol: currency_symbol.into(),
+            decimals: NATIVE_DECIMALS,
+            tx_types: tx_types.into(),
+            block_time,
+            wrapped_native_token: None,
+        }
+    }
+
+    /// Sets the canonical wrapped native currency contract of the chain.
+    pub fn with_wrapped_native_token(mut self, address: AccountAddress) -> Self {
+        self.wrapped_native_token = Some(address);
+        self
+    }
+
+    const fn built_in(
+        id: u64,
+        name: &'static str,
+        currency_symbol: &'static str,
+        tx_types: &'static [TxType],
+        block_time_millis: u64,
+        wrapped_native_token: Option<&'static str>,
+    ) -> Self {
+        Self {
+            id,
+            name: Cow::Borrowed(name),
+            currency_symbol: Cow::Borrowed(currency_symbol),
+            decimals: NATIVE_DECIMALS,
+            tx_types: Cow::Borrowed(tx_types),
+            block_time: Duration::from_millis(block_time_millis),
+            wrapped_native_token: match wrapped_native_token {
+                Some(address) => Some(const_address(address)),
+                None => None,
+            },
+        }
+    }
+
+    /// Returns `true` if the chain accepts transactions of the type.
+    pub fn supports(&self, tx_type: TxType) -> bool {
+        self.tx_types.contains(&tx_type)
+    }
+}
+
+impl Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.id)
+    }
+}
+
+// Parses the hex address at compile time, so that built-in chains are constants.
+const fn const_address(address: &str) -> AccountAddress {
+    let hex = address.as_bytes();
+    let mut bytes = [0u8; 20];
+    let mut index = 0;
+    while index < bytes.len() {
+        bytes[index] = hex_digit(hex[2 + 2 * index]) << 4 | hex_digit(hex[3 + 2 * index]);
+        index += 1;
+    }
+
+    AccountAddress(bytes)
+}
+
+const fn hex_digit(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        b'A'..=b'F' => digit - b'A' + 10,
+        _ => panic!("Invalid hex digit in address"),
+    }
+}
+
+/// Looks up one of the chains shipped with the library, i.e. Ethereum, common L2s and their
+/// testnets.
+pub fn built_in_chain(chain_id: u64) -> Option<&'static Chain> {
+    BUILT_IN_CHAINS.iter().find(|chain| chain.id == chain_id)
+}
+
+/// Registry of known chains, extending the built-in chains with custom ones.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use evm_signer_kms::evm_account::{chains::{Chain, ChainRegistry}, transaction::TxType};
+///
+/// let mut registry = ChainRegistry::new();
+/// registry.register(Chain::new(
+///     31337,
+///     "Anvil",
+///     "ETH",
+///     vec![TxType::Legacy, TxType::Eip1559],
+///     Duration::from_secs(1),
+/// ));
+///
+/// assert_eq!(registry.get(31337).unwrap().name, "Anvil");
+/// assert_eq!(registry.get(1).unwrap().name, "Ethereum");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ChainRegistry {
+    custom_chains: HashMap<u64, Chain>,
+}
+
+impl ChainRegistry {
+    /// Creates a new registry with the built-in chains only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the chain, overriding a built-in or previously registered chain with the same
+    /// ID. Returns the previously registered custom chain, if any.
+    pub fn register(&mut self, chain: Chain) -> Option<Chain> {
+        self.custom_chains.insert(chain.id, chain)
+    }
+
+    /// Looks up the chain, preferring custom chains over the built-in ones.
+    pub fn get(&self, chain_id: u64) -> Option<&Chain> {
+        self.custom_chains
+            .get(&chain_id)
+            .or_else(|| built_in_chain(chain_id))
+    }
+
+    /// Verifies that the chain of the transaction accepts its type. Transactions bound to unknown
+    /// chains (or to no chain) are not checked.
+    pub fn check<T: Transaction>(&self, tx: &T) -> Result<(), Error> {
+        match tx.chain_id().and_then(|chain_id| self.get(chain_id)) {
+            Some(chain) if !chain.supports(T::TX_TYPE) => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Transaction type {} is not supported on {}",
+                    T::TX_TYPE.id(),
+                    chain
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::transaction::{
+        free_market_transaction::FreeMarketTransaction, Bytes, U256,
+    };
+
+    fn free_market_tx(chain_id: u64) -> FreeMarketTransaction {
+        FreeMarketTransaction {
+            gas_limit: 21_000,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
+            chain_id,
+            nonce: 0,
+            to: None,
+            value: U256::ZERO,
+            data: Bytes::default(),
+            access_list: vec![],
+        }
+    }
+
+    fn legacy_only_chain() -> Chain {
+        Chain::new(
+            31337,
+            "Legacy devnet",
+            "ETH",
+            vec![TxType::Legacy],
+            Duration::from_secs(1),
+        )
+    }
+
+    #[test]
+    fn built_in_chain_succeed() {
+        let right = built_in_chain(11155111).unwrap();
+
+        assert_eq!("Sepolia", right.name);
+        assert_eq!(18, right.decimals);
+        assert!(right.supports(TxType::Eip4844));
+        assert_eq!("Sepolia (11155111)", right.to_string());
+        assert_eq!(
+            Some(
+                "0xfFf9976782d46CC05630D1f6eBAb18b2324d6B14"
+                    .parse()
+                    .unwrap()
+            ),
+            right.wrapped_native_token
+        );
+    }
+
+    #[test]
+    fn built_in_chain_wrapped_native_token_succeed() {
+        assert_eq!(
+            Some(OP_STACK_WETH.parse().unwrap()),
+            built_in_chain(8453).unwrap().wrapped_native_token
+        );
+    }
+
+    #[test]
+    fn built_in_chain_unknown_fail() {
+        assert!(built_in_chain(31337).is_none());
+    }
+
+    #[test]
+    fn built_in_chain_ids_unique_succeed() {
+        for (index, chain) in BUILT_IN_CHAINS.iter().enumerate() {
+            assert!(BUILT_IN_CHAINS[index + 1..]
+                .iter()
+                .all(|other| other.id != chain.id));
+        }
+    }
+
+    #[test]
+    fn register_override_succeed() {
+        let mut registry = ChainRegistry::new();
+        let left = Chain::new(1, "Mainnet", "ETH", ALL_TX_TYPES, Duration::from_secs(12));
+
+        assert!(registry.register(left.clone()).is_none());
+
+        assert_eq!(Some(&left), registry.get(1));
+    }
+
+    #[test]
+    fn check_succeed() {
+        let registry = ChainRegistry::new();
+
+        assert!(registry.check(&free_market_tx(8453)).is_ok());
+        assert!(registry.check(&free_market_tx(31337)).is_ok());
+    }
+
+    #[test]
+    fn check_unsupported_tx_type_fail() {
+        let mut registry = ChainRegistry::new();
+        registry.register(legacy_only_chain());
+
+        let error = registry.check(&free_market_tx(31337)).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,110 @@
+use std::io::{Error, ErrorKind};
+
+#[cfg(feature = "pure-rust")]
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+#[cfg(not(feature = "pure-rust"))]
+use {
+    super::SIGNATURE_COMPONENT_LENGTH,
+    secp256k1::{
+        ecdsa::{RecoverableSignature, RecoveryId},
+        Message, Secp256k1,
+    },
+};
+
+use super::{hash::Keccak256Digest, PublicKey, SignatureComponent, PUBLIC_KEY_LENGTH};
+
+#[cfg(not(any(feature = "secp256k1", feature = "pure-rust")))]
+compile_error!("Either the `secp256k1` or the `pure-rust` feature has to be enabled");
+
+/// Recovers the raw, uncompressed 64-byte public key which signed the digest.
+///
+/// Uses `k256` with the `pure-rust` feature and the C `secp256k1` library otherwise. Errors carry
+/// the message of the underlying library, to be put into context by the caller.
+#[cfg(not(feature = "pure-rust"))]
+pub fn recover_public_key(
+    digest: &Keccak256Digest,
+    r: &SignatureComponent,
+    s: &SignatureComponent,
+    y_parity: u8,
+) -> Result<PublicKey, Error> {
+    let secp_context = Secp256k1::verification_only();
+    // Compact signature is concatenation of 32-byte r and 32-byte s with no headers
+    let mut compact_signature = [0u8; 2 * SIGNATURE_COMPONENT_LENGTH];
+    compact_signature[..SIGNATURE_COMPONENT_LENGTH].copy_from_slice(r);
+    compact_signature[SIGNATURE_COMPONENT_LENGTH..].copy_from_slice(s);
+    let message = Message::from_digest(*digest);
+
+    let public_key = RecoveryId::try_from(i32::from(y_parity))
+        .and_then(|recovery_id| RecoverableSignature::from_compact(&compact_signature, recovery_id))
+        .and_then(|signature| secp_context.recover_ecdsa(&message, &signature))
+        .map_err(|error| Error::new(ErrorKind::InvalidData, error.to_string()))?
+        .serialize_uncompressed();
+
+    Ok(strip_prefix(&public_key))
+}
+
+/// Recovers the raw, uncompressed 64-byte public key which signed the digest.
+///
+/// Uses `k256` with the `pure-rust` feature and the C `secp256k1` library otherwise. Errors carry
+/// the message of the underlying library, to be put into context by the caller.
+#[cfg(feature = "pure-rust")]
+pub fn recover_public_key(
+    digest: &Keccak256Digest,
+    r: &SignatureComponent,
+    s: &SignatureComponent,
+    y_parity: u8,
+) -> Result<PublicKey, Error> {
+    let recovery_id = RecoveryId::from_byte(y_parity).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Invalid recovery ID: {}", y_parity),
+        )
+    })?;
+
+    let signature = Signature::from_scalars(*r, *s)
+        .map_err(|error| Error::new(ErrorKind::InvalidData, error.to_string()))?;
+    // k256 only accepts low `s`, unlike `ecrecover`. Negating `s` flips the parity of `R`, so the
+    // same public key is recovered.
+    let (signature, recovery_id) = match signature.normalize_s() {
+        Some(signature) => (
+            signature,
+            RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced()),
+        ),
+        None => (signature, recovery_id),
+    };
+
+    let public_key = VerifyingKey::recover_from_prehash(digest, &signature, recovery_id)
+        .map_err(|error| Error::new(ErrorKind::InvalidData, error.to_string()))?
+        .to_encoded_point(false);
+
+    Ok(strip_prefix(public_key.as_bytes()))
+}
+
+// Drops the 0x04 uncompressed EC prefix of the 65-byte SEC1 encoding
+fn strip_prefix(public_key: &[u8]) -> PublicKey {
+    let mut raw_public_key: PublicKey = [0u8; PUBLIC_KEY_LENGTH];
+    raw_public_key.copy_from_slice(&public_key[1..]);
+
+    raw_public_key
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::hash::address_from_public_key;
+    use crate::evm_account::signature_vector::{TEST_DIGEST, TEST_R, TEST_S, TEST_SENDER};
+
+    #[test]
+    fn recover_public_key_succeed() {
+        let right = recover_public_key(&TEST_DIGEST, &TEST_R, &TEST_S, 0).unwrap();
+
+        assert_eq!(TEST_SENDER, address_from_public_key(&right).to_string());
+    }
+
+    #[test]
+    fn recover_public_key_invalid_parity_fail() {
+        let right = recover_public_key(&TEST_DIGEST, &TEST_R, &TEST_S, 4).unwrap_err();
+
+        assert_eq!(ErrorKind::InvalidData, right.kind());
+    }
+}
@@ -1,6 +1,6 @@
 use ethnum::U256;
 
-use crate::evm_account::SignatureComponent;
+use crate::evm_account::{signature::SignatureError, SignatureComponent};
 
 const SECP_256K1_N: U256 = U256([
     // NOTE: The order of words is reversed
@@ -8,23 +8,59 @@ const SECP_256K1_N: U256 = U256([
     0xffffffff_ffffffff_ffffffff_fffffffe,
 ]);
 
-/// Wraps the `s` value of signature around x-axis.
+/// Validates that `r` and `s` are in `[1, n - 1]`, and wraps `s` into the lower half of the
+/// curve order, as required by [`EIP-2`](https://eips.ethereum.org/EIPS/eip-2) for transaction
+/// signatures.
 ///
-/// See [`EIP-2`](https://eips.ethereum.org/EIPS/eip-2) for details. Moved to separate module to
-/// keep Ethereum specific dependencies in one place.
-pub fn wrap_s(component: SignatureComponent) -> SignatureComponent {
-    let mut s_u256 = U256::from_be_bytes(component);
+/// Returns `s` after wrapping. Moved to separate module to keep Ethereum specific dependencies in
+/// one place.
+pub fn canonicalize(
+    r: &SignatureComponent,
+    s: SignatureComponent,
+) -> Result<SignatureComponent, SignatureError> {
+    let r_u256 = U256::from_be_bytes(*r);
+    if r_u256 == U256::ZERO {
+        return Err(SignatureError::ZeroR);
+    }
+    if r_u256 >= SECP_256K1_N {
+        return Err(SignatureError::ROutOfRange);
+    }
+
+    let s_u256 = U256::from_be_bytes(s);
+    if s_u256 == U256::ZERO {
+        return Err(SignatureError::ZeroS);
+    }
+    if s_u256 >= SECP_256K1_N {
+        return Err(SignatureError::SOutOfRange);
+    }
 
-    // TODO: Remove after sufficient testing and monitoring
-    assert!(s_u256 <= SECP_256K1_N, "⚠️ Maximum curve value exceeded‼️");
+    let s = wrap_s(s);
+    check_low_s(&s)?;
 
-    if s_u256 >= SECP_256K1_N / 2 {
+    Ok(s)
+}
+
+// Wraps the `s` value of signature around x-axis. `s` must not exceed the curve order.
+fn wrap_s(component: SignatureComponent) -> SignatureComponent {
+    let mut s_u256 = U256::from_be_bytes(component);
+
+    // `n` is odd, so `n / 2` is the largest low `s`
+    if s_u256 > SECP_256K1_N / 2 {
         s_u256 = SECP_256K1_N - s_u256;
     }
 
     s_u256.to_be_bytes()
 }
 
+// Guards the encoding against a regression of the wrapping
+fn check_low_s(s: &SignatureComponent) -> Result<(), SignatureError> {
+    if U256::from_be_bytes(*s) > SECP_256K1_N / 2 {
+        return Err(SignatureError::HighS);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
@@ -40,11 +76,12 @@ mod unit_tests {
     }
 
     #[test]
-    #[should_panic]
-    fn test_wrap_s_max_exceeded() {
+    fn test_canonicalize_max_exceeded() {
         let input = SignatureComponent::try_from((SECP_256K1_N + 1).to_be_bytes()).unwrap();
 
-        wrap_s(input);
+        let right = canonicalize(&[0x01; 32], input).unwrap_err();
+
+        assert_eq!(SignatureError::SOutOfRange, right);
     }
 
     #[test]
@@ -68,4 +105,54 @@ mod unit_tests {
 
         assert_eq!(left, right);
     }
+
+    #[test]
+    fn test_wrap_s_half() {
+        let input = SignatureComponent::try_from((SECP_256K1_N / 2).to_be_bytes()).unwrap();
+
+        let right = wrap_s(input);
+
+        assert_eq!(input, right);
+        assert!(check_low_s(&right).is_ok());
+    }
+
+    #[test]
+    fn check_low_s_fail() {
+        let input = SignatureComponent::try_from((SECP_256K1_N / 2 + 1).to_be_bytes()).unwrap();
+
+        let right = check_low_s(&input).unwrap_err();
+
+        assert_eq!(SignatureError::HighS, right);
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let input = SignatureComponent::try_from((SECP_256K1_N - 1).to_be_bytes()).unwrap();
+
+        let left = U256([0x01, 0x00]).to_be_bytes();
+        let right = canonicalize(&input, input).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn test_canonicalize_zero() {
+        assert_eq!(
+            SignatureError::ZeroR,
+            canonicalize(&[0x00; 32], [0x01; 32]).unwrap_err()
+        );
+        assert_eq!(
+            SignatureError::ZeroS,
+            canonicalize(&[0x01; 32], [0x00; 32]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_r_out_of_range() {
+        let input = SignatureComponent::try_from(SECP_256K1_N.to_be_bytes()).unwrap();
+
+        let right = canonicalize(&input, [0x01; 32]).unwrap_err();
+
+        assert_eq!(SignatureError::ROutOfRange, right);
+    }
 }
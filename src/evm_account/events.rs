@@ -0,0 +1,400 @@
+use std::io::Error;
+#[cfg(feature = "notifications")]
+use std::{fmt, io::ErrorKind, time::Duration};
+
+#[cfg(feature = "notifications")]
+use aws_config::SdkConfig;
+#[cfg(feature = "notifications")]
+use aws_sdk_eventbridge::types::PutEventsRequestEntry;
+#[cfg(feature = "notifications")]
+use hmac::{Hmac, Mac};
+#[cfg(feature = "notifications")]
+use reqwest::header::CONTENT_TYPE;
+use serde::Serialize;
+#[cfg(feature = "notifications")]
+use sha2::Sha256;
+
+#[cfg(feature = "notifications")]
+use super::rpc::http_client;
+use super::{
+    hash::Keccak256Digest,
+    transaction::{AccountAddress, Bytes},
+};
+
+// Source and detail type of EventBridge events, used in event patterns of rules.
+#[cfg(feature = "notifications")]
+const EVENT_SOURCE: &str = "evm-signer-kms";
+#[cfg(feature = "notifications")]
+const EVENT_DETAIL_TYPE: &str = "Signing Event";
+// Header of webhook requests carrying the HMAC-SHA256 of the body, like GitHub webhooks
+#[cfg(feature = "notifications")]
+const SIGNATURE_HEADER: &str = "X-Signature-256";
+#[cfg(feature = "notifications")]
+const SIGNATURE_PREFIX: &str = "sha256=";
+#[cfg(feature = "notifications")]
+const HTTPS_SCHEME: &str = "https://";
+// Time after which a webhook request is abandoned, so that unresponsive receivers do not pile up
+// pending requests
+#[cfg(feature = "notifications")]
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Result of a signing attempt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SigningOutcome {
+    /// The digest was signed.
+    Signed,
+    /// The transaction violated the signing policy and was not sent to KMS.
+    Rejected,
+    /// Signing failed, e.g. KMS denied access to the key.
+    Failed,
+}
+
+/// Context of a signing attempt supplied by the caller, e.g. the ID of the business request which
+/// originated it, attached to the tracing span and the event of the attempt.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SigningContext {
+    /// Correlation (or request) ID tying the signature back to the originating request.
+    pub correlation_id: Option<String>,
+}
+
+impl SigningContext {
+    /// Creates the context of the request with the correlation ID.
+    pub fn with_correlation_id(correlation_id: impl Into<String>) -> Self {
+        Self {
+            correlation_id: Some(correlation_id.into()),
+        }
+    }
+}
+
+/// Structured event published after each signing attempt.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SigningEvent {
+    /// ID of the KMS key, as the account was created with.
+    pub key_id: String,
+    /// Address of the account.
+    pub address: AccountAddress,
+    /// Hash of the signed transaction, or `None` for messages and failed attempts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<Bytes>,
+    /// Digest which was (or was to be) signed.
+    pub digest: Bytes,
+    /// Result of the attempt.
+    pub outcome: SigningOutcome,
+    /// Description of the error if the attempt did not succeed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Correlation ID of the `SigningContext` of the attempt, if supplied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+}
+
+impl SigningEvent {
+    /// Creates the event of a successful signing.
+    pub fn signed(
+        key_id: &str,
+        address: AccountAddress,
+        digest: &Keccak256Digest,
+        tx_hash: Option<&Keccak256Digest>,
+    ) -> Self {
+        Self {
+            key_id: key_id.to_string(),
+            address,
+            tx_hash: tx_hash.map(|tx_hash| Bytes::from(tx_hash.as_slice())),
+            digest: Bytes::from(digest.as_slice()),
+            outcome: SigningOutcome::Signed,
+            error: None,
+            correlation_id: None,
+        }
+    }
+
+    /// Creates the event of an unsuccessful signing attempt.
+    pub fn unsuccessful(
+        key_id: &str,
+        address: AccountAddress,
+        digest: &Keccak256Digest,
+        outcome: SigningOutcome,
+        error: &Error,
+    ) -> Self {
+        Self {
+            key_id: key_id.to_string(),
+            address,
+            tx_hash: None,
+            digest: Bytes::from(digest.as_slice()),
+            outcome,
+            error: Some(error.to_string()),
+            correlation_id: None,
+        }
+    }
+
+    /// Attaches the correlation ID of the context to the event.
+    pub fn with_context(mut self, context: &SigningContext) -> Self {
+        self.correlation_id = context.correlation_id.clone();
+        self
+    }
+}
+
+/// Destination of signing events, attached to an account with `EvmAccount::with_notifier`.
+///
+/// The account publishes events in the background and ignores failures to deliver them, so
+/// delivery is best effort.
+#[cfg(feature = "notifications")]
+#[derive(Clone, Debug)]
+pub enum Notifier {
+    /// Publishes events as JSON messages to an SNS topic.
+    Sns {
+        client: aws_sdk_sns::Client,
+        topic_arn: String,
+    },
+    /// Puts events with the `evm-signer-kms` source on an EventBridge event bus.
+    EventBridge {
+        client: aws_sdk_eventbridge::Client,
+        event_bus_name: String,
+    },
+    /// Posts events as JSON to an HTTPS webhook, e.g. of a SIEM, with the HMAC-SHA256 of the body
+    /// in the `X-Signature-256` header, e.g. `sha256=9f86d0...`.
+    Webhook {
+        client: reqwest::Client,
+        url: String,
+        secret: WebhookSecret,
+    },
+}
+
+/// Shared secret of the HMAC signatures of webhook requests, which is not disclosed by `Debug`.
+#[cfg(feature = "notifications")]
+#[derive(Clone)]
+pub struct WebhookSecret(Vec<u8>);
+
+#[cfg(feature = "notifications")]
+impl WebhookSecret {
+    /// Returns the signature of the body, i.e. its HMAC-SHA256 in hex prefixed with `sha256=`.
+    ///
+    /// Receivers verify requests by computing the signature of the raw body and comparing it to
+    /// the `X-Signature-256` header in constant time.
+    pub fn sign(&self, body: &[u8]) -> String {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.0).expect("HMAC accepts keys of any length");
+        mac.update(body);
+
+        format!(
+            "{}{}",
+            SIGNATURE_PREFIX,
+            hex::encode(mac.finalize().into_bytes())
+        )
+    }
+}
+
+#[cfg(feature = "notifications")]
+impl<T: Into<Vec<u8>>> From<T> for WebhookSecret {
+    fn from(secret: T) -> Self {
+        Self(secret.into())
+    }
+}
+
+#[cfg(feature = "notifications")]
+impl fmt::Debug for WebhookSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WebhookSecret(****)")
+    }
+}
+
+#[cfg(feature = "notifications")]
+impl Notifier {
+    /// Creates a notifier publishing to the SNS topic.
+    pub fn sns(config: &SdkConfig, topic_arn: impl Into<String>) -> Self {
+        Self::Sns {
+            client: aws_sdk_sns::Client::new(config),
+            topic_arn: topic_arn.into(),
+        }
+    }
+
+    /// Creates a notifier putting events on the EventBridge event bus, e.g. `default`.
+    pub fn event_bridge(config: &SdkConfig, event_bus_name: impl Into<String>) -> Self {
+        Self::EventBridge {
+            client: aws_sdk_eventbridge::Client::new(config),
+            event_bus_name: event_bus_name.into(),
+        }
+    }
+
+    /// Creates a notifier posting events to the webhook, signed with the shared secret. Requests
+    /// time out after 10 seconds.
+    ///
+    /// Fails if the URL is not HTTPS, as the events would be sent in plain text, or if the HTTP
+    /// client cannot be initialized.
+    pub fn webhook(
+        url: impl Into<String>,
+        secret: impl Into<WebhookSecret>,
+    ) -> Result<Self, Error> {
+        let url = url.into();
+        if !url.starts_with(HTTPS_SCHEME) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Webhook URL must use HTTPS",
+            ));
+        }
+
+        Ok(Self::Webhook {
+            client: http_client()?,
+            url,
+            secret: secret.into(),
+        })
+    }
+
+    /// Publishes the event.
+    pub async fn publish(&self, event: &SigningEvent) -> Result<(), Error> {
+        let event_json = serde_json::to_string(event)?;
+
+        match self {
+            Self::Sns { client, topic_arn } => {
+                client
+                    .publish()
+                    .topic_arn(topic_arn)
+                    .message(event_json)
+                    .send()
+                    .await
+                    .map_err(|error| publish_error(&format!("{:?}", error)))?;
+            }
+            Self::EventBridge {
+                client,
+                event_bus_name,
+            } => {
+                let entry = PutEventsRequestEntry::builder()
+                    .event_bus_name(event_bus_name)
+                    .source(EVENT_SOURCE)
+                    .detail_type(EVENT_DETAIL_TYPE)
+                    .detail(event_json)
+                    .build();
+                let output = client
+                    .put_events()
+                    .entries(entry)
+                    .send()
+                    .await
+                    .map_err(|error| publish_error(&format!("{:?}", error)))?;
+
+                if output.failed_entry_count() > 0 {
+                    return Err(publish_error("Event was not put on the event bus"));
+                }
+            }
+            Self::Webhook {
+                client,
+                url,
+                secret,
+            } => {
+                let response = client
+                    .post(url)
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(SIGNATURE_HEADER, secret.sign(event_json.as_bytes()))
+                    .timeout(WEBHOOK_TIMEOUT)
+                    .body(event_json)
+                    .send()
+                    .await
+                    .map_err(|error| publish_error(&error.to_string()))?;
+
+                if !response.status().is_success() {
+                    return Err(publish_error(&format!(
+                        "Webhook responded with {}",
+                        response.status()
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "notifications")]
+fn publish_error(message: &str) -> Error {
+    Error::other(format!("Error publishing signing event: {}", message))
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use std::io::ErrorKind;
+
+    const TEST_ADDRESS: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+    const TEST_DIGEST: Keccak256Digest = [0xab; 32];
+
+    #[test]
+    fn serialize_signed_event_succeed() {
+        let input = SigningEvent::signed(
+            "alias/treasury",
+            TEST_ADDRESS.parse().unwrap(),
+            &TEST_DIGEST,
+            Some(&[0xcd; 32]),
+        );
+
+        let right = serde_json::to_value(&input).unwrap();
+
+        assert_eq!("alias/treasury", right["keyId"]);
+        assert_eq!(TEST_ADDRESS, right["address"]);
+        assert_eq!(format!("0x{}", "cd".repeat(32)), right["txHash"]);
+        assert_eq!(format!("0x{}", "ab".repeat(32)), right["digest"]);
+        assert_eq!("signed", right["outcome"]);
+        assert!(right.get("error").is_none());
+        assert!(right.get("correlationId").is_none());
+    }
+
+    #[test]
+    fn serialize_event_with_context_succeed() {
+        let input = SigningEvent::signed(
+            "alias/treasury",
+            TEST_ADDRESS.parse().unwrap(),
+            &TEST_DIGEST,
+            None,
+        )
+        .with_context(&SigningContext::with_correlation_id("order-1234"));
+
+        let right = serde_json::to_value(&input).unwrap();
+
+        assert_eq!("order-1234", right["correlationId"]);
+    }
+
+    #[test]
+    fn serialize_rejected_event_succeed() {
+        let input = SigningEvent::unsuccessful(
+            "alias/treasury",
+            TEST_ADDRESS.parse().unwrap(),
+            &TEST_DIGEST,
+            SigningOutcome::Rejected,
+            &Error::new(ErrorKind::PermissionDenied, "Chain ID 1 is not permitted"),
+        );
+
+        let right = serde_json::to_value(&input).unwrap();
+
+        assert_eq!("rejected", right["outcome"]);
+        assert_eq!("Chain ID 1 is not permitted", right["error"]);
+        assert!(right.get("txHash").is_none());
+    }
+
+    #[cfg(feature = "notifications")]
+    #[test]
+    fn webhook_secret_sign_succeed() {
+        let input = WebhookSecret::from("key");
+
+        let right = input.sign(b"The quick brown fox jumps over the lazy dog");
+
+        assert_eq!(
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8",
+            right
+        );
+    }
+
+    #[cfg(feature = "notifications")]
+    #[test]
+    fn webhook_secret_debug_succeed() {
+        let input = WebhookSecret::from("key");
+
+        assert_eq!("WebhookSecret(****)", format!("{:?}", input));
+    }
+
+    #[cfg(feature = "notifications")]
+    #[test]
+    fn webhook_plain_http_fail() {
+        let right = Notifier::webhook("http://siem.example.com/events", "key").unwrap_err();
+
+        assert_eq!(ErrorKind::InvalidInput, right.kind());
+    }
+}
@@ -0,0 +1,150 @@
+use sha3::{Digest, Keccak256};
+
+use super::{transaction::AccountAddress, PublicKey, KECCAK_256_LENGTH};
+
+// Prefix of messages signed with `personal_sign` (see EIP-191, version 0x45).
+const EIP_191_MESSAGE_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+// Prefix of EIP-712 digests, i.e. EIP-191 version 0x01.
+const EIP_712_PREFIX: [u8; 2] = [0x19, 0x01];
+// Address is the lowest 20 bytes of the public key hash.
+const ADDRESS_OFFSET: usize = 12;
+// Number of decimal digits of `usize::MAX` on 64-bit targets.
+const MAX_DECIMAL_LENGTH: usize = 20;
+
+/// 32-byte Keccak-256 digest.
+pub type Keccak256Digest = [u8; KECCAK_256_LENGTH];
+
+/// Computes the Keccak-256 digest of the data (i.e. the hash function used by the EVM).
+pub fn keccak256(data: impl AsRef<[u8]>) -> Keccak256Digest {
+    Keccak256::digest(data).into()
+}
+
+/// Derives the account address from the raw, uncompressed 64-byte public key (i.e. with no
+/// `0x04` prefix), as stored in `EvmAccount::public_key`.
+pub fn address_from_public_key(public_key: &PublicKey) -> AccountAddress {
+    let digest = keccak256(public_key);
+
+    let mut address = AccountAddress::default();
+    address.0.copy_from_slice(&digest[ADDRESS_OFFSET..]);
+
+    address
+}
+
+/// Computes the digest of the message prefixed according to
+/// [`EIP-191`](https://eips.ethereum.org/EIPS/eip-191), i.e. the digest signed by
+/// `personal_sign` and `eth_sign`.
+pub fn eip191_hash_message(message: impl AsRef<[u8]>) -> Keccak256Digest {
+    let message = message.as_ref();
+
+    let mut hasher = Keccak256::new();
+    hasher.update(EIP_191_MESSAGE_PREFIX);
+    hasher.update(decimal(message.len(), &mut [0u8; MAX_DECIMAL_LENGTH]));
+    hasher.update(message);
+
+    hasher.finalize().into()
+}
+
+// Writes the decimal representation of the value to the end of the buffer, so that hashing the
+// message length needs no allocation.
+fn decimal(mut value: usize, buffer: &mut [u8; MAX_DECIMAL_LENGTH]) -> &[u8] {
+    let mut start = MAX_DECIMAL_LENGTH;
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+
+    &buffer[start..]
+}
+
+/// Computes the digest of typed structured data according to
+/// [`EIP-712`](https://eips.ethereum.org/EIPS/eip-712), i.e. the digest signed by
+/// `eth_signTypedData_v4`.
+pub fn eip712_hash(
+    domain_separator: &Keccak256Digest,
+    struct_hash: &Keccak256Digest,
+) -> Keccak256Digest {
+    let mut hasher = Keccak256::new();
+    hasher.update(EIP_712_PREFIX);
+    hasher.update(domain_separator);
+    hasher.update(struct_hash);
+
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    // Public key of the private key `0x01`
+    const TEST_PUBLIC_KEY: PublicKey = [
+        0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87, 0x0b,
+        0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16, 0xf8,
+        0x17, 0x98, 0x48, 0x3a, 0xda, 0x77, 0x26, 0xa3, 0xc4, 0x65, 0x5d, 0xa4, 0xfb, 0xfc, 0x0e,
+        0x11, 0x08, 0xa8, 0xfd, 0x17, 0xb4, 0x48, 0xa6, 0x85, 0x54, 0x19, 0x9c, 0x47, 0xd0, 0x8f,
+        0xfb, 0x10, 0xd4, 0xb8,
+    ];
+
+    #[test]
+    fn keccak256_empty_succeed() {
+        let left = "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+
+        let right = hex::encode(keccak256(b""));
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn address_from_public_key_succeed() {
+        let left: AccountAddress = "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"
+            .parse()
+            .unwrap();
+
+        let right = address_from_public_key(&TEST_PUBLIC_KEY);
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn eip191_hash_message_succeed() {
+        let left = "a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2";
+
+        let right = hex::encode(eip191_hash_message("Hello World"));
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn decimal_succeed() {
+        let mut buffer = [0u8; MAX_DECIMAL_LENGTH];
+
+        assert_eq!(b"0", decimal(0, &mut buffer));
+        assert_eq!(b"1024", decimal(1024, &mut buffer));
+        assert_eq!(
+            usize::MAX.to_string().as_bytes(),
+            decimal(usize::MAX, &mut buffer)
+        );
+    }
+
+    #[test]
+    fn eip712_hash_succeed() {
+        // `Mail` example of EIP-712
+        let domain_separator =
+            hex::decode("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+                .unwrap();
+        let struct_hash =
+            hex::decode("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+                .unwrap();
+        let left = "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2";
+
+        let right = hex::encode(eip712_hash(
+            &domain_separator.try_into().unwrap(),
+            &struct_hash.try_into().unwrap(),
+        ));
+
+        assert_eq!(left, right);
+    }
+}
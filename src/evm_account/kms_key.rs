@@ -1,10 +1,25 @@
-use aws_config::SdkConfig;
+use aws_config::{ConfigLoader, SdkConfig};
+#[cfg(feature = "tracing")]
+use aws_sdk_kms::operation::RequestId;
 use aws_sdk_kms::{
     primitives::Blob,
-    types::{MessageType, SigningAlgorithmSpec},
+    types::{KeyMetadata, KeySpec, KeyState, KeyUsageType, MessageType, SigningAlgorithmSpec},
     Client,
 };
-use std::io::{Error, ErrorKind, Result};
+#[cfg(any(feature = "rustls", feature = "native-tls"))]
+use aws_smithy_runtime::client::http::hyper_014::HyperClientBuilder;
+use std::{
+    io::{Error, ErrorKind, Result},
+    time::Duration,
+};
+
+// Propagation of the OpenTelemetry context into KMS requests.
+#[cfg(feature = "otel")]
+mod trace_context;
+
+// Number of trailing characters of key IDs left visible by `redact_key_id`
+const VISIBLE_KEY_ID_SUFFIX_LENGTH: usize = 4;
+const REDACTED_KEY_ID_PREFIX: &str = "****";
 
 /// Representation of `secp256k1` key pair stored in AWS KMS.
 ///
@@ -36,7 +51,8 @@ use std::io::{Error, ErrorKind, Result};
 /// }
 /// ```
 pub struct KmsKey<'a> {
-    config: SdkConfig,
+    // Created once, so that connections and credentials are reused across calls
+    client: Client,
     kms_key_id: &'a str,
 }
 
@@ -62,9 +78,28 @@ impl<'a> KmsKey<'a> {
     /// The method relies on the AWS SDK to do the validation.
 
     pub async fn new(kms_key_id: &'a str) -> KmsKey<'a> {
-        let config = aws_config::from_env().load().await;
+        let config = aws_config_loader().load().await;
+
+        Self::with_config(kms_key_id, config)
+    }
+
+    /// Creates a new `KmsKey` instance tied to KMS key identified by KMS key ID, using the AWS
+    /// configuration provided instead of the one set in the environment, e.g. with credentials of
+    /// an assumed role.
+    pub fn with_config(kms_key_id: &'a str, config: SdkConfig) -> KmsKey<'a> {
+        let client = kms_client(&config);
 
-        KmsKey { config, kms_key_id }
+        KmsKey { client, kms_key_id }
+    }
+
+    /// Returns the ID of the KMS key, as the instance was created with.
+    pub fn key_id(&self) -> &str {
+        self.kms_key_id
+    }
+
+    /// Returns the region KMS requests are sent to, or `None` if no region is configured.
+    pub fn region(&self) -> Option<&str> {
+        self.client.config().region().map(|region| region.as_ref())
     }
 
     /// Retrieves the public key associated with the private key.
@@ -77,14 +112,31 @@ impl<'a> KmsKey<'a> {
     /// $ aws kms get-public-key --key-id <kms_key_id> --region $AWS_REGION | jq -r .PublicKey | base64 -d | xxd -c 0 -ps
     /// 3056301006072a8648ce3d020106052b8104000a034200043b5ca9876d1c4ca39838fd8ef1bc4b138a1edf73ad8e29b9f6338f39e4a6f64c7d83df86b01deb689c6d14536413fce6752f4df7240d7180b53f27f5611d06a3
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "kms.get_public_key",
+            level = "debug",
+            skip_all,
+            fields(
+                key_id = %redact_key_id(self.kms_key_id),
+                aws.request_id = tracing::field::Empty,
+            ),
+            err
+        )
+    )]
     pub async fn get_public_key(&self) -> Result<Vec<u8>> {
-        let client = Client::new(&self.config);
-
-        let get_public_key_output = client.get_public_key().key_id(self.kms_key_id).send();
+        let get_public_key_output = self
+            .client
+            .get_public_key()
+            .key_id(self.kms_key_id)
+            .send()
+            .await;
+        #[cfg(feature = "tracing")]
+        record_request_id(&get_public_key_output);
 
         // Retrieve DER encoded public key
         let public_key_blob = get_public_key_output
-            .await
             .map_err(|error| {
                 Error::new(
                     ErrorKind::NotFound,
@@ -103,24 +155,123 @@ impl<'a> KmsKey<'a> {
         Ok(public_key_blob.into_inner())
     }
 
+    /// Checks whether the key is enabled, i.e. can be used for signing.
+    ///
+    /// Fails if KMS is unreachable or the key cannot be described with the credentials, which makes
+    /// it suitable for readiness checks of services.
+    pub async fn is_enabled(&self) -> Result<bool> {
+        let key_metadata = self.describe().await?;
+
+        Ok(key_metadata.key_state() == Some(&KeyState::Enabled))
+    }
+
+    /// Validates that the key can sign EVM transactions, i.e. that it is an enabled
+    /// `ECC_SECG_P256K1` key for signing and verification.
+    ///
+    /// Fails with `ErrorKind::InvalidInput` if the key spec or usage is wrong and with
+    /// `ErrorKind::PermissionDenied` if the key is not enabled, e.g. pending deletion.
+    pub async fn validate(&self) -> Result<()> {
+        let key_metadata = self.describe().await?;
+
+        if key_metadata.key_spec() != Some(&KeySpec::EccSecgP256K1) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Unsupported key spec: {}",
+                    key_metadata.key_spec().map_or("unknown", KeySpec::as_str)
+                ),
+            ));
+        }
+        if key_metadata.key_usage() != Some(&KeyUsageType::SignVerify) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "Unsupported key usage: {}",
+                    key_metadata
+                        .key_usage()
+                        .map_or("unknown", KeyUsageType::as_str)
+                ),
+            ));
+        }
+        if key_metadata.key_state() != Some(&KeyState::Enabled) {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                format!(
+                    "Key is not enabled: {}",
+                    key_metadata.key_state().map_or("unknown", KeyState::as_str)
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "kms.describe_key",
+            level = "debug",
+            skip_all,
+            fields(
+                key_id = %redact_key_id(self.kms_key_id),
+                aws.request_id = tracing::field::Empty,
+            ),
+            err
+        )
+    )]
+    async fn describe(&self) -> Result<KeyMetadata> {
+        let describe_key_output = self
+            .client
+            .describe_key()
+            .key_id(self.kms_key_id)
+            .send()
+            .await;
+        #[cfg(feature = "tracing")]
+        record_request_id(&describe_key_output);
+
+        describe_key_output
+            .map_err(|error| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("Error describing key: {:?}", error),
+                )
+            })?
+            .key_metadata
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "Invalid response. No key found"))
+    }
+
     /// Signs a message digest using the private key.
     ///
     /// Expects a 32-byte digest of the message to be signed.
     ///
     /// Returns a DER encoded signature. Note that the signature is different every time.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "kms.sign",
+            level = "debug",
+            skip_all,
+            fields(
+                key_id = %redact_key_id(self.kms_key_id),
+                aws.request_id = tracing::field::Empty,
+            ),
+            err
+        )
+    )]
     pub async fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
-        let client = Client::new(&self.config);
-
-        let sign_output = client
+        let sign_output = self
+            .client
             .sign()
             .key_id(self.kms_key_id)
             .signing_algorithm(SigningAlgorithmSpec::EcdsaSha256)
             .message_type(MessageType::Digest)
             .message(Blob::new(message))
-            .send();
+            .send()
+            .await;
+        #[cfg(feature = "tracing")]
+        record_request_id(&sign_output);
 
         let signature = sign_output
-            .await
             .map_err(|error| {
                 Error::new(
                     ErrorKind::PermissionDenied,
@@ -140,3 +291,213 @@ impl<'a> KmsKey<'a> {
         Ok(signature.into_inner())
     }
 }
+
+// Annotates the current span with the ID of the KMS request, which identifies its CloudTrail event
+#[cfg(feature = "tracing")]
+fn record_request_id(response: &impl RequestId) {
+    if let Some(request_id) = response.request_id() {
+        tracing::Span::current().record("aws.request_id", request_id);
+    }
+}
+
+#[cfg(not(feature = "otel"))]
+fn kms_client(config: &SdkConfig) -> Client {
+    Client::new(config)
+}
+
+// Propagates the OpenTelemetry context of the current span into the requests
+#[cfg(feature = "otel")]
+fn kms_client(config: &SdkConfig) -> Client {
+    let config = aws_sdk_kms::config::Builder::from(config)
+        .interceptor(trace_context::TraceContextInterceptor)
+        .build();
+
+    Client::from_conf(config)
+}
+
+/// Masks all but the last characters of the key ID, e.g. `****abcd`, so that it can be logged or
+/// attached to traces without disclosing the key.
+pub fn redact_key_id(key_id: &str) -> String {
+    let length = key_id.chars().count();
+    if length <= 2 * VISIBLE_KEY_ID_SUFFIX_LENGTH {
+        return REDACTED_KEY_ID_PREFIX.to_string();
+    }
+
+    let suffix: String = key_id
+        .chars()
+        .skip(length - VISIBLE_KEY_ID_SUFFIX_LENGTH)
+        .collect();
+
+    format!("{}{}", REDACTED_KEY_ID_PREFIX, suffix)
+}
+
+/// Returns the loader of the AWS configuration from the environment, like `aws_config::from_env`,
+/// using the TLS backend selected with the `rustls` (default) and `native-tls` features.
+///
+/// Use it instead of `aws_config::from_env` to build configurations passed to
+/// `KmsKey::with_config`, e.g. with credentials of an assumed role.
+#[cfg(not(feature = "native-tls"))]
+pub fn aws_config_loader() -> ConfigLoader {
+    aws_config::from_env()
+}
+
+/// Returns the loader of the AWS configuration from the environment, like `aws_config::from_env`,
+/// using the TLS backend selected with the `rustls` (default) and `native-tls` features. Native TLS
+/// takes precedence, as the default `rustls` feature may still be enabled.
+///
+/// Use it instead of `aws_config::from_env` to build configurations passed to
+/// `KmsKey::with_config`, e.g. with credentials of an assumed role.
+#[cfg(feature = "native-tls")]
+pub fn aws_config_loader() -> ConfigLoader {
+    aws_config_loader_with(&HttpClientSettings::default())
+}
+
+/// Returns the loader of the AWS configuration from the environment like `aws_config_loader`, with
+/// the HTTP client tuned according to the settings.
+#[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+pub fn aws_config_loader_with(settings: &HttpClientSettings) -> ConfigLoader {
+    let http_client = HyperClientBuilder::new()
+        .hyper_builder(settings.hyper_builder())
+        .build_https();
+
+    aws_config::from_env().http_client(http_client)
+}
+
+/// Returns the loader of the AWS configuration from the environment like `aws_config_loader`, with
+/// the HTTP client tuned according to the settings.
+#[cfg(feature = "native-tls")]
+pub fn aws_config_loader_with(settings: &HttpClientSettings) -> ConfigLoader {
+    let http_client = HyperClientBuilder::new()
+        .hyper_builder(settings.hyper_builder())
+        .build(hyper_tls::HttpsConnector::new());
+
+    aws_config::from_env().http_client(http_client)
+}
+
+/// Settings of the HTTP client sending KMS requests, so that high-throughput signers keep
+/// connections open rather than reconnecting under load, e.g.:
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use evm_signer_kms::evm_account::kms_key::{aws_config_loader_with, HttpClientSettings, KmsKey};
+///
+/// # async fn example() {
+/// let settings = HttpClientSettings::new()
+///     .with_pool_max_idle_per_host(64)
+///     .with_pool_idle_timeout(Duration::from_secs(90));
+/// let config = aws_config_loader_with(&settings).load().await;
+/// let kms_key = KmsKey::with_config("alias/treasury", config);
+/// # }
+/// ```
+///
+/// Settings which are not set are left at the defaults of the AWS SDK.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HttpClientSettings {
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    http2_only: bool,
+    http2_keep_alive_interval: Option<Duration>,
+    http2_keep_alive_timeout: Option<Duration>,
+}
+
+impl HttpClientSettings {
+    /// Creates settings leaving the HTTP client at the defaults of the AWS SDK.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of idle connections kept open to KMS, e.g. to the number of
+    /// concurrent signing requests.
+    pub fn with_pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+        self.pool_max_idle_per_host = Some(pool_max_idle_per_host);
+        self
+    }
+
+    /// Sets how long idle connections are kept open, i.e. HTTP/1.1 keep-alive.
+    pub fn with_pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
+
+    /// Sends requests over HTTP/2 only, multiplexing them over fewer connections.
+    pub fn with_http2_only(mut self, http2_only: bool) -> Self {
+        self.http2_only = http2_only;
+        self
+    }
+
+    /// Sends HTTP/2 keep-alive pings at the interval, also on idle connections, so that they are
+    /// not closed by intermediaries.
+    pub fn with_http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Sets how long to wait for the acknowledgement of a keep-alive ping before closing the
+    /// HTTP/2 connection.
+    pub fn with_http2_keep_alive_timeout(mut self, timeout: Duration) -> Self {
+        self.http2_keep_alive_timeout = Some(timeout);
+        self
+    }
+
+    #[cfg(any(feature = "rustls", feature = "native-tls"))]
+    fn hyper_builder(&self) -> hyper::client::Builder {
+        let mut builder = hyper::Client::builder();
+        if let Some(pool_max_idle_per_host) = self.pool_max_idle_per_host {
+            builder.pool_max_idle_per_host(pool_max_idle_per_host);
+        }
+        if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+            builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        builder.http2_only(self.http2_only);
+        if let Some(interval) = self.http2_keep_alive_interval {
+            builder
+                .http2_keep_alive_interval(interval)
+                .http2_keep_alive_while_idle(true);
+        }
+        if let Some(timeout) = self.http2_keep_alive_timeout {
+            builder.http2_keep_alive_timeout(timeout);
+        }
+
+        builder
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn redact_key_id_succeed() {
+        let input = "arn:aws:kms:eu-west-1:123456789012:key/1234abcd-12ab-34cd-56ef-1234567890ab";
+
+        assert_eq!("****90ab", redact_key_id(input));
+    }
+
+    #[test]
+    fn http_client_settings_succeed() {
+        let left = HttpClientSettings {
+            pool_max_idle_per_host: Some(64),
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            http2_only: true,
+            http2_keep_alive_interval: Some(Duration::from_secs(30)),
+            http2_keep_alive_timeout: Some(Duration::from_secs(5)),
+        };
+
+        let right = HttpClientSettings::new()
+            .with_pool_max_idle_per_host(64)
+            .with_pool_idle_timeout(Duration::from_secs(90))
+            .with_http2_only(true)
+            .with_http2_keep_alive_interval(Duration::from_secs(30))
+            .with_http2_keep_alive_timeout(Duration::from_secs(5));
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn redact_key_id_short_succeed() {
+        let input = "alias/a";
+
+        assert_eq!("****", redact_key_id(input));
+    }
+}
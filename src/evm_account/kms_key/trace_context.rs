@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use aws_sdk_kms::{
+    config::{
+        interceptors::BeforeTransmitInterceptorContextMut, ConfigBag, Intercept, RuntimeComponents,
+    },
+    error::BoxError,
+};
+use opentelemetry::global;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Injects the OpenTelemetry context of the current span into the headers of KMS requests, with
+/// the globally registered propagator, e.g. `traceparent` of the W3C trace context propagator.
+#[derive(Debug)]
+pub struct TraceContextInterceptor;
+
+impl Intercept for TraceContextInterceptor {
+    fn name(&self) -> &'static str {
+        "TraceContextInterceptor"
+    }
+
+    fn modify_before_signing(
+        &self,
+        context: &mut BeforeTransmitInterceptorContextMut<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let trace_context = tracing::Span::current().context();
+        let mut fields = HashMap::new();
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&trace_context, &mut fields)
+        });
+
+        let headers = context.request_mut().headers_mut();
+        for (name, value) in fields {
+            headers.try_insert(name, value)?;
+        }
+
+        Ok(())
+    }
+}
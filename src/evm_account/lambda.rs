@@ -0,0 +1,167 @@
+use std::{
+    env,
+    io::{Error, ErrorKind},
+    sync::Arc,
+};
+
+use lambda_runtime::{service_fn, LambdaEvent, Service};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{
+    events::SigningContext,
+    hash::keccak256,
+    kms_key::KmsKey,
+    policy::SigningPolicy,
+    transaction::{json::Mode, Bytes},
+    EvmAccount,
+};
+
+// Name of the environment variable with the KMS key ID used by `SignHandler::from_env`.
+const KMS_KEY_ID_VAR_NAME: &str = "KMS_KEY_ID";
+// Name of the environment variable which skips the validation of the key when set to `true`.
+const SKIP_KEY_VALIDATION_VAR_NAME: &str = "SKIP_KEY_VALIDATION";
+
+/// Response of the handler with the signed transaction.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignResponse {
+    /// Encoding of the signed transaction, ready to be broadcast.
+    pub raw_transaction: Bytes,
+    /// Hash of the signed transaction.
+    pub hash: Bytes,
+}
+
+/// Ready-made AWS Lambda handler which signs the transaction JSON of the event payload.
+///
+/// The handler is created once per execution environment, i.e. during the cold start, so that the
+/// KMS client and the public key are reused by all invocations:
+///
+/// ```rust,no_run
+/// use evm_signer_kms::evm_account::lambda::SignHandler;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), lambda_runtime::Error> {
+///     let handler = SignHandler::from_env().await?;
+///
+///     lambda_runtime::run(handler.into_service()).await
+/// }
+/// ```
+pub struct SignHandler {
+    evm_account: EvmAccount<'static>,
+    mode: Mode,
+}
+
+impl SignHandler {
+    /// Creates the handler of the account. Transactions are deserialized in the lenient mode.
+    pub fn new(evm_account: EvmAccount<'static>) -> Self {
+        Self {
+            evm_account,
+            mode: Mode::Lenient,
+        }
+    }
+
+    /// Creates the handler of the KMS key whose ID is in the `KMS_KEY_ID` environment variable,
+    /// enforcing the default signing policy.
+    pub async fn from_env() -> Result<Self, Error> {
+        Self::from_env_with_policy(SigningPolicy::default()).await
+    }
+
+    /// Creates the handler of the KMS key whose ID is in the `KMS_KEY_ID` environment variable,
+    /// enforcing the provided signing policy.
+    ///
+    /// The key is validated during the cold start (see `EvmAccount::with_policy`), unless the
+    /// `SKIP_KEY_VALIDATION` environment variable is `true`, which saves the `DescribeKey` request.
+    pub async fn from_env_with_policy(policy: SigningPolicy) -> Result<Self, Error> {
+        let kms_key_id = env::var(KMS_KEY_ID_VAR_NAME).map_err(|_| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("Environment variable {} not set", KMS_KEY_ID_VAR_NAME),
+            )
+        })?;
+
+        // The key is used by the handler until the execution environment is shut down
+        let kms_key_id = Box::leak(kms_key_id.into_boxed_str());
+        let kms_key = Box::leak(Box::new(KmsKey::new(kms_key_id).await));
+        let evm_account = if is_enabled_flag(env::var(SKIP_KEY_VALIDATION_VAR_NAME).ok()) {
+            EvmAccount::with_policy_unvalidated(kms_key, policy).await?
+        } else {
+            EvmAccount::with_policy(kms_key, policy).await?
+        };
+
+        Ok(Self::new(evm_account))
+    }
+
+    /// Sets the strictness of transaction JSON deserialization.
+    pub fn with_mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Signs the transaction JSON of any supported type (see `EvmAccount::sign_transaction_json`).
+    pub async fn handle(&self, payload: Value) -> Result<SignResponse, Error> {
+        self.handle_with_context(payload, &SigningContext::default())
+            .await
+    }
+
+    /// Signs the transaction JSON like `handle`, attaching the context of the invocation to the
+    /// signing attempt.
+    pub async fn handle_with_context(
+        &self,
+        payload: Value,
+        context: &SigningContext,
+    ) -> Result<SignResponse, Error> {
+        let raw_transaction = self
+            .evm_account
+            .sign_transaction_json_with_context(&payload.to_string(), self.mode, context)
+            .await?;
+        let hash = keccak256(&raw_transaction);
+
+        Ok(SignResponse {
+            raw_transaction: Bytes(raw_transaction),
+            hash: Bytes::from(hash.as_slice()),
+        })
+    }
+
+    /// Wraps the handler in a service run by `lambda_runtime::run`. The ID of the invocation is the
+    /// correlation ID of the signing attempt.
+    pub fn into_service(
+        self,
+    ) -> impl Service<LambdaEvent<Value>, Response = SignResponse, Error = lambda_runtime::Error>
+    {
+        let handler = Arc::new(self);
+
+        service_fn(move |event: LambdaEvent<Value>| {
+            let handler = Arc::clone(&handler);
+            async move {
+                let context = SigningContext::with_correlation_id(event.context.request_id);
+                handler
+                    .handle_with_context(event.payload, &context)
+                    .await
+                    .map_err(lambda_runtime::Error::from)
+            }
+        })
+    }
+}
+
+// Whether the value of the flag environment variable enables it, i.e. is `true` or `1`
+fn is_enabled_flag(value: Option<String>) -> bool {
+    matches!(value.as_deref().map(str::trim), Some("true" | "1"))
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn is_enabled_flag_succeed() {
+        assert!(is_enabled_flag(Some("true".to_string())));
+        assert!(is_enabled_flag(Some("1".to_string())));
+    }
+
+    #[test]
+    fn is_enabled_flag_fail() {
+        assert!(!is_enabled_flag(None));
+        assert!(!is_enabled_flag(Some("false".to_string())));
+    }
+}
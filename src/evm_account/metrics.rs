@@ -0,0 +1,196 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Value};
+
+use super::events::{SigningEvent, SigningOutcome};
+
+// Dimension of all metrics, so that they are reported per key
+const KEY_ID_DIMENSION: &str = "KeyId";
+const SIGN_COUNT_METRIC: &str = "SignCount";
+const SIGN_LATENCY_METRIC: &str = "SignLatency";
+const SIGN_ERRORS_METRIC: &str = "SignErrors";
+const SIGN_REJECTIONS_METRIC: &str = "SignRejections";
+const SLOW_KMS_CALLS_METRIC: &str = "SlowKmsCalls";
+const KMS_SIGN_LATENCY_METRIC: &str = "KmsSignLatency";
+const COUNT_UNIT: &str = "Count";
+const MILLISECONDS_UNIT: &str = "Milliseconds";
+
+/// Sink of signing metrics in the CloudWatch
+/// [Embedded Metric Format](https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/CloudWatch_Embedded_Metric_Format_Specification.html),
+/// attached to an account with `EvmAccount::with_metrics`.
+///
+/// Each signing attempt is written to standard output as a single line of JSON, which Lambda and
+/// ECS (with the `awslogs` log driver) ship to CloudWatch Logs, where the metrics are extracted
+/// without a metrics pipeline. The metrics are reported per KMS key ID:
+///
+/// * `SignCount` - signing attempts
+/// * `SignLatency` - duration of the attempt in milliseconds, including the KMS call
+/// * `SignErrors` - failed attempts, e.g. KMS denied access to the key
+/// * `SignRejections` - transactions which violated the signing policy
+/// * `SlowKmsCalls` and `KmsSignLatency` - KMS calls slower than the threshold set with
+///   `EvmAccount::with_slow_call_threshold`, along with the region of the key
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmfSink {
+    namespace: String,
+}
+
+impl EmfSink {
+    /// Creates a sink reporting metrics in the CloudWatch namespace, e.g. `EvmSignerKms`.
+    pub fn new(namespace: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+        }
+    }
+
+    /// Writes the metrics of the signing attempt to standard output.
+    pub fn emit(&self, event: &SigningEvent, latency: Duration) {
+        println!("{}", self.to_json(event, latency, SystemTime::now()));
+    }
+
+    /// Writes the metrics of the KMS call slower than the threshold to standard output.
+    pub fn emit_slow_call(&self, key_id: &str, region: Option<&str>, latency: Duration) {
+        println!(
+            "{}",
+            self.slow_call_to_json(key_id, region, latency, SystemTime::now())
+        );
+    }
+
+    /// Returns the Embedded Metric Format document of the signing attempt which took `latency` and
+    /// finished at `timestamp`, e.g. to ship it with a custom log writer.
+    pub fn to_json(&self, event: &SigningEvent, latency: Duration, timestamp: SystemTime) -> Value {
+        let metrics = [
+            (SIGN_COUNT_METRIC, COUNT_UNIT),
+            (SIGN_LATENCY_METRIC, MILLISECONDS_UNIT),
+            (SIGN_ERRORS_METRIC, COUNT_UNIT),
+            (SIGN_REJECTIONS_METRIC, COUNT_UNIT),
+        ];
+
+        json!({
+            "_aws": self.metadata(&metrics, timestamp),
+            KEY_ID_DIMENSION: event.key_id,
+            "Outcome": event.outcome,
+            SIGN_COUNT_METRIC: 1,
+            SIGN_LATENCY_METRIC: to_milliseconds(latency),
+            SIGN_ERRORS_METRIC: u32::from(event.outcome == SigningOutcome::Failed),
+            SIGN_REJECTIONS_METRIC: u32::from(event.outcome == SigningOutcome::Rejected),
+        })
+    }
+
+    /// Returns the Embedded Metric Format document of the KMS call which took `latency` and
+    /// finished at `timestamp`. The region is a property rather than a dimension, so that the
+    /// metrics of a key are aggregated across regions.
+    pub fn slow_call_to_json(
+        &self,
+        key_id: &str,
+        region: Option<&str>,
+        latency: Duration,
+        timestamp: SystemTime,
+    ) -> Value {
+        let metrics = [
+            (SLOW_KMS_CALLS_METRIC, COUNT_UNIT),
+            (KMS_SIGN_LATENCY_METRIC, MILLISECONDS_UNIT),
+        ];
+
+        json!({
+            "_aws": self.metadata(&metrics, timestamp),
+            KEY_ID_DIMENSION: key_id,
+            "Region": region,
+            SLOW_KMS_CALLS_METRIC: 1,
+            KMS_SIGN_LATENCY_METRIC: to_milliseconds(latency),
+        })
+    }
+
+    // Metadata directing CloudWatch to extract the metrics, given by names and units
+    fn metadata(&self, metrics: &[(&str, &str)], timestamp: SystemTime) -> Value {
+        let timestamp = timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        let metrics: Vec<Value> = metrics
+            .iter()
+            .map(|(name, unit)| json!({ "Name": name, "Unit": unit }))
+            .collect();
+
+        json!({
+            "Timestamp": timestamp,
+            "CloudWatchMetrics": [{
+                "Namespace": self.namespace,
+                "Dimensions": [[KEY_ID_DIMENSION]],
+                "Metrics": metrics,
+            }],
+        })
+    }
+}
+
+fn to_milliseconds(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use std::io::{Error, ErrorKind};
+
+    const TEST_ADDRESS: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+    const TEST_NAMESPACE: &str = "EvmSignerKms";
+
+    #[test]
+    fn to_json_signed_succeed() {
+        let input = SigningEvent::signed(
+            "alias/treasury",
+            TEST_ADDRESS.parse().unwrap(),
+            &[0xab; 32],
+            None,
+        );
+        let timestamp = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+
+        let right =
+            EmfSink::new(TEST_NAMESPACE).to_json(&input, Duration::from_millis(25), timestamp);
+
+        assert_eq!(1_700_000_000_000u64, right["_aws"]["Timestamp"]);
+        let directive = &right["_aws"]["CloudWatchMetrics"][0];
+        assert_eq!(TEST_NAMESPACE, directive["Namespace"]);
+        assert_eq!(json!([["KeyId"]]), directive["Dimensions"]);
+        assert_eq!("alias/treasury", right["KeyId"]);
+        assert_eq!("signed", right["Outcome"]);
+        assert_eq!(1, right["SignCount"]);
+        assert_eq!(25.0, right["SignLatency"]);
+        assert_eq!(0, right["SignErrors"]);
+        assert_eq!(0, right["SignRejections"]);
+    }
+
+    #[test]
+    fn to_json_failed_succeed() {
+        let input = SigningEvent::unsuccessful(
+            "alias/treasury",
+            TEST_ADDRESS.parse().unwrap(),
+            &[0xab; 32],
+            SigningOutcome::Failed,
+            &Error::new(ErrorKind::PermissionDenied, "Error signing message"),
+        );
+
+        let right = EmfSink::new(TEST_NAMESPACE).to_json(&input, Duration::ZERO, UNIX_EPOCH);
+
+        assert_eq!("failed", right["Outcome"]);
+        assert_eq!(1, right["SignErrors"]);
+        assert_eq!(0, right["SignRejections"]);
+    }
+
+    #[test]
+    fn slow_call_to_json_succeed() {
+        let right = EmfSink::new(TEST_NAMESPACE).slow_call_to_json(
+            "alias/treasury",
+            Some("eu-west-1"),
+            Duration::from_micros(212_500),
+            UNIX_EPOCH,
+        );
+
+        let directive = &right["_aws"]["CloudWatchMetrics"][0];
+        assert_eq!("SlowKmsCalls", directive["Metrics"][0]["Name"]);
+        assert_eq!("Milliseconds", directive["Metrics"][1]["Unit"]);
+        assert_eq!("alias/treasury", right["KeyId"]);
+        assert_eq!("eu-west-1", right["Region"]);
+        assert_eq!(1, right["SlowKmsCalls"]);
+        assert_eq!(212.5, right["KmsSignLatency"]);
+    }
+}
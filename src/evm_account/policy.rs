@@ -0,0 +1,267 @@
+use std::{
+    collections::HashSet,
+    error,
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+};
+
+use super::transaction::{check_data_length, Transaction};
+
+// Chain ID 0 is not assigned to any network and is not replay protected (see EIP-155).
+const INVALID_CHAIN_ID: u64 = 0;
+
+/// Rule of the signing policy violated by a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PolicyError {
+    /// Transaction is bound to chain ID `0`.
+    InvalidChainId,
+    /// Transaction is bound to a chain which is not permitted.
+    ChainIdNotPermitted(u64),
+    /// Transaction is bound to no chain, while only some chains are permitted.
+    NoChainId,
+    /// Transaction data is longer than the limit.
+    DataTooLong {
+        /// Length of the data in bytes.
+        length: usize,
+        /// Maximum length of the data in bytes.
+        max_data_length: usize,
+    },
+}
+
+impl PolicyError {
+    /// Returns the policy violation carried by the error returned from `check`, if any.
+    pub fn from_io_error(error: &Error) -> Option<&Self> {
+        error.get_ref()?.downcast_ref()
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            PolicyError::ChainIdNotPermitted(_) | PolicyError::NoChainId => {
+                ErrorKind::PermissionDenied
+            }
+            PolicyError::InvalidChainId | PolicyError::DataTooLong { .. } => {
+                ErrorKind::InvalidInput
+            }
+        }
+    }
+}
+
+impl Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyError::InvalidChainId => {
+                write!(f, "Chain ID {} is not valid", INVALID_CHAIN_ID)
+            }
+            PolicyError::ChainIdNotPermitted(chain_id) => {
+                write!(f, "Chain ID {} is not permitted", chain_id)
+            }
+            PolicyError::NoChainId => f.write_str("Transaction is not bound to any chain"),
+            PolicyError::DataTooLong {
+                length,
+                max_data_length,
+            } => write!(
+                f,
+                "Transaction data length {} exceeds the limit of {} bytes",
+                length, max_data_length
+            ),
+        }
+    }
+}
+
+impl error::Error for PolicyError {}
+
+impl From<PolicyError> for Error {
+    fn from(error: PolicyError) -> Self {
+        Error::new(error.kind(), error)
+    }
+}
+
+/// Set of rules verified against every transaction before it is signed.
+///
+/// The default policy only rejects transactions bound to chain ID `0`. Further restrictions are
+/// opted into with the `with_*` methods, e.g.:
+///
+/// ```rust
+/// use evm_signer_kms::evm_account::policy::SigningPolicy;
+///
+/// // Only allow signing transactions for Sepolia and Arbitrum Sepolia
+/// let policy = SigningPolicy::new().with_permitted_chain_ids([11155111, 421614]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SigningPolicy {
+    permitted_chain_ids: Option<HashSet<u64>>,
+    max_data_length: Option<usize>,
+}
+
+impl SigningPolicy {
+    /// Creates a new policy with no restrictions other than rejecting chain ID `0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts signing to transactions bound to one of the provided chain IDs.
+    ///
+    /// Once the set is configured, transactions which carry no chain ID (i.e. legacy
+    /// transactions) are rejected as well, as they could be replayed on any network.
+    pub fn with_permitted_chain_ids<I>(mut self, chain_ids: I) -> Self
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        self.permitted_chain_ids = Some(chain_ids.into_iter().collect());
+        self
+    }
+
+    /// Rejects transactions whose `data` is longer than `max_data_length` bytes.
+    ///
+    /// Useful for signing services exposed to user input which need to bound payload sizes.
+    pub fn with_max_data_length(mut self, max_data_length: usize) -> Self {
+        self.max_data_length = Some(max_data_length);
+        self
+    }
+
+    /// Returns the set of permitted chain IDs or `None` if any chain is permitted.
+    pub fn permitted_chain_ids(&self) -> Option<&HashSet<u64>> {
+        self.permitted_chain_ids.as_ref()
+    }
+
+    /// Verifies the transaction against the policy.
+    ///
+    /// Returns an error describing the first violated rule, which carries a `PolicyError` (see
+    /// `PolicyError::from_io_error`).
+    pub fn check<T: Transaction>(&self, tx: &T) -> Result<(), Error> {
+        self.check_fields(tx.chain_id(), tx.data())
+    }
+
+    /// Verifies the fields of a transaction of another library, e.g. alloy, against the policy.
+    pub(crate) fn check_fields(&self, chain_id: Option<u64>, data: &[u8]) -> Result<(), Error> {
+        self.check_chain_id(chain_id)?;
+        check_data_length(data.len(), self.max_data_length)
+    }
+
+    fn check_chain_id(&self, chain_id: Option<u64>) -> Result<(), Error> {
+        if chain_id == Some(INVALID_CHAIN_ID) {
+            return Err(PolicyError::InvalidChainId.into());
+        }
+
+        let permitted_chain_ids = match &self.permitted_chain_ids {
+            Some(permitted_chain_ids) => permitted_chain_ids,
+            None => return Ok(()),
+        };
+
+        match chain_id {
+            Some(chain_id) if permitted_chain_ids.contains(&chain_id) => Ok(()),
+            Some(chain_id) => Err(PolicyError::ChainIdNotPermitted(chain_id).into()),
+            None => Err(PolicyError::NoChainId.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::transaction::{
+        free_market_transaction::FreeMarketTransaction, legacy_transaction::LegacyTransaction,
+        Bytes, U256,
+    };
+
+    const TEST_CHAIN_ID_1: u64 = 1;
+    const TEST_CHAIN_ID_2: u64 = 11155111;
+
+    fn free_market_tx(chain_id: u64) -> FreeMarketTransaction {
+        FreeMarketTransaction {
+            gas_limit: 21_000,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
+            chain_id,
+            nonce: 0,
+            to: None,
+            value: U256::ZERO,
+            data: Bytes::default(),
+            access_list: vec![],
+        }
+    }
+
+    fn legacy_tx() -> LegacyTransaction {
+        LegacyTransaction {
+            nonce: 0,
+            gas_price: U256::new(100_000_000_000),
+            gas_limit: 21_000,
+            to: None,
+            value: U256::ZERO,
+            data: Bytes::default(),
+        }
+    }
+
+    #[test]
+    fn default_policy_any_chain_succeed() {
+        let policy = SigningPolicy::new();
+
+        assert!(policy.check(&free_market_tx(TEST_CHAIN_ID_1)).is_ok());
+        assert!(policy.check(&legacy_tx()).is_ok());
+    }
+
+    #[test]
+    fn default_policy_chain_id_zero_fail() {
+        let policy = SigningPolicy::new();
+
+        let error = policy.check(&free_market_tx(0)).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn permitted_chain_id_succeed() {
+        let policy = SigningPolicy::new().with_permitted_chain_ids([TEST_CHAIN_ID_2]);
+
+        assert!(policy.check(&free_market_tx(TEST_CHAIN_ID_2)).is_ok());
+    }
+
+    #[test]
+    fn not_permitted_chain_id_fail() {
+        let policy = SigningPolicy::new().with_permitted_chain_ids([TEST_CHAIN_ID_2]);
+
+        let error = policy.check(&free_market_tx(TEST_CHAIN_ID_1)).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(
+            Some(&PolicyError::ChainIdNotPermitted(TEST_CHAIN_ID_1)),
+            PolicyError::from_io_error(&error)
+        );
+    }
+
+    #[test]
+    fn max_data_length_succeed() {
+        let policy = SigningPolicy::new().with_max_data_length(2);
+        let mut tx = free_market_tx(TEST_CHAIN_ID_1);
+        tx.data = Bytes(vec![0xab, 0xcd]);
+
+        assert!(policy.check(&tx).is_ok());
+    }
+
+    #[test]
+    fn max_data_length_exceeded_fail() {
+        let policy = SigningPolicy::new().with_max_data_length(1);
+        let mut tx = free_market_tx(TEST_CHAIN_ID_1);
+        tx.data = Bytes(vec![0xab, 0xcd]);
+
+        let error = policy.check(&tx).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert_eq!(
+            Some(&PolicyError::DataTooLong {
+                length: 2,
+                max_data_length: 1
+            }),
+            PolicyError::from_io_error(&error)
+        );
+    }
+
+    #[test]
+    fn permitted_chain_ids_legacy_tx_fail() {
+        let policy = SigningPolicy::new().with_permitted_chain_ids([TEST_CHAIN_ID_1]);
+
+        let error = policy.check(&legacy_tx()).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::PermissionDenied);
+    }
+}
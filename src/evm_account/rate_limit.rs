@@ -0,0 +1,174 @@
+use std::{
+    num::NonZeroU32,
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use super::runtime;
+
+/// Token-bucket limit of the rate of KMS sign calls, e.g.:
+///
+/// ```rust
+/// use std::{num::NonZeroU32, sync::Arc};
+/// use evm_signer_kms::evm_account::rate_limit::RateLimiter;
+///
+/// let rate_limiter = Arc::new(
+///     RateLimiter::new(NonZeroU32::new(50).unwrap()).with_burst(NonZeroU32::new(100).unwrap()),
+/// );
+/// ```
+///
+/// The bucket holds up to `burst` tokens and is refilled at `requests_per_second`. Each sign call
+/// takes a token, waiting for it if the bucket is empty, so that the calls never exceed the rate
+/// over time. Calls waiting for tokens are served in the order they arrived.
+///
+/// KMS throttles requests per account and region, so the limiter should be shared by all the
+/// accounts of a key, and its rate kept below the quota left to other tenants.
+#[derive(Debug)]
+pub struct RateLimiter {
+    rate: f64,
+    burst: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    // Negative if calls are waiting for tokens
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter of the rate of sign calls, with a full bucket of as many tokens as
+    /// requests per second.
+    pub fn new(requests_per_second: NonZeroU32) -> Self {
+        let rate = f64::from(requests_per_second.get());
+
+        Self {
+            rate,
+            burst: rate,
+            bucket: Mutex::new(Bucket {
+                tokens: rate,
+                updated: Instant::now(),
+            }),
+        }
+    }
+
+    /// Sets the number of sign calls which may be sent at once after a quiet period.
+    pub fn with_burst(mut self, burst: NonZeroU32) -> Self {
+        self.burst = f64::from(burst.get());
+        let bucket = self
+            .bucket
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        bucket.tokens = self.burst;
+        self
+    }
+
+    /// Sustained rate of sign calls.
+    pub fn requests_per_second(&self) -> f64 {
+        self.rate
+    }
+
+    /// Maximum number of sign calls sent at once.
+    pub fn burst(&self) -> f64 {
+        self.burst
+    }
+
+    /// Takes a token from the bucket, waiting until one is available.
+    pub async fn acquire(&self) {
+        wait_until(self.reserve(Instant::now())).await;
+    }
+
+    // Instant from which the call holding the taken token may be sent. Taking the token apart from
+    // waiting for it lets callers, e.g. batches, reserve tokens in the order of their input.
+    pub(super) fn reserve(&self, now: Instant) -> Instant {
+        // The bucket is only updated with the lock held, so it is consistent after a panic
+        let mut bucket = self.bucket.lock().unwrap_or_else(PoisonError::into_inner);
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.burst) - 1.0;
+        bucket.updated = bucket.updated.max(now);
+
+        if bucket.tokens >= 0.0 {
+            now
+        } else {
+            now + Duration::from_secs_f64(-bucket.tokens / self.rate)
+        }
+    }
+}
+
+// Waits until the instant reserved for a call, on the runtime selected with the `rt-tokio` or
+// `rt-async-std` feature
+pub(super) async fn wait_until(instant: Instant) {
+    runtime::sleep(instant.saturating_duration_since(Instant::now())).await;
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn rate_limiter(requests_per_second: u32, burst: u32) -> RateLimiter {
+        RateLimiter::new(NonZeroU32::new(requests_per_second).unwrap())
+            .with_burst(NonZeroU32::new(burst).unwrap())
+    }
+
+    #[test]
+    fn with_burst_succeed() {
+        let right = rate_limiter(10, 20);
+
+        assert_eq!(10.0, right.requests_per_second());
+        assert_eq!(20.0, right.burst());
+    }
+
+    #[test]
+    fn reserve_burst_succeed() {
+        let input = rate_limiter(10, 3);
+        let now = Instant::now();
+
+        let right: Vec<Instant> = (0..4).map(|_| input.reserve(now)).collect();
+
+        assert_eq!(vec![now, now, now], right[..3]);
+        assert_eq!(Duration::from_millis(100), right[3] - now);
+    }
+
+    #[test]
+    fn reserve_queue_succeed() {
+        let input = rate_limiter(10, 1);
+        let now = Instant::now();
+
+        let left = input.reserve(now);
+        let right = (input.reserve(now), input.reserve(now));
+
+        assert_eq!(now, left);
+        assert_eq!(Duration::from_millis(100), right.0 - now);
+        assert_eq!(Duration::from_millis(200), right.1 - now);
+    }
+
+    #[test]
+    fn reserve_refill_succeed() {
+        let input = rate_limiter(10, 2);
+        let now = Instant::now();
+        input.reserve(now);
+        input.reserve(now);
+
+        let left = input.reserve(now + Duration::from_millis(100));
+        let right = input.reserve(now + Duration::from_secs(10));
+
+        assert_eq!(now + Duration::from_millis(100), left);
+        assert_eq!(now + Duration::from_secs(10), right);
+    }
+
+    #[test]
+    fn acquire_succeed() {
+        let input = rate_limiter(100, 1);
+
+        let right = tokio_test::block_on(async {
+            let started = Instant::now();
+            for _ in 0..3 {
+                input.acquire().await;
+            }
+            started.elapsed()
+        });
+
+        assert!(right >= Duration::from_millis(20));
+    }
+}
@@ -0,0 +1,331 @@
+use std::{
+    error,
+    fmt::{self, Display},
+    future::Future,
+    io::{Error, ErrorKind},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{
+    hash::Keccak256Digest,
+    transaction::{hex_quantity, to_rpc_json, AccountAddress, Bytes, Transaction, U256},
+    KECCAK_256_LENGTH,
+};
+
+mod http;
+#[cfg(feature = "ws")]
+mod ws;
+
+/// Account balance lookup with `eth_getBalance` and checks of transaction costs against it.
+pub mod balance;
+/// Submission of signed transactions with `eth_sendRawTransaction`.
+pub mod broadcast;
+/// Chain ID lookup with `eth_chainId` and verification of transactions against it.
+pub mod chain;
+/// Resolution of ENS names to recipient addresses.
+pub mod ens;
+/// Fee suggestions based on `eth_feeHistory` (EIP-1559) and `eth_gasPrice` (legacy).
+pub mod fees;
+/// Gas limit estimation with `eth_estimateGas`.
+pub mod gas;
+/// Account nonce lookup with `eth_getTransactionCount`.
+pub mod nonce;
+/// Transaction receipt lookup and confirmation polling.
+pub mod receipt;
+/// Submission of bundles and private transactions to MEV-protected relays, e.g. Flashbots.
+#[cfg(feature = "aws")]
+pub mod relay;
+/// Simulation of transactions with `eth_call` before they are signed.
+pub mod simulation;
+/// Tracking of broadcast transactions until they are included or replaced.
+pub mod tracker;
+
+#[cfg(feature = "notifications")]
+pub(crate) use http::http_client;
+pub use http::HttpProvider;
+#[cfg(feature = "ws")]
+pub use ws::WsProvider;
+
+const JSON_RPC_VERSION: &str = "2.0";
+const HEX_PREFIX: &str = "0x";
+const HEX_RADIX: u32 = 16;
+// Parameters of methods which take none.
+const NO_PARAMS: [(); 0] = [];
+
+/// Transport of JSON-RPC requests to an Ethereum node, e.g. `HttpProvider` or `WsProvider`.
+///
+/// All helpers of this module are built on top of it, so it can be implemented over an existing
+/// connection pool or as a test double:
+///
+/// ```rust
+/// use std::{future::Future, io::Error};
+/// use evm_signer_kms::evm_account::rpc::{chain::chain_id, Provider};
+/// use serde_json::{json, Value};
+///
+/// struct StaticProvider;
+///
+/// impl Provider for StaticProvider {
+///     fn request_value(
+///         &self,
+///         _method: &str,
+///         _params: Value,
+///     ) -> impl Future<Output = Result<Value, Error>> + Send {
+///         async { Ok(json!("0xaa36a7")) }
+///     }
+/// }
+///
+/// # tokio_test::block_on(async {
+/// assert_eq!(11155111, chain_id(&StaticProvider).await.unwrap());
+/// # })
+/// ```
+pub trait Provider: Sync {
+    /// Calls the JSON-RPC method with the serialized parameters and returns its raw result.
+    ///
+    /// Errors returned by the node have to carry an `RpcError` (see `RpcError::from_io_error`),
+    /// as the helpers rely on it to classify them.
+    fn request_value(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> impl Future<Output = Result<Value, Error>> + Send;
+
+    /// Calls the JSON-RPC method and deserializes its result.
+    fn request<P, R>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> impl Future<Output = Result<R, Error>> + Send
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let params = serde_json::to_value(params)
+            .map_err(|error| Error::new(ErrorKind::InvalidInput, error));
+
+        async move {
+            let result = self.request_value(method, params?).await?;
+
+            serde_json::from_value(result).map_err(|error| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Invalid JSON-RPC result: {}", error),
+                )
+            })
+        }
+    }
+}
+
+/// Error object returned by the node in a JSON-RPC response.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct RpcError {
+    /// Error code, e.g. `-32000` for most transaction validation errors.
+    pub code: i64,
+    /// Error message, e.g. `nonce too low`.
+    pub message: String,
+    /// Additional error data, e.g. the revert data of failed calls.
+    #[serde(default)]
+    pub data: Option<Value>,
+}
+
+impl RpcError {
+    /// Returns the JSON-RPC error carried by the error returned from `Provider::request`, if any.
+    pub fn from_io_error(error: &Error) -> Option<&Self> {
+        error.get_ref()?.downcast_ref()
+    }
+}
+
+impl Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "JSON-RPC error {}: {}", self.code, self.message)
+    }
+}
+
+impl error::Error for RpcError {}
+
+#[derive(Deserialize)]
+struct Response {
+    #[serde(default)]
+    result: Value,
+    error: Option<RpcError>,
+}
+
+fn build_request(id: u64, method: &str, params: Value) -> Value {
+    json!({
+        "jsonrpc": JSON_RPC_VERSION,
+        "id": id,
+        "method": method,
+        "params": params,
+    })
+}
+
+fn parse_response<R>(response: Value) -> Result<R, Error>
+where
+    R: DeserializeOwned,
+{
+    let response: Response = serde_json::from_value(response).map_err(|error| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Invalid JSON-RPC response: {}", error),
+        )
+    })?;
+
+    if let Some(error) = response.error {
+        return Err(Error::other(error));
+    }
+
+    serde_json::from_value(response.result).map_err(|error| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Invalid JSON-RPC result: {}", error),
+        )
+    })
+}
+
+// Builds the transaction call object of `eth_call` and `eth_estimateGas` sent from the address.
+fn call_request<T>(tx: &T, from: &AccountAddress) -> Result<Value, Error>
+where
+    T: Transaction,
+{
+    let mut request = to_rpc_json(tx)?;
+    request.insert("from".to_string(), from.to_string().into());
+    // Older nodes only accept `data` rather than `input`
+    if let Some(input) = request.get("input").cloned() {
+        request.insert("data".to_string(), input);
+    }
+
+    Ok(Value::Object(request))
+}
+
+// Converts a hex encoded quantity returned by the node, e.g. `0x1a`.
+fn decode_quantity<T>(quantity: &str) -> Result<T, Error>
+where
+    T: TryFrom<U256>,
+{
+    quantity
+        .strip_prefix(HEX_PREFIX)
+        .and_then(|digits| U256::from_str_radix(digits, HEX_RADIX).ok())
+        .and_then(|quantity| T::try_from(quantity).ok())
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "JSON-RPC endpoint returned an invalid quantity: {}",
+                    quantity
+                ),
+            )
+        })
+}
+
+// Converts a hex encoded transaction or block hash returned by the node.
+fn decode_hash(hash: Bytes) -> Result<Keccak256Digest, Error> {
+    <[u8; KECCAK_256_LENGTH]>::try_from(hash.0).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidData,
+            "JSON-RPC endpoint returned an invalid hash",
+        )
+    })
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::transaction::legacy_transaction::LegacyTransaction;
+
+    #[test]
+    fn parse_response_result_succeed() {
+        let input = json!({"jsonrpc": "2.0", "id": 1, "result": "0xaa36a7"});
+
+        let right: String = parse_response(input).unwrap();
+
+        assert_eq!("0xaa36a7", right);
+    }
+
+    #[test]
+    fn parse_response_null_result_succeed() {
+        let input = json!({"jsonrpc": "2.0", "id": 1, "result": null});
+
+        let right: Option<String> = parse_response(input).unwrap();
+
+        assert_eq!(None, right);
+    }
+
+    #[test]
+    fn parse_response_error_fail() {
+        let left = RpcError {
+            code: -32000,
+            message: "nonce too low".to_string(),
+            data: None,
+        };
+        let input = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": {"code": -32000, "message": "nonce too low"}
+        });
+
+        let error = parse_response::<String>(input).unwrap_err();
+
+        assert_eq!(Some(&left), RpcError::from_io_error(&error));
+    }
+
+    #[test]
+    fn parse_response_invalid_result_fail() {
+        let input = json!({"jsonrpc": "2.0", "id": 1, "result": 1});
+
+        let error = parse_response::<String>(input).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert!(RpcError::from_io_error(&error).is_none());
+    }
+
+    #[test]
+    fn call_request_succeed() {
+        let from: AccountAddress = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573"
+            .parse()
+            .unwrap();
+        let input = LegacyTransaction {
+            nonce: 5,
+            gas_price: U256::new(100_000_000_000),
+            gas_limit: 21_000,
+            to: None,
+            value: U256::new(10_000_000_000_000_000),
+            data: Bytes(vec![0xab, 0xcd]),
+        };
+
+        let right = call_request(&input, &from).unwrap();
+
+        assert_eq!(right["from"], from.to_string());
+        assert_eq!(right["gas"], "0x5208");
+        assert_eq!(right["gasPrice"], "0x174876e800");
+        assert_eq!(right["value"], "0x2386f26fc10000");
+        assert_eq!(right["input"], "0xabcd");
+        assert_eq!(right["data"], "0xabcd");
+    }
+
+    #[test]
+    fn decode_quantity_succeed() {
+        let right: u128 = decode_quantity("0x1a").unwrap();
+
+        assert_eq!(26, right);
+    }
+
+    #[test]
+    fn decode_quantity_out_of_range_fail() {
+        let input = format!("0x1{}", "0".repeat(16));
+
+        assert!(decode_quantity::<u64>(&input).is_err());
+    }
+
+    #[test]
+    fn decode_quantity_missing_prefix_fail() {
+        assert!(decode_quantity::<u64>("26").is_err());
+    }
+
+    #[test]
+    fn decode_hash_invalid_length_fail() {
+        let input = Bytes(vec![0xab; KECCAK_256_LENGTH - 1]);
+
+        assert!(decode_hash(input).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    error,
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+};
+
+use super::{decode_quantity, Provider};
+use crate::evm_account::{
+    transaction::{AccountAddress, Transaction, U256},
+    units::{format_units, Unit},
+};
+
+// Balance available to transactions pending in the mempool.
+const PENDING_BLOCK_TAG: &str = "pending";
+
+/// Maximum cost of a transaction exceeding the balance of the sending account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InsufficientFunds {
+    /// Maximum cost of the transaction in wei (see `Transaction::max_cost`).
+    pub required: U256,
+    /// Balance of the account in wei.
+    pub available: U256,
+}
+
+impl InsufficientFunds {
+    /// Amount of wei missing to cover the maximum cost of the transaction.
+    pub fn shortfall(&self) -> U256 {
+        self.required.saturating_sub(self.available)
+    }
+
+    /// Returns the shortfall carried by the error returned from `check_balance`, if any.
+    pub fn from_io_error(error: &Error) -> Option<&Self> {
+        error.get_ref()?.downcast_ref()
+    }
+}
+
+impl Display for InsufficientFunds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Insufficient funds: transaction may cost {} ETH, but the balance is {} ETH ({} ETH short)",
+            format_units(self.required, Unit::Ether),
+            format_units(self.available, Unit::Ether),
+            format_units(self.shortfall(), Unit::Ether),
+        )
+    }
+}
+
+impl error::Error for InsufficientFunds {}
+
+/// Returns the balance of the account in wei, including transactions pending in the mempool
+/// (`eth_getBalance` at the `pending` block).
+pub async fn get_balance(
+    address: &AccountAddress,
+    provider: &impl Provider,
+) -> Result<U256, Error> {
+    let balance: String = provider
+        .request("eth_getBalance", (address.to_string(), PENDING_BLOCK_TAG))
+        .await?;
+
+    decode_quantity(&balance)
+}
+
+/// Verifies that the balance of the sending account covers the maximum cost of the transaction,
+/// e.g. before it is signed.
+///
+/// Fails with an error carrying `InsufficientFunds` otherwise.
+pub async fn check_balance<T>(
+    tx: &T,
+    address: &AccountAddress,
+    provider: &impl Provider,
+) -> Result<(), Error>
+where
+    T: Transaction,
+{
+    let required = tx.max_cost()?;
+    let available = get_balance(address, provider).await?;
+
+    verify(required, available)
+}
+
+fn verify(required: U256, available: U256) -> Result<(), Error> {
+    if required > available {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            InsufficientFunds {
+                required,
+                available,
+            },
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn verify_succeed() {
+        let input = U256::new(21_000_000_000_000);
+
+        assert!(verify(input, input).is_ok());
+    }
+
+    #[test]
+    fn verify_insufficient_funds_fail() {
+        let left = InsufficientFunds {
+            required: U256::new(2_500_000_000_000_000_000),
+            available: U256::new(1_000_000_000_000_000_000),
+        };
+
+        let error = verify(left.required, left.available).unwrap_err();
+        let right = InsufficientFunds::from_io_error(&error).unwrap();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert_eq!(&left, right);
+        assert_eq!(U256::new(1_500_000_000_000_000_000), right.shortfall());
+    }
+
+    #[test]
+    fn insufficient_funds_display_succeed() {
+        let input = InsufficientFunds {
+            required: U256::new(2_500_000_000_000_000_000),
+            available: U256::new(1_000_000_000_000_000_000),
+        };
+
+        assert_eq!(
+            "Insufficient funds: transaction may cost 2.5 ETH, but the balance is 1 ETH (1.5 ETH short)",
+            input.to_string()
+        );
+    }
+}
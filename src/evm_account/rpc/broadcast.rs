@@ -0,0 +1,251 @@
+use std::{
+    error,
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+};
+
+use super::{decode_hash, Provider, RpcError};
+use crate::evm_account::{
+    hash::Keccak256Digest,
+    transaction::{Bytes, SignedTransaction, Transaction},
+};
+
+/// Reason for which the node rejected a transaction, classified from the JSON-RPC error message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BroadcastError {
+    /// Transaction with the same or higher nonce has already been included.
+    NonceTooLow,
+    /// Nonce is ahead of the next nonce of the account by more than the node accepts.
+    NonceTooHigh,
+    /// Gas price or fees are below the minimum accepted by the node.
+    Underpriced,
+    /// Fees are not sufficiently higher than those of the pending transaction with the same nonce.
+    ReplacementUnderpriced,
+    /// Account balance does not cover the maximum transaction cost.
+    InsufficientFunds,
+    /// Gas limit is below the intrinsic gas of the transaction.
+    IntrinsicGasTooLow,
+    /// Transaction is already in the mempool of the node.
+    AlreadyKnown,
+    /// Any other reason reported by the node.
+    Rejected(RpcError),
+}
+
+impl BroadcastError {
+    /// Returns the broadcast error carried by the error returned from `broadcast`, if any.
+    pub fn from_io_error(error: &Error) -> Option<&Self> {
+        error.get_ref()?.downcast_ref()
+    }
+
+    fn kind(&self) -> ErrorKind {
+        match self {
+            BroadcastError::AlreadyKnown => ErrorKind::AlreadyExists,
+            BroadcastError::Rejected(_) => ErrorKind::Other,
+            _ => ErrorKind::InvalidInput,
+        }
+    }
+}
+
+impl From<RpcError> for BroadcastError {
+    fn from(error: RpcError) -> Self {
+        let message = error.message.to_lowercase();
+        // Messages differ between node implementations, e.g. `nonce too low` (geth) and
+        // `OldNonce` (nethermind)
+        let matches = |patterns: &[&str]| patterns.iter().any(|pattern| message.contains(pattern));
+
+        if matches(&["nonce too low", "oldnonce"]) {
+            BroadcastError::NonceTooLow
+        } else if matches(&["nonce too high", "nonce gap"]) {
+            BroadcastError::NonceTooHigh
+        } else if matches(&[
+            "replacement transaction underpriced",
+            "replacement fee too low",
+        ]) {
+            BroadcastError::ReplacementUnderpriced
+        } else if matches(&[
+            "underpriced",
+            "feetoolow",
+            "fee cap less than block base fee",
+        ]) {
+            BroadcastError::Underpriced
+        } else if matches(&["insufficient funds", "insufficientfunds"]) {
+            BroadcastError::InsufficientFunds
+        } else if matches(&["intrinsic gas too low"]) {
+            BroadcastError::IntrinsicGasTooLow
+        } else if matches(&["already known", "known transaction", "alreadyknown"]) {
+            BroadcastError::AlreadyKnown
+        } else {
+            BroadcastError::Rejected(error)
+        }
+    }
+}
+
+impl Display for BroadcastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BroadcastError::NonceTooLow => f.write_str("Nonce too low"),
+            BroadcastError::NonceTooHigh => f.write_str("Nonce too high"),
+            BroadcastError::Underpriced => f.write_str("Transaction underpriced"),
+            BroadcastError::ReplacementUnderpriced => {
+                f.write_str("Replacement transaction underpriced")
+            }
+            BroadcastError::InsufficientFunds => f.write_str("Insufficient funds"),
+            BroadcastError::IntrinsicGasTooLow => f.write_str("Intrinsic gas too low"),
+            BroadcastError::AlreadyKnown => f.write_str("Transaction already known"),
+            BroadcastError::Rejected(error) => write!(f, "Transaction rejected: {}", error),
+        }
+    }
+}
+
+impl error::Error for BroadcastError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            BroadcastError::Rejected(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Submits the signed transaction with `eth_sendRawTransaction` and returns its hash.
+///
+/// Rejections by the node carry a `BroadcastError` (see `BroadcastError::from_io_error`), e.g.:
+///
+/// ```rust,no_run
+/// use evm_signer_kms::evm_account::{
+///     rpc::{broadcast::{broadcast, BroadcastError}, Provider},
+///     transaction::{free_market_transaction::FreeMarketTransaction, SignedTransaction},
+/// };
+///
+/// async fn submit(signed_tx: &SignedTransaction<FreeMarketTransaction>, provider: &impl Provider) {
+///     match broadcast(signed_tx, provider).await {
+///         Ok(hash) => println!("Broadcast 0x{}", hex::encode(hash)),
+///         Err(error) => match BroadcastError::from_io_error(&error) {
+///             Some(BroadcastError::NonceTooLow) => println!("Nonce already used"),
+///             _ => println!("Broadcast failed: {}", error),
+///         },
+///     }
+/// }
+/// ```
+pub async fn broadcast<T>(
+    signed_tx: &SignedTransaction<T>,
+    provider: &impl Provider,
+) -> Result<Keccak256Digest, Error>
+where
+    T: Transaction,
+{
+    broadcast_raw(&signed_tx.encode(), provider).await
+}
+
+/// Submits the raw signed transaction encoding with `eth_sendRawTransaction` and returns its hash,
+/// e.g. for transactions signed elsewhere. Rejections are classified as by `broadcast`.
+pub async fn broadcast_raw(
+    raw_tx: &[u8],
+    provider: &impl Provider,
+) -> Result<Keccak256Digest, Error> {
+    let raw_tx = Bytes::from(raw_tx).to_string();
+
+    let hash = provider
+        .request("eth_sendRawTransaction", [raw_tx])
+        .await
+        .map_err(classify_error)?;
+
+    decode_hash(hash)
+}
+
+// Replaces JSON-RPC errors with the classified broadcast errors.
+fn classify_error(error: Error) -> Error {
+    match RpcError::from_io_error(&error) {
+        Some(rpc_error) => {
+            let error = BroadcastError::from(rpc_error.clone());
+            Error::new(error.kind(), error)
+        }
+        None => error,
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn rpc_error(message: &str) -> RpcError {
+        RpcError {
+            code: -32000,
+            message: message.to_string(),
+            data: None,
+        }
+    }
+
+    #[test]
+    fn classify_nonce_too_low_succeed() {
+        let input = rpc_error("nonce too low: next nonce 8, tx nonce 7");
+
+        assert_eq!(BroadcastError::NonceTooLow, BroadcastError::from(input));
+    }
+
+    #[test]
+    fn classify_replacement_underpriced_succeed() {
+        let input = rpc_error("replacement transaction underpriced");
+
+        assert_eq!(
+            BroadcastError::ReplacementUnderpriced,
+            BroadcastError::from(input)
+        );
+    }
+
+    #[test]
+    fn classify_underpriced_succeed() {
+        let input = rpc_error("transaction underpriced: tip needed 1, tip permitted 0");
+
+        assert_eq!(BroadcastError::Underpriced, BroadcastError::from(input));
+    }
+
+    #[test]
+    fn classify_insufficient_funds_succeed() {
+        let input = rpc_error("insufficient funds for gas * price + value");
+
+        assert_eq!(
+            BroadcastError::InsufficientFunds,
+            BroadcastError::from(input)
+        );
+    }
+
+    #[test]
+    fn classify_already_known_succeed() {
+        let input = rpc_error("AlreadyKnown");
+
+        assert_eq!(BroadcastError::AlreadyKnown, BroadcastError::from(input));
+    }
+
+    #[test]
+    fn classify_unknown_error_succeed() {
+        let input = rpc_error("invalid sender");
+
+        assert_eq!(
+            BroadcastError::Rejected(input.clone()),
+            BroadcastError::from(input)
+        );
+    }
+
+    #[test]
+    fn classify_error_succeed() {
+        let input = Error::other(rpc_error("already known"));
+
+        let right = classify_error(input);
+
+        assert_eq!(right.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(
+            Some(&BroadcastError::AlreadyKnown),
+            BroadcastError::from_io_error(&right)
+        );
+    }
+
+    #[test]
+    fn classify_transport_error_succeed() {
+        let input = Error::new(ErrorKind::TimedOut, "timed out");
+
+        let right = classify_error(input);
+
+        assert_eq!(right.kind(), ErrorKind::TimedOut);
+        assert!(BroadcastError::from_io_error(&right).is_none());
+    }
+}
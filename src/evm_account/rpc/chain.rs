@@ -0,0 +1,56 @@
+use std::io::{Error, ErrorKind};
+
+use super::{decode_quantity, Provider, NO_PARAMS};
+use crate::evm_account::transaction::Transaction;
+
+/// Returns the chain ID of the network the endpoint is connected to (`eth_chainId`).
+pub async fn chain_id(provider: &impl Provider) -> Result<u64, Error> {
+    let chain_id: String = provider.request("eth_chainId", NO_PARAMS).await?;
+
+    decode_quantity(&chain_id)
+}
+
+/// Verifies that the transaction is bound to the chain of the endpoint, e.g. before it is signed
+/// and broadcast, to prevent sending it to the wrong network. Transactions which carry no chain ID
+/// (i.e. legacy transactions) are not checked.
+pub async fn check_chain_id<T>(tx: &T, provider: &impl Provider) -> Result<(), Error>
+where
+    T: Transaction,
+{
+    match tx.chain_id() {
+        Some(tx_chain_id) => verify(tx_chain_id, chain_id(provider).await?),
+        None => Ok(()),
+    }
+}
+
+fn verify(tx_chain_id: u64, endpoint_chain_id: u64) -> Result<(), Error> {
+    if tx_chain_id != endpoint_chain_id {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "Transaction chain ID {} does not match chain ID {} of the endpoint",
+                tx_chain_id, endpoint_chain_id
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn verify_succeed() {
+        assert!(verify(11155111, 11155111).is_ok());
+    }
+
+    #[test]
+    fn verify_mismatch_fail() {
+        let error = verify(1, 11155111).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(error.to_string().contains("11155111"));
+    }
+}
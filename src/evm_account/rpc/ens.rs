@@ -0,0 +1,341 @@
+use std::{
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+};
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use super::{Provider, HEX_PREFIX};
+use crate::evm_account::{
+    hash::{keccak256, Keccak256Digest},
+    transaction::{AccountAddress, Bytes, Transaction},
+    KECCAK_256_LENGTH,
+};
+
+// ENS registry deployed at the same address on mainnet and the official testnets.
+const ENS_REGISTRY: AccountAddress = AccountAddress([
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x2e, 0x07, 0x4e, 0xc6, 0x9a, 0x0d, 0xfb, 0x29, 0x97, 0xba,
+    0x6c, 0x7d, 0x2e, 0x1e,
+]);
+// Selectors of `resolver(bytes32)` of the registry and `addr(bytes32)` of resolvers.
+const RESOLVER_SELECTOR: [u8; 4] = [0x01, 0x78, 0xb8, 0xbf];
+const ADDR_SELECTOR: [u8; 4] = [0x3b, 0x3b, 0x57, 0xde];
+const LATEST_BLOCK_TAG: &str = "latest";
+const LABEL_SEPARATOR: char = '.';
+const TO_FIELD: &str = "to";
+const WORD_LENGTH: usize = 32;
+// Addresses are right-aligned in ABI encoded words.
+const ADDRESS_OFFSET: usize = 12;
+
+/// ENS name resolved to an address, e.g. to be confirmed before the transaction is signed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Resolution {
+    /// Resolved ENS name.
+    pub name: String,
+    /// Address the name resolved to.
+    pub address: AccountAddress,
+}
+
+impl Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} resolved to {}", self.name, self.address)
+    }
+}
+
+/// Transaction deserialized with `from_json_with_ens_name`, whose recipient may be an ENS name yet
+/// to be resolved.
+///
+/// The transaction is only handed out by `resolve_recipient`, as it has no recipient until the name
+/// is resolved and would otherwise be signed as a contract creation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use = "the transaction has to be resolved with `resolve_recipient`"]
+pub struct UnresolvedTransaction<T> {
+    tx: T,
+    name: Option<String>,
+}
+
+impl<T> UnresolvedTransaction<T> {
+    /// ENS name of the recipient, if it is not an address.
+    pub fn ens_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+/// Computes the ENS `namehash` of the name (see
+/// [`ENSIP-1`](https://docs.ens.domains/ensip/1)).
+///
+/// Labels are only lowercased rather than fully normalized, so names with non-ASCII characters
+/// have to be normalized by the caller.
+pub fn namehash(name: &str) -> Keccak256Digest {
+    let mut node = [0u8; KECCAK_256_LENGTH];
+    if name.is_empty() {
+        return node;
+    }
+
+    for label in name.rsplit(LABEL_SEPARATOR) {
+        let mut preimage = [0u8; 2 * KECCAK_256_LENGTH];
+        preimage[..KECCAK_256_LENGTH].copy_from_slice(&node);
+        preimage[KECCAK_256_LENGTH..].copy_from_slice(&keccak256(label.to_lowercase()));
+        node = keccak256(preimage);
+    }
+
+    node
+}
+
+/// Returns `true` if the recipient looks like an ENS name (e.g. `vitalik.eth`) rather than a hex
+/// address.
+pub fn is_ens_name(recipient: &str) -> bool {
+    !recipient.starts_with(HEX_PREFIX)
+        && recipient
+            .split(LABEL_SEPARATOR)
+            .all(|label| !label.is_empty())
+        && recipient.contains(LABEL_SEPARATOR)
+}
+
+/// Resolves the ENS name to an address through the ENS registry.
+///
+/// Fails with `ErrorKind::NotFound` if the name has no resolver or does not resolve to an address.
+pub async fn resolve_name(name: &str, provider: &impl Provider) -> Result<AccountAddress, Error> {
+    let node = namehash(name);
+
+    let resolver = call_address(&ENS_REGISTRY, RESOLVER_SELECTOR, &node, provider).await?;
+    if resolver == AccountAddress::default() {
+        return Err(Error::new(
+            ErrorKind::NotFound,
+            format!("ENS name `{}` has no resolver", name),
+        ));
+    }
+
+    let address = call_address(&resolver, ADDR_SELECTOR, &node, provider).await?;
+    if address == AccountAddress::default() {
+        return Err(Error::new(
+            ErrorKind::NotFound,
+            format!("ENS name `{}` does not resolve to an address", name),
+        ));
+    }
+
+    Ok(address)
+}
+
+/// Deserializes the transaction from JSON, tolerating an ENS name in place of the `to` address.
+///
+/// The transaction has to be resolved with `resolve_recipient` before it can be signed.
+pub fn from_json_with_ens_name<T>(json: &str) -> Result<UnresolvedTransaction<T>, Error>
+where
+    T: DeserializeOwned,
+{
+    let mut value: Value =
+        serde_json::from_str(json).map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+
+    let name = match value.get_mut(TO_FIELD) {
+        Some(to) if to.as_str().is_some_and(is_ens_name) => match to.take() {
+            Value::String(name) => Some(name),
+            _ => None,
+        },
+        _ => None,
+    };
+    let tx =
+        serde_json::from_value(value).map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+
+    Ok(UnresolvedTransaction { tx, name })
+}
+
+/// Resolves the ENS name of the recipient, if any, and sets it as the recipient of the
+/// transaction. Returns the transaction with the resolution for confirmation, which is `None` if
+/// the recipient already was an address.
+pub async fn resolve_recipient<T>(
+    tx: UnresolvedTransaction<T>,
+    provider: &impl Provider,
+) -> Result<(T, Option<Resolution>), Error>
+where
+    T: Transaction,
+{
+    let UnresolvedTransaction { mut tx, name } = tx;
+    let Some(name) = name else {
+        return Ok((tx, None));
+    };
+
+    let address = resolve_name(&name, provider).await?;
+    tx.set_to(Some(address));
+
+    Ok((tx, Some(Resolution { name, address })))
+}
+
+async fn call_address(
+    contract: &AccountAddress,
+    selector: [u8; 4],
+    node: &Keccak256Digest,
+    provider: &impl Provider,
+) -> Result<AccountAddress, Error> {
+    let mut data = selector.to_vec();
+    data.extend_from_slice(node);
+    let request = json!({
+        "to": contract.to_string(),
+        "data": Bytes(data).to_string(),
+    });
+
+    let output: Bytes = provider
+        .request("eth_call", (request, LATEST_BLOCK_TAG))
+        .await?;
+
+    decode_address(&output)
+}
+
+fn decode_address(output: &[u8]) -> Result<AccountAddress, Error> {
+    // Calls to addresses with no code succeed with empty output
+    if output.len() < WORD_LENGTH {
+        return Ok(AccountAddress::default());
+    }
+
+    AccountAddress::try_from(&output[ADDRESS_OFFSET..WORD_LENGTH])
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::transaction::free_market_transaction::FreeMarketTransaction;
+    use std::future::{self, Future};
+
+    struct FailingProvider;
+
+    impl Provider for FailingProvider {
+        fn request_value(
+            &self,
+            _method: &str,
+            _params: Value,
+        ) -> impl Future<Output = Result<Value, Error>> + Send {
+            future::ready(Err(Error::other("Connection reset")))
+        }
+    }
+
+    const TEST_ADDRESS_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+
+    fn free_market_tx_json(to: &str) -> String {
+        format!(
+            r#"{{
+                "gasLimit": 21000,
+                "maxFeePerGas": 100000000000,
+                "maxPriorityFeePerGas": 3000000000,
+                "chainId": 1,
+                "nonce": 0,
+                "to": "{}",
+                "value": 0,
+                "data": "",
+                "accessList": []
+            }}"#,
+            to
+        )
+    }
+
+    #[test]
+    fn namehash_succeed() {
+        assert_eq!([0u8; 32], namehash(""));
+        assert_eq!(
+            "93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae",
+            hex::encode(namehash("eth"))
+        );
+        assert_eq!(
+            "de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f",
+            hex::encode(namehash("foo.eth"))
+        );
+    }
+
+    #[test]
+    fn namehash_case_insensitive_succeed() {
+        assert_eq!(namehash("foo.eth"), namehash("Foo.ETH"));
+    }
+
+    #[test]
+    fn is_ens_name_succeed() {
+        assert!(is_ens_name("vitalik.eth"));
+        assert!(is_ens_name("pay.vitalik.eth"));
+    }
+
+    #[test]
+    fn is_ens_name_fail() {
+        assert!(!is_ens_name(TEST_ADDRESS_STR));
+        assert!(!is_ens_name("eth"));
+        assert!(!is_ens_name("vitalik..eth"));
+        assert!(!is_ens_name(".eth"));
+    }
+
+    #[test]
+    fn from_json_with_ens_name_succeed() {
+        let input = free_market_tx_json("vitalik.eth");
+
+        let right = from_json_with_ens_name::<FreeMarketTransaction>(&input).unwrap();
+
+        assert_eq!(Some("vitalik.eth"), right.ens_name());
+    }
+
+    #[test]
+    fn from_json_with_address_succeed() {
+        let input = free_market_tx_json(TEST_ADDRESS_STR);
+
+        let right = from_json_with_ens_name::<FreeMarketTransaction>(&input).unwrap();
+
+        assert_eq!(None, right.ens_name());
+    }
+
+    #[test]
+    fn resolve_recipient_address_succeed() {
+        let input = from_json_with_ens_name::<FreeMarketTransaction>(&free_market_tx_json(
+            TEST_ADDRESS_STR,
+        ))
+        .unwrap();
+
+        let (tx, resolution) =
+            tokio_test::block_on(resolve_recipient(input, &FailingProvider)).unwrap();
+
+        assert_eq!(Some(TEST_ADDRESS_STR.parse().unwrap()), tx.to);
+        assert_eq!(None, resolution);
+    }
+
+    #[test]
+    fn resolve_recipient_failed_request_fail() {
+        let input =
+            from_json_with_ens_name::<FreeMarketTransaction>(&free_market_tx_json("vitalik.eth"))
+                .unwrap();
+
+        let right = tokio_test::block_on(resolve_recipient(input, &FailingProvider));
+
+        assert!(right.is_err());
+    }
+
+    #[test]
+    fn from_json_with_invalid_address_fail() {
+        let input = free_market_tx_json("0x1234");
+
+        assert!(from_json_with_ens_name::<FreeMarketTransaction>(&input).is_err());
+    }
+
+    #[test]
+    fn decode_address_succeed() {
+        let left: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+        let mut input = vec![0u8; 12];
+        input.extend_from_slice(left.as_slice());
+
+        let right = decode_address(&input).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn decode_address_empty_succeed() {
+        assert_eq!(AccountAddress::default(), decode_address(&[]).unwrap());
+    }
+
+    #[test]
+    fn resolution_display_succeed() {
+        let input = Resolution {
+            name: "vitalik.eth".to_string(),
+            address: TEST_ADDRESS_STR.parse().unwrap(),
+        };
+
+        assert_eq!(
+            format!("vitalik.eth resolved to {}", TEST_ADDRESS_STR),
+            input.to_string()
+        );
+    }
+}
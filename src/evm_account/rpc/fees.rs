@@ -0,0 +1,332 @@
+use std::{
+    future::Future,
+    io::{Error, ErrorKind},
+};
+
+use serde::Deserialize;
+
+use super::{decode_quantity, hex_quantity, Provider, NO_PARAMS};
+use crate::evm_account::transaction::{
+    access_list_transaction::AccessListTransaction, free_market_transaction::FreeMarketTransaction,
+    legacy_transaction::LegacyTransaction, U256,
+};
+
+// Number of recent blocks the fee suggestions are based on.
+const FEE_HISTORY_BLOCK_COUNT: u64 = 10;
+const LATEST_BLOCK_TAG: &str = "latest";
+const PERCENT: u128 = 100;
+
+/// Preset trading off the inclusion speed against the fees paid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FeePreset {
+    /// Tip of the 25th percentile of recent transactions and room for the base fee to rise by 25%.
+    /// Cheapest, but the transaction may wait for inclusion when the network is busy.
+    Conservative,
+    /// Median tip of recent transactions and room for the base fee to double.
+    #[default]
+    Normal,
+    /// Tip of the 90th percentile of recent transactions and room for the base fee to triple.
+    Aggressive,
+}
+
+impl FeePreset {
+    fn reward_percentile(&self) -> f64 {
+        match self {
+            FeePreset::Conservative => 25.0,
+            FeePreset::Normal => 50.0,
+            FeePreset::Aggressive => 90.0,
+        }
+    }
+
+    fn base_fee_percent(&self) -> u128 {
+        match self {
+            FeePreset::Conservative => 125,
+            FeePreset::Normal => 200,
+            FeePreset::Aggressive => 300,
+        }
+    }
+}
+
+/// Gas price multiplier applied by default, in percent.
+pub const DEFAULT_GAS_PRICE_PERCENT: u32 = 110;
+
+/// Transactions paying a single gas price, i.e. legacy and
+/// [`EIP-2930`](https://eips.ethereum.org/EIPS/eip-2930) transactions.
+pub trait GasPriced {
+    /// Sets the price paid per unit of gas.
+    fn set_gas_price(&mut self, gas_price: U256);
+}
+
+impl GasPriced for LegacyTransaction {
+    fn set_gas_price(&mut self, gas_price: U256) {
+        self.gas_price = gas_price;
+    }
+}
+
+impl GasPriced for AccessListTransaction {
+    fn set_gas_price(&mut self, gas_price: U256) {
+        self.gas_price = gas_price;
+    }
+}
+
+/// Transactions whose fees can be set to the ones suggested by the node with default settings,
+/// i.e. `FeePreset::Normal` for EIP-1559 transactions and `DEFAULT_GAS_PRICE_PERCENT` otherwise.
+pub trait FillFees {
+    /// Sets the fees of the transaction to the suggested ones.
+    fn fill_suggested_fees(
+        &mut self,
+        provider: &impl Provider,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+impl FillFees for LegacyTransaction {
+    async fn fill_suggested_fees(&mut self, provider: &impl Provider) -> Result<(), Error> {
+        fill_gas_price(self, provider, DEFAULT_GAS_PRICE_PERCENT).await?;
+        Ok(())
+    }
+}
+
+impl FillFees for AccessListTransaction {
+    async fn fill_suggested_fees(&mut self, provider: &impl Provider) -> Result<(), Error> {
+        fill_gas_price(self, provider, DEFAULT_GAS_PRICE_PERCENT).await?;
+        Ok(())
+    }
+}
+
+impl FillFees for FreeMarketTransaction {
+    async fn fill_suggested_fees(&mut self, provider: &impl Provider) -> Result<(), Error> {
+        fill_fees(self, provider, FeePreset::default()).await?;
+        Ok(())
+    }
+}
+
+/// Fees suggested for [`EIP-1559`](https://eips.ethereum.org/EIPS/eip-1559) transactions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeSuggestion {
+    /// Maximum total fee per unit of gas, i.e. the base fee headroom and the tip.
+    pub max_fee_per_gas: U256,
+    /// Tip paid to the block producer per unit of gas.
+    pub max_priority_fee_per_gas: U256,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FeeHistory {
+    base_fee_per_gas: Vec<String>,
+    #[serde(default)]
+    reward: Vec<Vec<String>>,
+}
+
+/// Suggests fees based on the base fees and tips of recent blocks (see `eth_feeHistory`).
+///
+/// Falls back to `eth_maxPriorityFeePerGas` for the tip if the node reports no rewards.
+pub async fn suggest_fees(
+    provider: &impl Provider,
+    preset: FeePreset,
+) -> Result<FeeSuggestion, Error> {
+    let history: FeeHistory = provider
+        .request(
+            "eth_feeHistory",
+            (
+                hex_quantity(FEE_HISTORY_BLOCK_COUNT),
+                LATEST_BLOCK_TAG,
+                [preset.reward_percentile()],
+            ),
+        )
+        .await?;
+
+    let base_fee = next_base_fee(&history)?;
+    let priority_fee = match median_reward(&history)? {
+        Some(priority_fee) => priority_fee,
+        None => {
+            let priority_fee: String = provider
+                .request("eth_maxPriorityFeePerGas", NO_PARAMS)
+                .await?;
+            decode_quantity(&priority_fee)?
+        }
+    };
+
+    Ok(suggest(base_fee, priority_fee, preset))
+}
+
+/// Sets the fees of the transaction to the suggested ones.
+pub async fn fill_fees(
+    tx: &mut FreeMarketTransaction,
+    provider: &impl Provider,
+    preset: FeePreset,
+) -> Result<FeeSuggestion, Error> {
+    let suggestion = suggest_fees(provider, preset).await?;
+    tx.max_fee_per_gas = suggestion.max_fee_per_gas;
+    tx.max_priority_fee_per_gas = suggestion.max_priority_fee_per_gas;
+
+    Ok(suggestion)
+}
+
+/// Suggests the gas price of legacy and EIP-2930 transactions, i.e. the `eth_gasPrice` of the
+/// node scaled by `multiplier_percent` (e.g. `110` for 10% above the node price).
+pub async fn suggest_gas_price(
+    provider: &impl Provider,
+    multiplier_percent: u32,
+) -> Result<U256, Error> {
+    let gas_price: String = provider.request("eth_gasPrice", NO_PARAMS).await?;
+
+    Ok(scale(
+        decode_quantity(&gas_price)?,
+        multiplier_percent.into(),
+    ))
+}
+
+/// Sets the gas price of the transaction to the suggested one (see `suggest_gas_price`).
+pub async fn fill_gas_price<T>(
+    tx: &mut T,
+    provider: &impl Provider,
+    multiplier_percent: u32,
+) -> Result<U256, Error>
+where
+    T: GasPriced,
+{
+    let gas_price = suggest_gas_price(provider, multiplier_percent).await?;
+    tx.set_gas_price(gas_price);
+
+    Ok(gas_price)
+}
+
+// The last base fee of the history is the base fee of the next block.
+fn next_base_fee(history: &FeeHistory) -> Result<U256, Error> {
+    let base_fee = history.base_fee_per_gas.last().ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidData,
+            "JSON-RPC endpoint returned no base fees",
+        )
+    })?;
+
+    decode_quantity(base_fee)
+}
+
+fn median_reward(history: &FeeHistory) -> Result<Option<U256>, Error> {
+    let mut rewards = history
+        .reward
+        .iter()
+        .filter_map(|block_rewards| block_rewards.first())
+        .map(|reward| decode_quantity(reward))
+        .collect::<Result<Vec<U256>, Error>>()?;
+    rewards.sort();
+
+    Ok(rewards.get(rewards.len() / 2).copied())
+}
+
+fn suggest(base_fee: U256, priority_fee: U256, preset: FeePreset) -> FeeSuggestion {
+    let base_fee_headroom =
+        base_fee.saturating_mul(U256::new(preset.base_fee_percent())) / U256::new(PERCENT);
+
+    FeeSuggestion {
+        max_fee_per_gas: base_fee_headroom.saturating_add(priority_fee),
+        max_priority_fee_per_gas: priority_fee,
+    }
+}
+
+fn scale(value: U256, percent: u128) -> U256 {
+    value.saturating_mul(U256::new(percent)) / U256::new(PERCENT)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn fee_history() -> FeeHistory {
+        serde_json::from_str(
+            r#"{
+                "oldestBlock": "0x10",
+                "baseFeePerGas": ["0x3b9aca00", "0x3b9aca00", "0x77359400"],
+                "gasUsedRatio": [0.5, 0.9],
+                "reward": [["0x3b9aca00"], ["0x5f5e100"], ["0xb2d05e00"]]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn next_base_fee_succeed() {
+        let left = U256::new(2_000_000_000);
+
+        let right = next_base_fee(&fee_history()).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn median_reward_succeed() {
+        let left = Some(U256::new(1_000_000_000));
+
+        let right = median_reward(&fee_history()).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn median_reward_empty_succeed() {
+        let input: FeeHistory = serde_json::from_str(r#"{"baseFeePerGas": ["0x1"]}"#).unwrap();
+
+        assert_eq!(None, median_reward(&input).unwrap());
+    }
+
+    #[test]
+    fn next_base_fee_empty_fail() {
+        let input: FeeHistory = serde_json::from_str(r#"{"baseFeePerGas": []}"#).unwrap();
+
+        assert!(next_base_fee(&input).is_err());
+    }
+
+    #[test]
+    fn suggest_normal_succeed() {
+        let left = FeeSuggestion {
+            max_fee_per_gas: U256::new(5_000_000_000),
+            max_priority_fee_per_gas: U256::new(1_000_000_000),
+        };
+
+        let right = suggest(
+            U256::new(2_000_000_000),
+            U256::new(1_000_000_000),
+            FeePreset::Normal,
+        );
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn scale_gas_price_succeed() {
+        let left = U256::new(1_100_000_000);
+
+        let right = scale(U256::new(1_000_000_000), DEFAULT_GAS_PRICE_PERCENT.into());
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn set_gas_price_succeed() {
+        let left = U256::new(1_100_000_000);
+        let mut input = LegacyTransaction {
+            nonce: 0,
+            gas_price: U256::ZERO,
+            gas_limit: 21_000,
+            to: None,
+            value: U256::ZERO,
+            data: Default::default(),
+        };
+
+        input.set_gas_price(left);
+
+        assert_eq!(left, input.gas_price);
+    }
+
+    #[test]
+    fn suggest_presets_ordered_succeed() {
+        let base_fee = U256::new(2_000_000_000);
+        let priority_fee = U256::new(1_000_000_000);
+
+        let conservative = suggest(base_fee, priority_fee, FeePreset::Conservative);
+        let aggressive = suggest(base_fee, priority_fee, FeePreset::Aggressive);
+
+        assert!(conservative.max_fee_per_gas < aggressive.max_fee_per_gas);
+    }
+}
@@ -0,0 +1,57 @@
+use std::io::Error;
+
+use super::{call_request, decode_quantity, Provider};
+use crate::evm_account::transaction::{AccountAddress, Transaction};
+
+/// Safety margin added on top of gas estimates by default, in percent.
+///
+/// Estimates are exact for the state the node simulates against, which may change until the
+/// transaction is included.
+pub const DEFAULT_GAS_MARGIN_PERCENT: u32 = 20;
+
+const PERCENT: u128 = 100;
+
+/// Estimates the gas consumed by the transaction sent from the address with `eth_estimateGas`
+/// and sets its gas limit to the estimate increased by `margin_percent`.
+///
+/// Returns the gas limit set. The current gas limit of the transaction is not passed to the node,
+/// so that it does not cap the estimate.
+pub async fn estimate_gas<T>(
+    tx: &mut T,
+    from: &AccountAddress,
+    provider: &impl Provider,
+    margin_percent: u32,
+) -> Result<u128, Error>
+where
+    T: Transaction,
+{
+    let mut request = call_request(tx, from)?;
+    if let Some(request) = request.as_object_mut() {
+        request.remove("gas");
+    }
+
+    let estimate: String = provider.request("eth_estimateGas", [request]).await?;
+    let gas_limit = with_margin(decode_quantity(&estimate)?, margin_percent);
+    tx.set_gas_limit(gas_limit);
+
+    Ok(gas_limit)
+}
+
+fn with_margin(estimate: u128, margin_percent: u32) -> u128 {
+    estimate.saturating_add(estimate.saturating_mul(margin_percent.into()) / PERCENT)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn with_margin_succeed() {
+        assert_eq!(25_200, with_margin(21_000, DEFAULT_GAS_MARGIN_PERCENT));
+    }
+
+    #[test]
+    fn with_no_margin_succeed() {
+        assert_eq!(21_000, with_margin(21_000, 0));
+    }
+}
@@ -0,0 +1,359 @@
+use std::{
+    future::Future,
+    io::{Error, ErrorKind},
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Mutex, MutexGuard, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+use serde_json::Value;
+
+use super::{build_request, parse_response, Provider};
+use crate::evm_account::runtime;
+
+const DEFAULT_RETRIES: u32 = 0;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+const RETRY_DELAY: Duration = Duration::from_millis(250);
+// Time for which endpoints which failed to respond are tried only after the healthy ones.
+const UNHEALTHY_COOLDOWN: Duration = Duration::from_secs(30);
+// Health checks are sent to every endpoint separately, so their ID is irrelevant.
+const HEALTH_CHECK_REQUEST_ID: u64 = 0;
+
+/// Client of Ethereum JSON-RPC endpoints over HTTP(S), e.g.:
+///
+/// ```rust
+/// use evm_signer_kms::evm_account::rpc::HttpProvider;
+///
+/// let provider = HttpProvider::new("https://ethereum-sepolia-rpc.publicnode.com").unwrap();
+/// ```
+///
+/// The provider can fail over between multiple endpoints of the same network. Endpoints which
+/// fail to respond are skipped for a while, until all remaining endpoints fail as well:
+///
+/// ```rust
+/// use evm_signer_kms::evm_account::rpc::HttpProvider;
+///
+/// let provider = HttpProvider::with_failover([
+///     "https://ethereum-sepolia-rpc.publicnode.com",
+///     "https://rpc.sepolia.org",
+/// ])
+/// .unwrap()
+/// .with_retries(2);
+/// ```
+#[derive(Debug)]
+pub struct HttpProvider {
+    client: reqwest::Client,
+    endpoints: Vec<Endpoint>,
+    active_endpoint: AtomicUsize,
+    retries: u32,
+    timeout: Duration,
+    request_id: AtomicU64,
+}
+
+impl HttpProvider {
+    /// Creates a new provider sending requests to the endpoint URL. Fails if the HTTP client
+    /// cannot be initialized.
+    pub fn new(endpoint: &str) -> Result<Self, Error> {
+        Self::from_endpoints(vec![Endpoint::new(endpoint)])
+    }
+
+    /// Creates a new provider failing over between the endpoint URLs in the given order of
+    /// preference. Fails if no endpoint is provided or if the HTTP client cannot be initialized.
+    pub fn with_failover<I, S>(endpoints: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let endpoints: Vec<_> = endpoints
+            .into_iter()
+            .map(|endpoint| Endpoint::new(endpoint.as_ref()))
+            .collect();
+        if endpoints.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "At least one JSON-RPC endpoint is required",
+            ));
+        }
+
+        Self::from_endpoints(endpoints)
+    }
+
+    fn from_endpoints(endpoints: Vec<Endpoint>) -> Result<Self, Error> {
+        Ok(Self {
+            client: http_client()?,
+            endpoints,
+            active_endpoint: AtomicUsize::new(0),
+            retries: DEFAULT_RETRIES,
+            timeout: DEFAULT_TIMEOUT,
+            request_id: AtomicU64::new(1),
+        })
+    }
+
+    /// Sets how many more times requests are sent to all endpoints if none of them responds,
+    /// with a growing delay in between.
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Sets the time after which a request to an endpoint which does not respond is abandoned, so
+    /// that the next endpoint is tried. Defaults to 30 seconds.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Time after which a request to an endpoint is abandoned.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// URL of the JSON-RPC endpoint which responded last.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoints[self.active_endpoint.load(Ordering::Relaxed)].url
+    }
+
+    /// URLs of all JSON-RPC endpoints in the order of preference.
+    pub fn endpoints(&self) -> impl Iterator<Item = &str> {
+        self.endpoints.iter().map(|endpoint| endpoint.url.as_str())
+    }
+
+    /// Queries the latest block number of every endpoint, marking the ones which fail to respond
+    /// as unhealthy. Returns the number of healthy endpoints.
+    pub async fn check_health(&self) -> usize {
+        let request = build_request(
+            HEALTH_CHECK_REQUEST_ID,
+            "eth_blockNumber",
+            Value::Array(vec![]),
+        );
+        let mut healthy_endpoints = 0;
+
+        for endpoint in &self.endpoints {
+            let healthy = match self.send(&endpoint.url, &request).await {
+                Ok(response) => parse_response::<String>(response).is_ok(),
+                Err(_) => false,
+            };
+            endpoint.set_healthy(healthy);
+            healthy_endpoints += usize::from(healthy);
+        }
+
+        healthy_endpoints
+    }
+
+    fn next_request_id(&self) -> u64 {
+        self.request_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    // Healthy endpoints first, starting from the one which responded last.
+    fn endpoint_order(&self) -> Vec<usize> {
+        let count = self.endpoints.len();
+        let active_endpoint = self.active_endpoint.load(Ordering::Relaxed);
+
+        let (mut order, unhealthy): (Vec<_>, Vec<_>) = (0..count)
+            .map(|offset| (active_endpoint + offset) % count)
+            .partition(|&index| self.endpoints[index].is_healthy());
+        order.extend(unhealthy);
+
+        order
+    }
+
+    async fn send(&self, url: &str, request: &Value) -> Result<Value, Error> {
+        let response = self
+            .client
+            .post(url)
+            .json(request)
+            .timeout(self.timeout)
+            .send()
+            .await
+            .map_err(transport_error)?;
+        let status = response.status();
+
+        response.json().await.map_err(|error| {
+            if status.is_success() {
+                transport_error(error)
+            } else {
+                Error::other(format!("JSON-RPC endpoint responded with {}", status))
+            }
+        })
+    }
+}
+
+impl Provider for HttpProvider {
+    /// Sends the request to the next endpoint if the current one cannot be reached. Errors
+    /// returned by the node are not retried.
+    fn request_value(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> impl Future<Output = Result<Value, Error>> + Send {
+        let request = build_request(self.next_request_id(), method, params);
+
+        async move {
+            let mut last_error = None;
+
+            for attempt in 0..=self.retries {
+                if attempt > 0 {
+                    runtime::sleep(RETRY_DELAY * attempt).await;
+                }
+
+                for index in self.endpoint_order() {
+                    let endpoint = &self.endpoints[index];
+                    match self.send(&endpoint.url, &request).await {
+                        Ok(response) => {
+                            endpoint.set_healthy(true);
+                            self.active_endpoint.store(index, Ordering::Relaxed);
+                            return parse_response(response);
+                        }
+                        Err(error) => {
+                            endpoint.set_healthy(false);
+                            last_error = Some(error);
+                        }
+                    }
+                }
+            }
+
+            Err(last_error.unwrap_or_else(|| Error::other("No JSON-RPC endpoint configured")))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Endpoint {
+    url: String,
+    failed_at: Mutex<Option<Instant>>,
+}
+
+impl Endpoint {
+    fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            failed_at: Mutex::new(None),
+        }
+    }
+
+    fn is_healthy(&self) -> bool {
+        match *self.failed_at() {
+            Some(failed_at) => failed_at.elapsed() >= UNHEALTHY_COOLDOWN,
+            None => true,
+        }
+    }
+
+    fn set_healthy(&self, healthy: bool) {
+        *self.failed_at() = if healthy { None } else { Some(Instant::now()) };
+    }
+
+    fn failed_at(&self) -> MutexGuard<'_, Option<Instant>> {
+        // The guarded value is always valid, even if another thread panicked
+        self.failed_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Creates the HTTP client of the TLS backend selected with the `rustls` (default) and `native-tls`
+/// features. Native TLS takes precedence, as the default `rustls` feature may still be enabled.
+///
+/// Fails if the TLS backend cannot be initialized, e.g. on hosts without system certificates.
+pub(crate) fn http_client() -> Result<reqwest::Client, Error> {
+    let builder = reqwest::Client::builder();
+    #[cfg(feature = "native-tls")]
+    let builder = builder.use_native_tls();
+
+    builder
+        .build()
+        .map_err(|error| Error::other(format!("Failed to initialize the HTTP client: {}", error)))
+}
+
+pub(super) fn transport_error(error: reqwest::Error) -> Error {
+    let kind = if error.is_timeout() {
+        ErrorKind::TimedOut
+    } else if error.is_connect() {
+        ErrorKind::ConnectionRefused
+    } else {
+        ErrorKind::Other
+    };
+
+    Error::new(kind, format!("JSON-RPC request failed: {}", error))
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::rpc::chain::chain_id;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    #[test]
+    fn with_failover_succeed() {
+        let right = HttpProvider::with_failover(["http://localhost:8545", "http://localhost:8546"])
+            .unwrap();
+
+        assert_eq!(
+            vec!["http://localhost:8545", "http://localhost:8546"],
+            right.endpoints().collect::<Vec<_>>()
+        );
+        assert_eq!("http://localhost:8545", right.endpoint());
+    }
+
+    #[test]
+    fn with_failover_no_endpoints_fail() {
+        let error = HttpProvider::with_failover(Vec::<String>::new()).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn endpoint_order_succeed() {
+        let input = HttpProvider::with_failover(["http://a", "http://b", "http://c"]).unwrap();
+        input.active_endpoint.store(1, Ordering::Relaxed);
+        input.endpoints[2].set_healthy(false);
+
+        assert_eq!(vec![1, 0, 2], input.endpoint_order());
+    }
+
+    #[test]
+    fn endpoint_health_succeed() {
+        let input = Endpoint::new("http://localhost:8545");
+
+        input.set_healthy(false);
+        assert!(!input.is_healthy());
+
+        input.set_healthy(true);
+        assert!(input.is_healthy());
+    }
+
+    #[test]
+    fn request_stalled_endpoint_fail_over_succeed() {
+        // Accepts connections, which the OS completes, without ever responding
+        let stalled = TcpListener::bind("127.0.0.1:0").unwrap();
+        let responding = TcpListener::bind("127.0.0.1:0").unwrap();
+        let urls = [&stalled, &responding]
+            .map(|listener| format!("http://{}", listener.local_addr().unwrap()));
+        thread::spawn(move || {
+            let (mut stream, _) = responding.accept().unwrap();
+            let _ = stream.read(&mut [0; 4096]).unwrap();
+            let body = r#"{"jsonrpc":"2.0","id":1,"result":"0xaa36a7"}"#;
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+        });
+        let input = HttpProvider::with_failover(&urls)
+            .unwrap()
+            .with_timeout(Duration::from_millis(200));
+
+        let right = tokio_test::block_on(chain_id(&input)).unwrap();
+
+        assert_eq!(11155111, right);
+        assert_eq!(urls[1], input.endpoint());
+        assert!(!input.endpoints[0].is_healthy());
+    }
+}
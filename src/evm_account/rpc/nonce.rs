@@ -0,0 +1,49 @@
+use std::io::Error;
+
+use super::{decode_quantity, Provider};
+use crate::evm_account::transaction::{AccountAddress, Transaction};
+
+// Block tag including transactions in the mempool of the node.
+const PENDING_BLOCK_TAG: &str = "pending";
+const LATEST_BLOCK_TAG: &str = "latest";
+
+/// Returns the next nonce of the account, i.e. the number of its transactions including those
+/// pending in the mempool (`eth_getTransactionCount` at the `pending` block).
+pub async fn next_nonce(address: &AccountAddress, provider: &impl Provider) -> Result<u128, Error> {
+    transaction_count(address, PENDING_BLOCK_TAG, provider).await
+}
+
+/// Returns the number of transactions of the account included in the latest block, i.e. nonces
+/// below it are no longer pending.
+pub async fn confirmed_nonce(
+    address: &AccountAddress,
+    provider: &impl Provider,
+) -> Result<u128, Error> {
+    transaction_count(address, LATEST_BLOCK_TAG, provider).await
+}
+
+async fn transaction_count(
+    address: &AccountAddress,
+    block_tag: &str,
+    provider: &impl Provider,
+) -> Result<u128, Error> {
+    let nonce: String = provider
+        .request("eth_getTransactionCount", (address.to_string(), block_tag))
+        .await?;
+
+    decode_quantity(&nonce)
+}
+
+/// Sets the nonce of the transaction to the next nonce of the sending account.
+pub async fn fill_nonce<T>(
+    tx: &mut T,
+    address: &AccountAddress,
+    provider: &impl Provider,
+) -> Result<(), Error>
+where
+    T: Transaction,
+{
+    tx.set_nonce(next_nonce(address, provider).await?);
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use std::{cmp, io::Error, time::Duration};
+
+use serde::Deserialize;
+
+use super::{decode_quantity, Provider, NO_PARAMS};
+use crate::evm_account::{
+    hash::Keccak256Digest,
+    runtime,
+    transaction::{AccountAddress, Bytes, U256},
+};
+
+const INITIAL_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(8);
+// Status of transactions which executed successfully (see EIP-658).
+const SUCCESS_STATUS: &str = "0x1";
+
+/// Outcome of a transaction included in a block.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Receipt {
+    /// Number of the block the transaction was included in.
+    pub block_number: u64,
+    /// Whether the transaction executed successfully, i.e. did not revert.
+    pub success: bool,
+    /// Amount of gas consumed by the transaction.
+    pub gas_used: u128,
+    /// Price actually paid per unit of gas, i.e. the base fee and the tip.
+    pub effective_gas_price: U256,
+    /// Address of the contract created by the transaction, if any.
+    pub contract_address: Option<AccountAddress>,
+}
+
+impl Receipt {
+    /// Amount of wei paid for gas, i.e. `gas_used * effective_gas_price`.
+    pub fn fee(&self) -> U256 {
+        U256::new(self.gas_used).saturating_mul(self.effective_gas_price)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawReceipt {
+    block_number: String,
+    // Missing in receipts of transactions preceding the Byzantium fork
+    status: Option<String>,
+    gas_used: String,
+    // Missing in receipts of some nodes preceding the London fork
+    effective_gas_price: Option<String>,
+    contract_address: Option<String>,
+}
+
+impl TryFrom<RawReceipt> for Receipt {
+    type Error = Error;
+
+    fn try_from(receipt: RawReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            block_number: decode_quantity(&receipt.block_number)?,
+            success: receipt.status.as_deref() == Some(SUCCESS_STATUS),
+            gas_used: decode_quantity(&receipt.gas_used)?,
+            effective_gas_price: receipt
+                .effective_gas_price
+                .as_deref()
+                .map(decode_quantity)
+                .transpose()?
+                .unwrap_or_default(),
+            contract_address: receipt
+                .contract_address
+                .map(|address| address.parse())
+                .transpose()?,
+        })
+    }
+}
+
+/// Returns the receipt of the transaction or `None` if it has not been included yet.
+pub async fn get_receipt(
+    tx_hash: &Keccak256Digest,
+    provider: &impl Provider,
+) -> Result<Option<Receipt>, Error> {
+    let tx_hash = Bytes::from(tx_hash.as_slice()).to_string();
+    let receipt: Option<RawReceipt> = provider
+        .request("eth_getTransactionReceipt", [tx_hash])
+        .await?;
+
+    receipt.map(Receipt::try_from).transpose()
+}
+
+/// Polls the receipt of the transaction with exponential backoff until the block including it has
+/// the requested number of confirmations (i.e. `1` once included), or fails with
+/// `ErrorKind::TimedOut` once `timeout` elapses.
+pub async fn wait_for_receipt(
+    tx_hash: &Keccak256Digest,
+    confirmations: u64,
+    timeout: Duration,
+    provider: &impl Provider,
+) -> Result<Receipt, Error> {
+    let poll = async {
+        let mut poll_interval = INITIAL_POLL_INTERVAL;
+
+        loop {
+            if let Some(receipt) = get_receipt(tx_hash, provider).await? {
+                if is_confirmed(&receipt, confirmations, block_number(provider).await?) {
+                    return Ok(receipt);
+                }
+            }
+
+            runtime::sleep(poll_interval).await;
+            poll_interval = cmp::min(poll_interval * 2, MAX_POLL_INTERVAL);
+        }
+    };
+
+    runtime::timeout(
+        timeout,
+        poll,
+        "Transaction was not confirmed before the timeout",
+    )
+    .await?
+}
+
+async fn block_number(provider: &impl Provider) -> Result<u64, Error> {
+    let block_number: String = provider.request("eth_blockNumber", NO_PARAMS).await?;
+
+    decode_quantity(&block_number)
+}
+
+fn is_confirmed(receipt: &Receipt, confirmations: u64, block_number: u64) -> bool {
+    // The block including the transaction is its first confirmation
+    let required_block_number = receipt
+        .block_number
+        .saturating_add(confirmations.saturating_sub(1));
+
+    block_number >= required_block_number
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn raw_receipt() -> RawReceipt {
+        serde_json::from_str(
+            r#"{
+                "transactionHash": "0x2f0d7b3e1b2e0c4f1a8f9d4e3c2b1a0f9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b",
+                "blockNumber": "0x10",
+                "status": "0x1",
+                "gasUsed": "0x5208",
+                "effectiveGasPrice": "0x3b9aca00",
+                "contractAddress": null
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn receipt_try_from_succeed() {
+        let left = Receipt {
+            block_number: 16,
+            success: true,
+            gas_used: 21_000,
+            effective_gas_price: U256::new(1_000_000_000),
+            contract_address: None,
+        };
+
+        let right = Receipt::try_from(raw_receipt()).unwrap();
+
+        assert_eq!(left, right);
+        assert_eq!(U256::new(21_000_000_000_000), right.fee());
+    }
+
+    #[test]
+    fn receipt_reverted_succeed() {
+        let mut input = raw_receipt();
+        input.status = Some("0x0".to_string());
+
+        assert!(!Receipt::try_from(input).unwrap().success);
+    }
+
+    #[test]
+    fn is_confirmed_succeed() {
+        let input = Receipt::try_from(raw_receipt()).unwrap();
+
+        assert!(is_confirmed(&input, 0, 16));
+        assert!(is_confirmed(&input, 1, 16));
+        assert!(is_confirmed(&input, 3, 18));
+    }
+
+    #[test]
+    fn is_confirmed_fail() {
+        let input = Receipt::try_from(raw_receipt()).unwrap();
+
+        assert!(!is_confirmed(&input, 2, 16));
+    }
+}
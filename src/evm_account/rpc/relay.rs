@@ -0,0 +1,285 @@
+use std::{
+    io::{Error, ErrorKind},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Map, Value};
+
+use super::{
+    build_request, decode_hash, hex_quantity,
+    http::{http_client, transport_error},
+    parse_response,
+};
+use crate::evm_account::{
+    hash::{keccak256, Keccak256Digest},
+    signature::Signature,
+    transaction::{AccountAddress, Bytes, SignedTransaction, Transaction},
+    EvmAccount,
+};
+
+/// URL of the Flashbots relay on mainnet.
+pub const FLASHBOTS_RELAY_URL: &str = "https://relay.flashbots.net";
+// Header authenticating requests with the identity of the searcher.
+const SIGNATURE_HEADER: &str = "X-Flashbots-Signature";
+
+/// Bundle of signed transactions included atomically in the target block, in the given order.
+///
+/// ```rust
+/// use evm_signer_kms::evm_account::rpc::relay::Bundle;
+///
+/// let bundle = Bundle::new(21_000_000).with_timestamps(Some(1_700_000_000), None);
+///
+/// assert!(bundle.is_empty());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bundle {
+    transactions: Vec<Bytes>,
+    block_number: u64,
+    min_timestamp: Option<u64>,
+    max_timestamp: Option<u64>,
+}
+
+impl Bundle {
+    /// Creates an empty bundle targeting the block.
+    pub fn new(block_number: u64) -> Self {
+        Self {
+            block_number,
+            ..Default::default()
+        }
+    }
+
+    /// Restricts the bundle to blocks with timestamps in the range (in seconds since epoch).
+    pub fn with_timestamps(
+        mut self,
+        min_timestamp: Option<u64>,
+        max_timestamp: Option<u64>,
+    ) -> Self {
+        self.min_timestamp = min_timestamp;
+        self.max_timestamp = max_timestamp;
+        self
+    }
+
+    /// Appends the signed transaction to the bundle.
+    pub fn push<T: Transaction>(&mut self, signed_tx: &SignedTransaction<T>) {
+        self.transactions.push(Bytes(signed_tx.encode()));
+    }
+
+    /// Number of transactions in the bundle.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if the bundle holds no transactions.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    fn to_params(&self) -> Value {
+        let mut params = Map::new();
+        params.insert(
+            "txs".to_string(),
+            self.transactions
+                .iter()
+                .map(|tx| Value::String(tx.to_string()))
+                .collect(),
+        );
+        params.insert(
+            "blockNumber".to_string(),
+            hex_quantity(self.block_number).into(),
+        );
+        if let Some(min_timestamp) = self.min_timestamp {
+            params.insert("minTimestamp".to_string(), min_timestamp.into());
+        }
+        if let Some(max_timestamp) = self.max_timestamp {
+            params.insert("maxTimestamp".to_string(), max_timestamp.into());
+        }
+
+        json!([params])
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BundleResponse {
+    bundle_hash: Bytes,
+}
+
+/// Client of an MEV-protected relay accepting bundles (`eth_sendBundle`) and private transactions
+/// (`eth_sendPrivateTransaction`), e.g. the Flashbots relay.
+///
+/// Requests are authenticated with an
+/// [`EIP-191`](https://eips.ethereum.org/EIPS/eip-191) signature of their body, produced by an
+/// identity account. It can be the account signing the transactions or a separate KMS key, so that
+/// the reputation of the searcher is not tied to the funds.
+#[derive(Debug)]
+pub struct Relay {
+    client: reqwest::Client,
+    endpoint: String,
+    request_id: AtomicU64,
+}
+
+impl Relay {
+    /// Creates a new relay client sending requests to the endpoint URL. Fails if the HTTP client
+    /// cannot be initialized.
+    pub fn new(endpoint: &str) -> Result<Self, Error> {
+        Ok(Self {
+            client: http_client()?,
+            endpoint: endpoint.to_string(),
+            request_id: AtomicU64::new(1),
+        })
+    }
+
+    /// Creates a new client of the Flashbots relay on mainnet.
+    pub fn flashbots() -> Result<Self, Error> {
+        Self::new(FLASHBOTS_RELAY_URL)
+    }
+
+    /// URL of the relay.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Submits the bundle for inclusion in its target block, returning the bundle hash.
+    pub async fn send_bundle(
+        &self,
+        bundle: &Bundle,
+        identity: &EvmAccount<'_>,
+    ) -> Result<Keccak256Digest, Error> {
+        if bundle.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Bundle holds no transactions",
+            ));
+        }
+
+        let response: BundleResponse = self
+            .request("eth_sendBundle", bundle.to_params(), identity)
+            .await?;
+
+        decode_hash(response.bundle_hash)
+    }
+
+    /// Submits the signed transaction privately, i.e. without exposing it in the public mempool,
+    /// returning its hash. The relay stops trying to include it after `max_block_number`.
+    pub async fn send_private_transaction<T>(
+        &self,
+        signed_tx: &SignedTransaction<T>,
+        max_block_number: Option<u64>,
+        identity: &EvmAccount<'_>,
+    ) -> Result<Keccak256Digest, Error>
+    where
+        T: Transaction,
+    {
+        let mut params = Map::new();
+        params.insert(
+            "tx".to_string(),
+            Bytes(signed_tx.encode()).to_string().into(),
+        );
+        if let Some(max_block_number) = max_block_number {
+            params.insert(
+                "maxBlockNumber".to_string(),
+                hex_quantity(max_block_number).into(),
+            );
+        }
+
+        let hash: Bytes = self
+            .request("eth_sendPrivateTransaction", json!([params]), identity)
+            .await?;
+
+        decode_hash(hash)
+    }
+
+    async fn request<R>(
+        &self,
+        method: &str,
+        params: Value,
+        identity: &EvmAccount<'_>,
+    ) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+    {
+        let id = self.request_id.fetch_add(1, Ordering::Relaxed);
+        let body = build_request(id, method, params).to_string();
+        let signature = identity.sign_message(body_digest(&body)).await?;
+
+        let response = self
+            .client
+            .post(&self.endpoint)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(
+                SIGNATURE_HEADER,
+                signature_header(&identity.address(), &signature),
+            )
+            .body(body)
+            .send()
+            .await
+            .map_err(transport_error)?;
+        let status = response.status();
+        let response: Value = response.json().await.map_err(|error| {
+            if status.is_success() {
+                transport_error(error)
+            } else {
+                Error::other(format!("Relay responded with {}", status))
+            }
+        })?;
+
+        parse_response(response)
+    }
+}
+
+// The relay expects the signed message to be the hex encoded digest of the body, rather than
+// the digest itself.
+fn body_digest(body: &str) -> String {
+    Bytes(keccak256(body).to_vec()).to_string()
+}
+
+fn signature_header(address: &AccountAddress, signature: &Signature) -> String {
+    format!("{}:{}", address, signature)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn bundle_to_params_succeed() {
+        let mut input = Bundle::new(26).with_timestamps(None, Some(1_700_000_000));
+        input.transactions.push(Bytes(vec![0x02, 0xab]));
+
+        let right = input.to_params();
+
+        assert_eq!(
+            json!([{
+                "txs": ["0x02ab"],
+                "blockNumber": "0x1a",
+                "maxTimestamp": 1_700_000_000,
+            }]),
+            right
+        );
+        assert_eq!(1, input.len());
+    }
+
+    #[test]
+    fn body_digest_succeed() {
+        let right = body_digest("");
+
+        assert_eq!(
+            "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+            right
+        );
+    }
+
+    #[test]
+    fn signature_header_succeed() {
+        let address: AccountAddress = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573"
+            .parse()
+            .unwrap();
+        let signature = Signature::new([0x11; 32], [0x22; 32], 0);
+
+        let right = signature_header(&address, &signature);
+
+        assert_eq!(format!("{}:{}", address, signature), right);
+        assert!(right.starts_with("0xa9d89186cAA663C8Ef0352Fd1Db3596280625573:0x1111"));
+    }
+}
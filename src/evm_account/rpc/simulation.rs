@@ -0,0 +1,234 @@
+use std::{
+    error,
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+};
+
+use serde_json::Value;
+
+use super::{call_request, Provider, RpcError};
+use crate::evm_account::transaction::{AccountAddress, Bytes, Transaction, U256};
+
+const PENDING_BLOCK_TAG: &str = "pending";
+// JSON-RPC error code of reverted calls (see EIP-1474).
+const EXECUTION_REVERTED_CODE: i64 = 3;
+const EXECUTION_REVERTED_MESSAGE: &str = "execution reverted";
+// Selectors of the `Error(string)` and `Panic(uint256)` errors raised by Solidity.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+const SELECTOR_LENGTH: usize = 4;
+const WORD_LENGTH: usize = 32;
+
+/// Reverted execution of a simulated transaction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Revert {
+    /// Revert reason decoded from `Error(string)` or `Panic(uint256)` revert data, if available.
+    pub reason: Option<String>,
+    /// Raw revert data, e.g. an ABI encoded custom error.
+    pub data: Bytes,
+}
+
+impl Display for Revert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.reason {
+            Some(reason) => write!(f, "Execution reverted: {}", reason),
+            None if self.data.is_empty() => f.write_str("Execution reverted"),
+            None => write!(f, "Execution reverted with data {}", self.data),
+        }
+    }
+}
+
+impl error::Error for Revert {}
+
+/// Outcome of a simulated transaction.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Simulation {
+    /// Execution succeeded with the returned data.
+    Success(Bytes),
+    /// Execution reverted.
+    Reverted(Revert),
+}
+
+impl Simulation {
+    /// Converts a reverted simulation to an error carrying the `Revert`.
+    pub fn into_result(self) -> Result<Bytes, Error> {
+        match self {
+            Simulation::Success(output) => Ok(output),
+            Simulation::Reverted(revert) => Err(Error::new(ErrorKind::InvalidInput, revert)),
+        }
+    }
+}
+
+/// Executes the transaction sent from the address with `eth_call` against the pending state of the
+/// node, without broadcasting it.
+///
+/// Fails only if the node cannot be queried; reverts are reported as `Simulation::Reverted`.
+pub async fn simulate<T>(
+    tx: &T,
+    from: &AccountAddress,
+    provider: &impl Provider,
+) -> Result<Simulation, Error>
+where
+    T: Transaction,
+{
+    let request = call_request(tx, from)?;
+
+    match provider
+        .request::<_, Bytes>("eth_call", (request, PENDING_BLOCK_TAG))
+        .await
+    {
+        Ok(output) => Ok(Simulation::Success(output)),
+        Err(error) => match RpcError::from_io_error(&error).and_then(to_revert) {
+            Some(revert) => Ok(Simulation::Reverted(revert)),
+            None => Err(error),
+        },
+    }
+}
+
+fn to_revert(error: &RpcError) -> Option<Revert> {
+    let message = error.message.to_lowercase();
+    if error.code != EXECUTION_REVERTED_CODE && !message.contains(EXECUTION_REVERTED_MESSAGE) {
+        return None;
+    }
+
+    let data = match &error.data {
+        Some(Value::String(data)) => data.parse().unwrap_or_default(),
+        _ => Bytes::default(),
+    };
+    // Fall back to the reason in the message, e.g. `execution reverted: Ownable: caller is not
+    // the owner`, if the node does not return revert data
+    let reason = decode_revert_reason(&data).or_else(|| {
+        error
+            .message
+            .split_once(": ")
+            .map(|(_, reason)| reason.to_string())
+    });
+
+    Some(Revert { reason, data })
+}
+
+/// Decodes the reason from `Error(string)` or `Panic(uint256)` revert data.
+pub fn decode_revert_reason(data: &[u8]) -> Option<String> {
+    if data.len() < SELECTOR_LENGTH {
+        return None;
+    }
+    let (selector, payload) = data.split_at(SELECTOR_LENGTH);
+
+    if selector == ERROR_SELECTOR {
+        let offset = read_word_usize(payload, 0)?;
+        let length = read_word_usize(payload, offset)?;
+        let start = offset.checked_add(WORD_LENGTH)?;
+        let reason = payload.get(start..start.checked_add(length)?)?;
+
+        String::from_utf8(reason.to_vec()).ok()
+    } else if selector == PANIC_SELECTOR {
+        let code = U256::from_be_bytes(payload.get(..WORD_LENGTH)?.try_into().ok()?);
+
+        Some(format!("Panic(0x{:x})", code))
+    } else {
+        None
+    }
+}
+
+fn read_word_usize(payload: &[u8], offset: usize) -> Option<usize> {
+    let word = payload.get(offset..offset.checked_add(WORD_LENGTH)?)?;
+    let value = U256::from_be_bytes(word.try_into().ok()?);
+
+    usize::try_from(value).ok()
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    // `Error("Not enough funds")`
+    const TEST_ERROR_DATA: &str = "0x08c379a0\
+        0000000000000000000000000000000000000000000000000000000000000020\
+        0000000000000000000000000000000000000000000000000000000000000010\
+        4e6f7420656e6f7567682066756e647300000000000000000000000000000000";
+    // `Panic(0x11)`, i.e. arithmetic overflow
+    const TEST_PANIC_DATA: &str = "0x4e487b71\
+        0000000000000000000000000000000000000000000000000000000000000011";
+
+    fn rpc_error(message: &str, data: Option<&str>) -> RpcError {
+        RpcError {
+            code: EXECUTION_REVERTED_CODE,
+            message: message.to_string(),
+            data: data.map(Value::from),
+        }
+    }
+
+    #[test]
+    fn decode_error_reason_succeed() {
+        let input: Bytes = TEST_ERROR_DATA.parse().unwrap();
+
+        let right = decode_revert_reason(&input);
+
+        assert_eq!(Some("Not enough funds".to_string()), right);
+    }
+
+    #[test]
+    fn decode_panic_reason_succeed() {
+        let input: Bytes = TEST_PANIC_DATA.parse().unwrap();
+
+        let right = decode_revert_reason(&input);
+
+        assert_eq!(Some("Panic(0x11)".to_string()), right);
+    }
+
+    #[test]
+    fn decode_custom_error_fail() {
+        let input = [0xde, 0xad, 0xbe, 0xef];
+
+        assert_eq!(None, decode_revert_reason(&input));
+    }
+
+    #[test]
+    fn decode_truncated_error_fail() {
+        let input: Bytes = TEST_ERROR_DATA.parse().unwrap();
+
+        assert_eq!(None, decode_revert_reason(&input[..40]));
+    }
+
+    #[test]
+    fn to_revert_data_succeed() {
+        let input = rpc_error("execution reverted", Some(TEST_ERROR_DATA));
+
+        let right = to_revert(&input).unwrap();
+
+        assert_eq!(Some("Not enough funds".to_string()), right.reason);
+        assert_eq!("Execution reverted: Not enough funds", right.to_string());
+    }
+
+    #[test]
+    fn to_revert_message_succeed() {
+        let input = rpc_error("execution reverted: Ownable: caller is not the owner", None);
+
+        let right = to_revert(&input).unwrap();
+
+        assert_eq!(
+            Some("Ownable: caller is not the owner".to_string()),
+            right.reason
+        );
+        assert!(right.data.is_empty());
+    }
+
+    #[test]
+    fn to_revert_other_error_fail() {
+        let input = RpcError {
+            code: -32000,
+            message: "insufficient funds for gas * price + value".to_string(),
+            data: None,
+        };
+
+        assert_eq!(None, to_revert(&input));
+    }
+
+    #[test]
+    fn into_result_reverted_fail() {
+        let input =
+            Simulation::Reverted(to_revert(&rpc_error("execution reverted", None)).unwrap());
+
+        assert!(input.into_result().is_err());
+    }
+}
@@ -0,0 +1,280 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    io::Error,
+};
+
+use super::{
+    nonce::confirmed_nonce,
+    receipt::{get_receipt, Receipt},
+    Provider,
+};
+use crate::evm_account::{
+    hash::Keccak256Digest,
+    transaction::{AccountAddress, SignedTransaction, Transaction},
+};
+
+/// Outcome of a nonce which is no longer pending.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Settlement {
+    /// One of the tracked transactions with the nonce was included.
+    Included {
+        /// Hash of the included transaction.
+        hash: Keccak256Digest,
+        /// Receipt of the included transaction.
+        receipt: Receipt,
+        /// Hashes of the other tracked transactions with the nonce, which can no longer be
+        /// included.
+        replaced: Vec<Keccak256Digest>,
+    },
+    /// A transaction which is not tracked was included with the nonce, e.g. one sent by another
+    /// process using the same key.
+    Replaced {
+        /// Hashes of the tracked transactions with the nonce.
+        replaced: Vec<Keccak256Digest>,
+    },
+}
+
+/// Nonce of an account which is no longer pending.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettledNonce {
+    /// Sender of the transactions.
+    pub address: AccountAddress,
+    /// Settled nonce.
+    pub nonce: u128,
+    /// Outcome of the nonce.
+    pub settlement: Settlement,
+}
+
+/// Tracks broadcast transactions per account until they are included or replaced, e.g. to
+/// coordinate nonces of high-volume senders.
+///
+/// Transactions re-signed with the same nonce (e.g. fee bumps) are tracked together, until one of
+/// them is included.
+#[derive(Debug, Default)]
+pub struct Tracker {
+    pending: HashMap<AccountAddress, BTreeMap<u128, Vec<Keccak256Digest>>>,
+}
+
+impl Tracker {
+    /// Creates a tracker with no transactions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the broadcast transaction under its sender recovered from the signature.
+    pub fn record<T>(&mut self, signed_tx: &SignedTransaction<T>) -> Result<(), Error>
+    where
+        T: Transaction,
+    {
+        let hashes = self
+            .pending
+            .entry(signed_tx.sender()?)
+            .or_default()
+            .entry(signed_tx.tx().nonce())
+            .or_default();
+
+        let hash = signed_tx.hash();
+        if !hashes.contains(&hash) {
+            hashes.push(hash);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the nonces of the account with transactions in flight.
+    pub fn in_flight_nonces(&self, address: &AccountAddress) -> BTreeSet<u128> {
+        self.pending
+            .get(address)
+            .map(|nonces| nonces.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the hashes of the transactions in flight with the nonce of the account.
+    pub fn in_flight_hashes(&self, address: &AccountAddress, nonce: u128) -> &[Keccak256Digest] {
+        self.pending
+            .get(address)
+            .and_then(|nonces| nonces.get(&nonce))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if no transactions are in flight.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Checks the tracked accounts against the latest block and stops tracking the nonces which
+    /// are no longer pending, returning their outcomes.
+    ///
+    /// If a request fails, no nonce stops being tracked, so that polling can be retried.
+    pub async fn poll(&mut self, provider: &impl Provider) -> Result<Vec<SettledNonce>, Error> {
+        let mut settled = vec![];
+        let mut confirmed_nonces = Vec::with_capacity(self.pending.len());
+
+        for (address, nonces) in &self.pending {
+            let confirmed_nonce = confirmed_nonce(address, provider).await?;
+
+            for (nonce, hashes) in nonces.range(..confirmed_nonce) {
+                let mut receipts = Vec::with_capacity(hashes.len());
+                for hash in hashes {
+                    receipts.push(get_receipt(hash, provider).await?);
+                }
+
+                settled.push(SettledNonce {
+                    address: *address,
+                    nonce: *nonce,
+                    settlement: settle(hashes.clone(), receipts),
+                });
+            }
+            confirmed_nonces.push((*address, confirmed_nonce));
+        }
+
+        // Only updated once all the requests succeeded
+        for (address, confirmed_nonce) in confirmed_nonces {
+            if let Some(nonces) = self.pending.get_mut(&address) {
+                *nonces = nonces.split_off(&confirmed_nonce);
+            }
+        }
+        self.pending.retain(|_, nonces| !nonces.is_empty());
+
+        Ok(settled)
+    }
+}
+
+fn settle(hashes: Vec<Keccak256Digest>, receipts: Vec<Option<Receipt>>) -> Settlement {
+    let mut included = None;
+    let mut replaced = vec![];
+
+    for (hash, receipt) in hashes.into_iter().zip(receipts) {
+        match receipt {
+            Some(receipt) if included.is_none() => included = Some((hash, receipt)),
+            _ => replaced.push(hash),
+        }
+    }
+
+    match included {
+        Some((hash, receipt)) => Settlement::Included {
+            hash,
+            receipt,
+            replaced,
+        },
+        None => Settlement::Replaced { replaced },
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::signature_vector::{TEST_DIGEST, TEST_R, TEST_S, TEST_SENDER};
+    use crate::evm_account::transaction::{legacy_transaction::LegacyTransaction, Bytes, U256};
+    use serde_json::{json, Value};
+    use std::future::{self, Future};
+
+    // Node on which nonces up to 4 are confirmed, and whose receipts cannot be fetched
+    struct FailingProvider;
+
+    impl Provider for FailingProvider {
+        fn request_value(
+            &self,
+            method: &str,
+            _params: Value,
+        ) -> impl Future<Output = Result<Value, Error>> + Send {
+            future::ready(match method {
+                "eth_getTransactionCount" => Ok(json!("0x5")),
+                _ => Err(Error::other("Connection reset")),
+            })
+        }
+    }
+
+    fn signed_tx(nonce: u128, gas_price: u128) -> SignedTransaction<LegacyTransaction> {
+        let tx = LegacyTransaction {
+            nonce,
+            gas_price: U256::new(gas_price),
+            gas_limit: 21_000,
+            to: None,
+            value: U256::ZERO,
+            data: Bytes::default(),
+        };
+        let encoding = tx.encode();
+
+        SignedTransaction::new(tx, &encoding, TEST_DIGEST, 0, TEST_R, TEST_S)
+    }
+
+    fn receipt() -> Receipt {
+        Receipt {
+            block_number: 16,
+            success: true,
+            gas_used: 21_000,
+            effective_gas_price: U256::new(1_000_000_000),
+            contract_address: None,
+        }
+    }
+
+    #[test]
+    fn record_succeed() {
+        let address: AccountAddress = TEST_SENDER.parse().unwrap();
+        let mut tracker = Tracker::new();
+
+        tracker.record(&signed_tx(3, 1)).unwrap();
+        tracker.record(&signed_tx(3, 2)).unwrap();
+        tracker.record(&signed_tx(3, 2)).unwrap();
+        tracker.record(&signed_tx(5, 1)).unwrap();
+
+        assert_eq!(BTreeSet::from([3, 5]), tracker.in_flight_nonces(&address));
+        assert_eq!(2, tracker.in_flight_hashes(&address, 3).len());
+        assert!(!tracker.is_empty());
+    }
+
+    #[test]
+    fn in_flight_nonces_unknown_account_succeed() {
+        let tracker = Tracker::new();
+
+        assert!(tracker
+            .in_flight_nonces(&AccountAddress::default())
+            .is_empty());
+    }
+
+    #[test]
+    fn settle_included_succeed() {
+        let input = vec![[0x01; 32], [0x02; 32]];
+
+        let right = settle(input, vec![None, Some(receipt())]);
+
+        assert_eq!(
+            Settlement::Included {
+                hash: [0x02; 32],
+                receipt: receipt(),
+                replaced: vec![[0x01; 32]],
+            },
+            right
+        );
+    }
+
+    #[test]
+    fn settle_replaced_succeed() {
+        let input = vec![[0x01; 32]];
+
+        let right = settle(input, vec![None]);
+
+        assert_eq!(
+            Settlement::Replaced {
+                replaced: vec![[0x01; 32]]
+            },
+            right
+        );
+    }
+
+    #[test]
+    fn poll_failed_request_fail() {
+        let address: AccountAddress = TEST_SENDER.parse().unwrap();
+        let mut tracker = Tracker::new();
+        tracker.record(&signed_tx(3, 1)).unwrap();
+        tracker.record(&signed_tx(5, 1)).unwrap();
+
+        let right = tokio_test::block_on(tracker.poll(&FailingProvider));
+
+        assert!(right.is_err());
+        assert_eq!(BTreeSet::from([3, 5]), tracker.in_flight_nonces(&address));
+        assert_eq!(1, tracker.in_flight_hashes(&address, 3).len());
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    io::{Error, ErrorKind},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::Value;
+use tokio::{
+    net::TcpStream,
+    sync::{mpsc, oneshot},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+use super::{build_request, parse_response, Provider};
+use crate::evm_account::runtime;
+
+type Responder = oneshot::Sender<Result<Value, Error>>;
+type Connection = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Client of an Ethereum JSON-RPC endpoint over a WebSocket connection, e.g.:
+///
+/// ```rust,no_run
+/// use evm_signer_kms::evm_account::rpc::WsProvider;
+///
+/// # tokio_test::block_on(async {
+/// let provider = WsProvider::connect("wss://ethereum-sepolia-rpc.publicnode.com")
+///     .await
+///     .unwrap();
+/// # })
+/// ```
+///
+/// Requests are multiplexed over a single connection served by a background task, which stops
+/// once the provider is dropped or the connection is closed (see `WsProvider::is_closed`).
+#[derive(Debug)]
+pub struct WsProvider {
+    requests: mpsc::UnboundedSender<(u64, Value, Responder)>,
+    request_id: AtomicU64,
+}
+
+impl WsProvider {
+    /// Connects to the endpoint URL, i.e. `ws://` or `wss://`. Fails if there is no runtime to serve
+    /// the connection on in the background.
+    pub async fn connect(endpoint: &str) -> Result<Self, Error> {
+        let (connection, _) = connect_async(endpoint).await.map_err(|error| {
+            Error::new(
+                ErrorKind::ConnectionRefused,
+                format!("WebSocket connection failed: {}", error),
+            )
+        })?;
+        let (requests, receiver) = mpsc::unbounded_channel();
+        runtime::spawn(serve(connection, receiver))?;
+
+        Ok(Self {
+            requests,
+            request_id: AtomicU64::new(1),
+        })
+    }
+
+    /// Returns `true` if the connection is closed, in which case a new provider has to be
+    /// connected.
+    pub fn is_closed(&self) -> bool {
+        self.requests.is_closed()
+    }
+}
+
+impl Provider for WsProvider {
+    fn request_value(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> impl Future<Output = Result<Value, Error>> + Send {
+        let id = self.request_id.fetch_add(1, Ordering::Relaxed);
+        let (responder, response) = oneshot::channel();
+        let sent = self
+            .requests
+            .send((id, build_request(id, method, params), responder));
+
+        async move {
+            sent.map_err(|_| connection_closed())?;
+
+            response.await.map_err(|_| connection_closed())?
+        }
+    }
+}
+
+async fn serve(
+    mut connection: Connection,
+    mut requests: mpsc::UnboundedReceiver<(u64, Value, Responder)>,
+) {
+    let mut pending = HashMap::new();
+
+    loop {
+        tokio::select! {
+            request = requests.recv() => match request {
+                Some((id, request, responder)) => {
+                    match connection.send(Message::text(request.to_string())).await {
+                        Ok(()) => {
+                            pending.insert(id, responder);
+                        }
+                        Err(error) => {
+                            // The caller may have given up waiting for the response
+                            let _ = responder.send(Err(Error::new(
+                                ErrorKind::BrokenPipe,
+                                format!("JSON-RPC request failed: {}", error),
+                            )));
+                        }
+                    }
+                }
+                // The provider was dropped
+                None => break,
+            },
+            message = connection.next() => match message {
+                Some(Ok(Message::Text(message))) => dispatch(&message, &mut pending),
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Pings are answered by the connection itself
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    // Dropping the pending responders fails the requests awaiting them
+}
+
+// Responses are matched with the requests by ID, as the node may respond out of order.
+fn dispatch(message: &str, pending: &mut HashMap<u64, Responder>) {
+    let response: Value = match serde_json::from_str(message) {
+        Ok(response) => response,
+        Err(_) => return,
+    };
+    // Subscription notifications carry no ID
+    let responder = match response.get("id").and_then(Value::as_u64) {
+        Some(id) => pending.remove(&id),
+        None => None,
+    };
+
+    if let Some(responder) = responder {
+        // The caller may have given up waiting for the response
+        let _ = responder.send(parse_response(response));
+    }
+}
+
+fn connection_closed() -> Error {
+    Error::new(
+        ErrorKind::ConnectionAborted,
+        "WebSocket connection is closed",
+    )
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_succeed() {
+        let (responder, mut response) = oneshot::channel();
+        let mut input = HashMap::from([(7, responder)]);
+
+        dispatch(
+            r#"{"jsonrpc":"2.0","id":7,"result":"0xaa36a7"}"#,
+            &mut input,
+        );
+
+        assert!(input.is_empty());
+        assert_eq!("0xaa36a7", response.try_recv().unwrap().unwrap());
+    }
+
+    #[test]
+    fn dispatch_error_succeed() {
+        let (responder, mut response) = oneshot::channel();
+        let mut input = HashMap::from([(7, responder)]);
+
+        dispatch(
+            r#"{"jsonrpc":"2.0","id":7,"error":{"code":-32000,"message":"nonce too low"}}"#,
+            &mut input,
+        );
+
+        assert!(response.try_recv().unwrap().is_err());
+    }
+
+    #[test]
+    fn dispatch_notification_succeed() {
+        let (responder, mut response) = oneshot::channel();
+        let mut input = HashMap::from([(7, responder)]);
+
+        dispatch(
+            r#"{"jsonrpc":"2.0","method":"eth_subscription","params":{}}"#,
+            &mut input,
+        );
+
+        assert_eq!(1, input.len());
+        assert!(response.try_recv().is_err());
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(any(feature = "rpc", feature = "rate-limit"))]
+use std::time::Duration;
+#[cfg(any(
+    feature = "rpc",
+    feature = "ws",
+    feature = "notifications",
+    feature = "audit-s3"
+))]
+use std::{
+    future::Future,
+    io::{Error, ErrorKind},
+};
+
+#[cfg(not(any(feature = "rt-tokio", feature = "rt-async-std")))]
+compile_error!(
+    "The `rpc`, `rate-limit` and `audit-s3` features require either the `rt-tokio` or the `rt-async-std` feature"
+);
+
+/// Waits for the duration without blocking the thread.
+#[cfg(any(feature = "rpc", feature = "rate-limit"))]
+pub async fn sleep(duration: Duration) {
+    #[cfg(feature = "rt-tokio")]
+    tokio::time::sleep(duration).await;
+    #[cfg(not(feature = "rt-tokio"))]
+    async_std::task::sleep(duration).await;
+}
+
+/// Awaits the future, failing with `ErrorKind::TimedOut` and the message if it does not complete
+/// within the duration.
+#[cfg(feature = "rpc")]
+pub async fn timeout<F: Future>(
+    duration: Duration,
+    future: F,
+    message: &str,
+) -> Result<F::Output, Error> {
+    #[cfg(feature = "rt-tokio")]
+    let output = tokio::time::timeout(duration, future).await.ok();
+    #[cfg(not(feature = "rt-tokio"))]
+    let output = async_std::future::timeout(duration, future).await.ok();
+
+    output.ok_or_else(|| Error::new(ErrorKind::TimedOut, message))
+}
+
+/// Runs the future in the background, detached from the caller. Fails with
+/// `ErrorKind::Unsupported` if there is no runtime to run it on, i.e. with `rt-tokio` outside of a
+/// Tokio runtime (e.g. in `futures::executor::block_on`), where `tokio::spawn` would panic.
+#[cfg(any(feature = "ws", feature = "notifications", feature = "audit-s3"))]
+pub fn spawn<F>(future: F) -> Result<(), Error>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    #[cfg(feature = "rt-tokio")]
+    tokio::runtime::Handle::try_current()
+        .map_err(|_| {
+            Error::new(
+                ErrorKind::Unsupported,
+                "No Tokio runtime to spawn the task on",
+            )
+        })?
+        .spawn(future);
+    #[cfg(not(feature = "rt-tokio"))]
+    async_std::task::spawn(future);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    #[cfg(feature = "rpc")]
+    use std::future;
+    #[cfg(any(feature = "rpc", feature = "rate-limit"))]
+    use std::time::Instant;
+
+    #[cfg(any(feature = "rpc", feature = "rate-limit"))]
+    #[test]
+    fn sleep_succeed() {
+        let started = Instant::now();
+
+        tokio_test::block_on(sleep(Duration::from_millis(10)));
+
+        assert!(started.elapsed() >= Duration::from_millis(10));
+    }
+
+    #[cfg(feature = "rpc")]
+    #[test]
+    fn timeout_succeed() {
+        let right = tokio_test::block_on(timeout(
+            Duration::from_secs(1),
+            future::ready(7),
+            "Timed out",
+        ));
+
+        assert_eq!(7, right.unwrap());
+    }
+
+    #[cfg(feature = "rpc")]
+    #[test]
+    fn timeout_fail() {
+        let right = tokio_test::block_on(timeout(
+            Duration::from_millis(1),
+            future::pending::<()>(),
+            "Timed out",
+        ))
+        .unwrap_err();
+
+        assert_eq!(ErrorKind::TimedOut, right.kind());
+        assert_eq!("Timed out", right.to_string());
+    }
+
+    #[cfg(any(feature = "ws", feature = "notifications", feature = "audit-s3"))]
+    #[test]
+    fn spawn_succeed() {
+        let right = tokio_test::block_on(async { spawn(async {}) });
+
+        assert!(right.is_ok());
+    }
+
+    #[cfg(all(
+        feature = "rt-tokio",
+        any(feature = "ws", feature = "notifications", feature = "audit-s3")
+    ))]
+    #[test]
+    fn spawn_outside_runtime_fail() {
+        let right = spawn(async {}).unwrap_err();
+
+        assert_eq!(ErrorKind::Unsupported, right.kind());
+    }
+}
@@ -0,0 +1,350 @@
+use std::{
+    error,
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::{
+    ecdsa::recover_public_key,
+    hash::{address_from_public_key, Keccak256Digest},
+    transaction::{hex_quantity, AccountAddress, Bytes, U256},
+    SignatureComponent, SIGNATURE_COMPONENT_LENGTH,
+};
+
+const SIGNATURE_LENGTH: usize = 2 * SIGNATURE_COMPONENT_LENGTH + 1;
+const COMPACT_SIGNATURE_LENGTH: usize = 2 * SIGNATURE_COMPONENT_LENGTH;
+// Bit of the first byte of `s` holding the parity in the compact representation.
+const COMPACT_PARITY_BIT: u8 = 0x80;
+// Parity offset used by `ecrecover` and most signature verification APIs.
+const PARITY_OFFSET: u8 = 27;
+
+/// Recoverable ECDSA signature over secp256k1, e.g. as produced by `EvmAccount`.
+///
+/// Converts to and from the 65-byte `r || s || v` format expected by contracts (e.g. `ecrecover`)
+/// and verification APIs, where `v` is `27` or `28`:
+///
+/// ```rust
+/// use evm_signer_kms::evm_account::signature::Signature;
+///
+/// let signature = Signature::new([0x11; 32], [0x22; 32], 1);
+///
+/// assert_eq!(signature.to_bytes()[64], 28);
+/// assert_eq!(signature, signature.to_string().parse().unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Signature {
+    r: SignatureComponent,
+    s: SignatureComponent,
+    y_parity: u8,
+}
+
+impl Signature {
+    /// Creates a new signature from its components. Any non-zero `y_parity` is treated as `1`.
+    pub fn new(r: SignatureComponent, s: SignatureComponent, y_parity: u8) -> Self {
+        Self {
+            r,
+            s,
+            y_parity: u8::from(y_parity != 0),
+        }
+    }
+
+    /// Signature component `r`.
+    pub fn r(&self) -> &SignatureComponent {
+        &self.r
+    }
+
+    /// Signature component `s`.
+    pub fn s(&self) -> &SignatureComponent {
+        &self.s
+    }
+
+    /// Parity of the signature, i.e. `0` or `1`.
+    pub fn y_parity(&self) -> u8 {
+        self.y_parity
+    }
+
+    /// Parity of the signature in the `ecrecover` convention, i.e. `27` or `28`.
+    pub fn v(&self) -> u8 {
+        self.y_parity + PARITY_OFFSET
+    }
+
+    /// Returns the 65-byte `r || s || v` representation with `v` being `27` or `28`.
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_LENGTH] {
+        let mut bytes = [0u8; SIGNATURE_LENGTH];
+        bytes[..SIGNATURE_COMPONENT_LENGTH].copy_from_slice(&self.r);
+        bytes[SIGNATURE_COMPONENT_LENGTH..2 * SIGNATURE_COMPONENT_LENGTH].copy_from_slice(&self.s);
+        bytes[2 * SIGNATURE_COMPONENT_LENGTH] = self.v();
+
+        bytes
+    }
+
+    /// Returns the 64-byte `r || yParityAndS` representation of
+    /// [`EIP-2098`](https://eips.ethereum.org/EIPS/eip-2098), which stores the parity in the top
+    /// bit of `s`.
+    pub fn to_compact_bytes(&self) -> [u8; COMPACT_SIGNATURE_LENGTH] {
+        let mut bytes = [0u8; COMPACT_SIGNATURE_LENGTH];
+        bytes[..SIGNATURE_COMPONENT_LENGTH].copy_from_slice(&self.r);
+        bytes[SIGNATURE_COMPONENT_LENGTH..].copy_from_slice(&self.s);
+        if self.y_parity != 0 {
+            bytes[SIGNATURE_COMPONENT_LENGTH] |= COMPACT_PARITY_BIT;
+        }
+
+        bytes
+    }
+
+    /// Returns the components as a JSON object with hex encoded quantities, i.e. the `r`, `s`,
+    /// `v` and `yParity` fields of JSON-RPC transactions, as printed by `cast`.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "r": hex_quantity(U256::from_be_bytes(self.r)),
+            "s": hex_quantity(U256::from_be_bytes(self.s)),
+            "v": hex_quantity(self.v()),
+            "yParity": hex_quantity(self.y_parity),
+        })
+    }
+
+    /// Recovers the address of the account which signed the digest.
+    pub fn recover(&self, digest: &Keccak256Digest) -> Result<AccountAddress, Error> {
+        let public_key =
+            recover_public_key(digest, &self.r, &self.s, self.y_parity).map_err(|error| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Failed to recover signer: {}", error),
+                )
+            })?;
+
+        Ok(address_from_public_key(&public_key))
+    }
+}
+
+impl From<Signature> for [u8; SIGNATURE_LENGTH] {
+    fn from(signature: Signature) -> Self {
+        signature.to_bytes()
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = Error;
+
+    /// Parses the 65-byte `r || s || v` representation. Accepts `v` as either `0` or `1`, or `27`
+    /// or `28`.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != SIGNATURE_LENGTH {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Invalid signature length",
+            ));
+        }
+
+        let y_parity = match bytes[2 * SIGNATURE_COMPONENT_LENGTH] {
+            v @ (0 | 1) => v,
+            v @ (27 | 28) => v - PARITY_OFFSET,
+            v => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Invalid signature parity: {}", v),
+                ))
+            }
+        };
+
+        let mut r = SignatureComponent::default();
+        let mut s = SignatureComponent::default();
+        r.copy_from_slice(&bytes[..SIGNATURE_COMPONENT_LENGTH]);
+        s.copy_from_slice(&bytes[SIGNATURE_COMPONENT_LENGTH..2 * SIGNATURE_COMPONENT_LENGTH]);
+
+        Ok(Self { r, s, y_parity })
+    }
+}
+
+impl FromStr for Signature {
+    type Err = Error;
+
+    /// Parses the hex encoded 65-byte representation, optionally prefixed with `0x`.
+    fn from_str(signature: &str) -> Result<Self, Self::Err> {
+        Self::try_from(signature.parse::<Bytes>()?.as_ref())
+    }
+}
+
+impl Display for Signature {
+    /// Formats the 65-byte representation as a `0x`-prefixed hex string.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Bytes::from(self.to_bytes().as_slice()))
+    }
+}
+
+impl Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Reason for which a signature returned by KMS is rejected as not canonical, carried by the
+/// `InvalidData` error of the signing methods of `EvmAccount`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignatureError {
+    /// Component is longer than 32 bytes, after dropping its leading zero bytes.
+    ComponentTooLong,
+    /// `r` is zero.
+    ZeroR,
+    /// `s` is zero.
+    ZeroS,
+    /// `r` is not below the order of the curve.
+    ROutOfRange,
+    /// `s` is not below the order of the curve.
+    SOutOfRange,
+    /// `s` is in the upper half of the order of the curve, even after reflection.
+    HighS,
+}
+
+impl SignatureError {
+    /// Returns the signature error carried by the error returned from signing, if any.
+    pub fn from_io_error(error: &Error) -> Option<&Self> {
+        error.get_ref()?.downcast_ref()
+    }
+}
+
+impl Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureError::ComponentTooLong => {
+                write!(
+                    f,
+                    "Signature component exceeds {} bytes",
+                    SIGNATURE_COMPONENT_LENGTH
+                )
+            }
+            SignatureError::ZeroR => f.write_str("Signature `r` is zero"),
+            SignatureError::ZeroS => f.write_str("Signature `s` is zero"),
+            SignatureError::ROutOfRange => f.write_str("Signature `r` exceeds the curve order"),
+            SignatureError::SOutOfRange => f.write_str("Signature `s` exceeds the curve order"),
+            SignatureError::HighS => f.write_str("Signature `s` is not in the lower half"),
+        }
+    }
+}
+
+impl error::Error for SignatureError {}
+
+impl From<SignatureError> for Error {
+    fn from(error: SignatureError) -> Self {
+        Error::new(ErrorKind::InvalidData, error)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::signature_vector::{TEST_DIGEST, TEST_R, TEST_S, TEST_SENDER};
+
+    #[test]
+    fn to_bytes_succeed() {
+        let input = Signature::new(TEST_R, TEST_S, 1);
+
+        let right = input.to_bytes();
+
+        assert_eq!(TEST_R, right[..32]);
+        assert_eq!(TEST_S, right[32..64]);
+        assert_eq!(28, right[64]);
+    }
+
+    #[test]
+    fn try_from_bytes_y_parity_succeed() {
+        let left = Signature::new(TEST_R, TEST_S, 1);
+        let mut input = left.to_bytes();
+        input[64] = 1;
+
+        let right = Signature::try_from(input.as_slice()).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn try_from_bytes_invalid_parity_fail() {
+        let mut input = Signature::new(TEST_R, TEST_S, 0).to_bytes();
+        input[64] = 2;
+
+        assert!(Signature::try_from(input.as_slice()).is_err());
+    }
+
+    #[test]
+    fn try_from_bytes_invalid_length_fail() {
+        let input = Signature::new(TEST_R, TEST_S, 0).to_bytes();
+
+        assert!(Signature::try_from(&input[1..]).is_err());
+    }
+
+    #[test]
+    fn hex_round_trip_succeed() {
+        let left = Signature::new(TEST_R, TEST_S, 0);
+
+        let right: Signature = left.to_string().parse().unwrap();
+
+        assert_eq!(left, right);
+        assert!(left.to_string().ends_with("1b"));
+    }
+
+    #[test]
+    fn to_compact_bytes_succeed() {
+        // Second test case of EIP-2098
+        let r = hex::decode("9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76")
+            .unwrap();
+        let s = hex::decode("139c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793")
+            .unwrap();
+        let input = Signature::new(r.try_into().unwrap(), s.try_into().unwrap(), 1);
+
+        let right = input.to_compact_bytes();
+
+        assert_eq!(
+            "9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76\
+             939c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793",
+            hex::encode(right)
+        );
+    }
+
+    #[test]
+    fn to_compact_bytes_even_parity_succeed() {
+        let input = Signature::new(TEST_R, TEST_S, 0);
+
+        let right = input.to_compact_bytes();
+
+        assert_eq!(TEST_R, right[..32]);
+        assert_eq!(TEST_S, right[32..]);
+    }
+
+    #[test]
+    fn to_json_succeed() {
+        let input = Signature::new([0x11; 32], [0x22; 32], 1);
+
+        let right = input.to_json();
+
+        assert_eq!(format!("0x{}", "11".repeat(32)), right["r"]);
+        assert_eq!(format!("0x{}", "22".repeat(32)), right["s"]);
+        assert_eq!("0x1c", right["v"]);
+        assert_eq!("0x1", right["yParity"]);
+    }
+
+    #[test]
+    fn recover_succeed() {
+        let input = Signature::new(TEST_R, TEST_S, 0);
+
+        let right = input.recover(&TEST_DIGEST).unwrap();
+
+        assert_eq!(TEST_SENDER, right.to_string());
+    }
+
+    #[test]
+    fn signature_error_from_io_error_succeed() {
+        let input = Error::from(SignatureError::HighS);
+
+        let right = SignatureError::from_io_error(&input);
+
+        assert_eq!(ErrorKind::InvalidData, input.kind());
+        assert_eq!(Some(&SignatureError::HighS), right);
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use super::{hash::Keccak256Digest, PublicKey, SignatureComponent};
+
+const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+// Keyed by the public key rather than the KMS key ID, as the same ID (e.g. an alias) may refer to
+// different keys in other regions or accounts
+type CacheKey = (PublicKey, Keccak256Digest);
+pub(super) type CachedSignature = (u32, SignatureComponent, SignatureComponent);
+
+/// Cache of signatures by public key and digest, which returns the signature of a digest signed
+/// within the TTL instead of calling KMS again, e.g.:
+///
+/// ```rust
+/// use std::{sync::Arc, time::Duration};
+/// use evm_signer_kms::evm_account::signature_cache::SignatureCache;
+///
+/// let signature_cache = Arc::new(
+///     SignatureCache::new(Duration::from_secs(300)).with_max_entries(1_000),
+/// );
+/// ```
+///
+/// ECDSA signatures of KMS are not deterministic, so a retried payload would otherwise be signed
+/// differently, e.g. yielding another transaction hash. With the cache, retries of the same
+/// payload within the TTL get the same signature, at no KMS cost.
+#[derive(Debug)]
+pub struct SignatureCache {
+    ttl: Duration,
+    max_entries: usize,
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    entries: HashMap<CacheKey, (CachedSignature, Instant)>,
+    // Keys in the order of insertion, which is the order of expiry as the TTL is fixed
+    expiries: VecDeque<(CacheKey, Instant)>,
+}
+
+impl SignatureCache {
+    /// Creates a cache of signatures obtained within the TTL, of up to 10000 entries.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            inner: Mutex::default(),
+        }
+    }
+
+    /// Sets the maximum number of cached signatures, above which the oldest ones are evicted.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries.max(1);
+        self
+    }
+
+    /// Time for which a signature is returned for the same public key and digest.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Maximum number of cached signatures.
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    /// Number of cached signatures, including expired ones which are not evicted yet.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Whether no signature is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(super) fn get(
+        &self,
+        public_key: &PublicKey,
+        digest: &Keccak256Digest,
+    ) -> Option<CachedSignature> {
+        self.get_at(public_key, digest, Instant::now())
+    }
+
+    pub(super) fn insert(
+        &self,
+        public_key: &PublicKey,
+        digest: &Keccak256Digest,
+        signature: CachedSignature,
+    ) {
+        self.insert_at(public_key, digest, signature, Instant::now());
+    }
+
+    fn get_at(
+        &self,
+        public_key: &PublicKey,
+        digest: &Keccak256Digest,
+        now: Instant,
+    ) -> Option<CachedSignature> {
+        let inner = self.lock();
+        let (signature, expires) = inner.entries.get(&(*public_key, *digest))?;
+
+        (*expires > now).then_some(*signature)
+    }
+
+    fn insert_at(
+        &self,
+        public_key: &PublicKey,
+        digest: &Keccak256Digest,
+        signature: CachedSignature,
+        now: Instant,
+    ) {
+        let mut inner = self.lock();
+        inner.evict(now, self.max_entries - 1);
+
+        let key = (*public_key, *digest);
+        let expires = now + self.ttl;
+        inner.entries.insert(key, (signature, expires));
+        inner.expiries.push_back((key, expires));
+    }
+
+    // Entries are only updated with the lock held, so they are consistent after a panic
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Inner {
+    // Evicts the expired entries, and the oldest ones above `max_entries`
+    fn evict(&mut self, now: Instant, max_entries: usize) {
+        while let Some((key, expires)) = self.expiries.front() {
+            if *expires > now && self.entries.len() <= max_entries {
+                break;
+            }
+            // The key may have been cached again since, with a later expiry
+            if self.entries.get(key).map(|(_, current)| current) == Some(expires) {
+                self.entries.remove(key);
+            }
+            self.expiries.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    const PUBLIC_KEY: PublicKey = [0x04; 64];
+    const SIGNATURE: CachedSignature = (1, [0x11; 32], [0x22; 32]);
+
+    #[test]
+    fn get_succeed() {
+        let input = SignatureCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+        input.insert_at(&PUBLIC_KEY, &[0xaa; 32], SIGNATURE, now);
+
+        let right = input.get_at(&PUBLIC_KEY, &[0xaa; 32], now + Duration::from_secs(59));
+
+        assert_eq!(Some(SIGNATURE), right);
+    }
+
+    #[test]
+    fn get_other_key_fail() {
+        let input = SignatureCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+        input.insert_at(&PUBLIC_KEY, &[0xaa; 32], SIGNATURE, now);
+
+        assert_eq!(None, input.get_at(&[0x05; 64], &[0xaa; 32], now));
+        assert_eq!(None, input.get_at(&PUBLIC_KEY, &[0xbb; 32], now));
+    }
+
+    #[test]
+    fn get_keys_of_same_alias_succeed() {
+        // Accounts of the same alias in two regions, sharing the cache
+        let input = SignatureCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+        let signatures = [
+            (PUBLIC_KEY, SIGNATURE),
+            ([0x05; 64], (0, [0x33; 32], [0x44; 32])),
+        ];
+        for (public_key, signature) in signatures {
+            if input.get_at(&public_key, &[0xaa; 32], now).is_none() {
+                input.insert_at(&public_key, &[0xaa; 32], signature, now);
+            }
+        }
+
+        let right = signatures.map(|(public_key, _)| input.get_at(&public_key, &[0xaa; 32], now));
+
+        assert_eq!(signatures.map(|(_, signature)| Some(signature)), right);
+    }
+
+    #[test]
+    fn get_expired_fail() {
+        let input = SignatureCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+        input.insert_at(&PUBLIC_KEY, &[0xaa; 32], SIGNATURE, now);
+
+        let right = input.get_at(&PUBLIC_KEY, &[0xaa; 32], now + Duration::from_secs(60));
+
+        assert_eq!(None, right);
+    }
+
+    #[test]
+    fn insert_evict_expired_succeed() {
+        let input = SignatureCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+        input.insert_at(&PUBLIC_KEY, &[0xaa; 32], SIGNATURE, now);
+
+        input.insert_at(
+            &PUBLIC_KEY,
+            &[0xbb; 32],
+            SIGNATURE,
+            now + Duration::from_secs(60),
+        );
+
+        assert_eq!(1, input.len());
+    }
+
+    #[test]
+    fn insert_evict_oldest_succeed() {
+        let input = SignatureCache::new(Duration::from_secs(60)).with_max_entries(2);
+        let now = Instant::now();
+        for digest in [[0xaa; 32], [0xbb; 32], [0xcc; 32]] {
+            input.insert_at(&PUBLIC_KEY, &digest, SIGNATURE, now);
+        }
+
+        assert_eq!(2, input.len());
+        assert_eq!(None, input.get_at(&PUBLIC_KEY, &[0xaa; 32], now));
+        assert_eq!(Some(SIGNATURE), input.get_at(&PUBLIC_KEY, &[0xcc; 32], now));
+    }
+
+    #[test]
+    fn insert_again_succeed() {
+        let input = SignatureCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+        input.insert_at(&PUBLIC_KEY, &[0xaa; 32], SIGNATURE, now);
+        input.insert_at(
+            &PUBLIC_KEY,
+            &[0xaa; 32],
+            SIGNATURE,
+            now + Duration::from_secs(30),
+        );
+
+        input.insert_at(
+            &PUBLIC_KEY,
+            &[0xbb; 32],
+            SIGNATURE,
+            now + Duration::from_secs(61),
+        );
+        let right = input.get_at(&PUBLIC_KEY, &[0xaa; 32], now + Duration::from_secs(61));
+
+        assert_eq!(Some(SIGNATURE), right);
+    }
+}
@@ -1,24 +1,57 @@
 use std::{
-    fmt::{Debug, Write},
+    cell::Cell,
+    convert::Infallible,
+    fmt::{self, Debug, Display, Write},
+    hash::{Hash, Hasher},
     io::{Error, ErrorKind},
+    ops::Deref,
+    str::FromStr,
     string::String,
 };
 
+use bytes::BytesMut;
 use hex;
-use rlp::{Encodable, RlpStream};
-use serde::{Deserialize, Deserializer, Serialize};
-use sha3::{Digest, Keccak256};
+use rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+use serde_json::{Map, Value};
 
 /// Implementation of access list with necessary encoding and serialization logic.
 pub mod access_list;
 /// Implementation of [`EIP-2930`](https://eips.ethereum.org/EIPS/eip-2930) (type 1) transaction.
 pub mod access_list_transaction;
+#[cfg(feature = "alloy")]
+mod alloy;
+/// Writers for batches of signed transactions, e.g. for handing off to external broadcasters.
+pub mod batch;
+#[cfg(feature = "ethers")]
+mod ethers;
+#[cfg(test)]
+mod fixtures;
 /// Implementation of [`EIP-1559`](https://eips.ethereum.org/EIPS/eip-1559) (type 2) transaction.
 pub mod free_market_transaction;
+/// JSON deserialization of transactions with strict and lenient handling of unknown fields.
+pub mod json;
 /// Implementation of the original transaction format.
 pub mod legacy_transaction;
+mod quantity;
+
+/// 256-bit unsigned integer used for wei denominated quantities (re-exported from `ethnum`).
+pub use ethnum::U256;
 
-use crate::evm_account::{Keccak256Digest, SignatureComponent};
+/// Minimum fee increase required by most nodes (e.g. geth) to replace a pending transaction with
+/// the same nonce, in percent.
+pub const MIN_FEE_BUMP_PERCENT: u32 = 10;
+
+use crate::evm_account::{
+    hash::{keccak256, Keccak256Digest},
+    policy::PolicyError,
+    signature::Signature,
+    units::{format_units, Unit},
+    SignatureComponent,
+};
 use access_list::Access;
 
 const HEX_PREFIX: &str = "0x";
@@ -28,9 +61,364 @@ const ADDRESS_LENGTH: usize = 20;
 const MAX_TX_TYPE_ID: u8 = 0x7f;
 // Lowest parity value for legacy transactions (see EIP-2).
 const LEGACY_TX_MIN_PARITY: u32 = 27;
+// Lowest parity value for legacy transactions with replay protection (see EIP-155).
+const EIP_155_MIN_PARITY: u32 = 35;
+// Fields of transaction JSON holding quantities, which are hex encoded in JSON-RPC responses.
+const JSON_QUANTITY_FIELDS: [&str; 7] = [
+    "chainId",
+    "nonce",
+    "gasLimit",
+    "gasPrice",
+    "maxFeePerGas",
+    "maxPriorityFeePerGas",
+    "value",
+];
+// Fields of transaction JSON which are named differently in JSON-RPC responses.
+const JSON_RENAMED_FIELDS: [(&str, &str); 2] = [("gasLimit", "gas"), ("data", "input")];
+// Signed transactions are followed by the `v`, `r` and `s` fields.
+const SIGNATURE_FIELD_COUNT: usize = 3;
+// Chain ID and two empty fields appended to the signed fields of EIP-155 legacy transactions
+const EIP_155_FIELD_COUNT: usize = 3;
+const PERCENT: u64 = 100;
+// Upper bound of the signature fields encoding, i.e. `v` (up to 1 + 8 bytes) and `r`, `s` (1 + 32
+// bytes each), plus the byte the list header may grow by.
+const MAX_SIGNATURE_ENCODING_LENGTH: usize = 9 + 2 * 33 + 1;
+// Upper bound of RLP list headers, i.e. the prefix and up to 8 bytes of the payload length
+const MAX_LIST_HEADER_LENGTH: usize = 9;
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+// Upper bound of the encoding of transaction fields other than the data and the access list, i.e.
+// the type identifier, list headers and up to 8 numeric or address fields of at most 33 bytes
+const MAX_FIELDS_ENCODING_LENGTH: usize = 1 + 3 * 9 + 8 * 33;
+// Upper bound of the encoding of an access list entry without storage keys, i.e. list headers and
+// the address
+const MAX_ACCESS_ENCODING_LENGTH: usize = 2 * 9 + 21;
+// Number of leading and trailing address characters shown in transaction summaries
+const SUMMARY_ADDRESS_PREFIX_LEN: usize = 6;
+const SUMMARY_ADDRESS_SUFFIX_LEN: usize = 4;
+
+/// 20-byte EVM account address.
+///
+/// Parsing from string validates the [`EIP-55`](https://eips.ethereum.org/EIPS/eip-55) checksum
+/// if the address contains uppercase characters. Displaying the address always produces the
+/// checksummed format, which is also used for serialization, e.g.:
+///
+/// ```rust
+/// use evm_signer_kms::evm_account::transaction::AccountAddress;
+///
+/// let address: AccountAddress = "0xd1220a0cf47c7b9be7a2e6ba89f429762e7b9adb".parse().unwrap();
+///
+/// assert_eq!(address.to_string(), "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountAddress(pub [u8; ADDRESS_LENGTH]);
+
+impl AccountAddress {
+    /// Returns the address as a byte slice.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; ADDRESS_LENGTH]> for AccountAddress {
+    fn from(bytes: [u8; ADDRESS_LENGTH]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<AccountAddress> for [u8; ADDRESS_LENGTH] {
+    fn from(address: AccountAddress) -> Self {
+        address.0
+    }
+}
+
+impl AsRef<[u8]> for AccountAddress {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for AccountAddress {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes
+            .try_into()
+            .map(Self)
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "Invalid address length"))
+    }
+}
+
+impl FromStr for AccountAddress {
+    type Err = Error;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        if !validate_address_checksum(address) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Invalid address checksum",
+            ));
+        }
+
+        Self::try_from(hex_data_string_to_bytes(address)?.as_slice())
+    }
+}
+
+impl TryFrom<&str> for AccountAddress {
+    type Error = Error;
+
+    fn try_from(address: &str) -> Result<Self, Self::Error> {
+        address.parse()
+    }
+}
+
+impl Display for AccountAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Never fails, as the hex encoding consists of valid characters only
+        let address_checksum =
+            compute_address_checksum(&hex::encode(self.0)).map_err(|_| fmt::Error)?;
+
+        f.write_str(&address_checksum)
+    }
+}
+
+impl Decodable for AccountAddress {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        rlp.data()?
+            .try_into()
+            .map(Self)
+            .map_err(|_| DecoderError::RlpInvalidLength)
+    }
+}
+
+impl Serialize for AccountAddress {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Arbitrary byte string, e.g. transaction data.
+///
+/// Serializes to and deserializes from a `0x`-prefixed hex string, so that it round-trips in the
+/// same format it is specified in transaction JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bytes(pub Vec<u8>);
+
+impl Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    fn from(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(bytes: Bytes) -> Self {
+        bytes.0
+    }
+}
+
+impl FromStr for Bytes {
+    type Err = Error;
+
+    /// Parses a hex string, optionally prefixed with `0x`.
+    fn from_str(hex_data: &str) -> Result<Self, Self::Err> {
+        hex_data_string_to_bytes(hex_data).map(Self)
+    }
+}
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", HEX_PREFIX, hex::encode(&self.0))
+    }
+}
+
+impl Encodable for Bytes {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.append(&self.0);
+    }
+}
+
+impl Decodable for Bytes {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        rlp.data().map(Self::from)
+    }
+}
+
+impl Serialize for Bytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(BytesVisitor)
+    }
+}
 
-/// Type alias for convenience.
-pub type AccountAddress = [u8; ADDRESS_LENGTH];
+struct BytesVisitor;
+
+impl Visitor<'_> for BytesVisitor {
+    type Value = Bytes;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a hex string")
+    }
+
+    fn visit_str<E: de::Error>(self, hex_data: &str) -> Result<Self::Value, E> {
+        // Checked before decoding, so that data over the limit is never allocated
+        let length = hex_data.trim_start_matches(HEX_PREFIX).len() / 2;
+        if let Some(max_data_length) = MAX_DATA_LENGTH.with(Cell::get) {
+            if length > max_data_length {
+                EXCEEDED_DATA_LENGTH.with(|exceeded| exceeded.set(Some(length)));
+                return Err(E::custom("Data exceeds the length limit"));
+            }
+        }
+
+        hex_data
+            .parse()
+            .map_err(|error| E::custom(format!("Failed to deserialize hex data: {}", error)))
+    }
+}
+
+thread_local! {
+    // Serde provides no way of passing context to deserialization functions, so the limit is set
+    // for the duration of deserialization with a data length limit on the current thread, along
+    // with the length of the data which exceeded it.
+    static MAX_DATA_LENGTH: Cell<Option<usize>> = const { Cell::new(None) };
+    static EXCEEDED_DATA_LENGTH: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+// Restores the previous limit when dropped, also if deserialization panics.
+struct MaxDataLengthGuard(Option<usize>);
+
+impl Drop for MaxDataLengthGuard {
+    fn drop(&mut self) {
+        MAX_DATA_LENGTH.with(|max_data_length| max_data_length.set(self.0));
+    }
+}
+
+// Runs `deserialize` rejecting `Bytes` longer than `max_data_length` before they are decoded,
+// failing with the error of `check_data_length` if they are.
+fn with_max_data_length<T>(
+    max_data_length: Option<usize>,
+    deserialize: impl FnOnce() -> Result<T, Error>,
+) -> Result<T, Error> {
+    let _guard = MaxDataLengthGuard(MAX_DATA_LENGTH.with(|limit| limit.replace(max_data_length)));
+    EXCEEDED_DATA_LENGTH.with(|exceeded| exceeded.set(None));
+
+    deserialize().map_err(|error| match EXCEEDED_DATA_LENGTH.with(Cell::take) {
+        Some(length) => check_data_length(length, max_data_length)
+            .err()
+            .unwrap_or(error),
+        None => error,
+    })
+}
+
+/// Transaction type (see [`EIP-2718`](https://eips.ethereum.org/EIPS/eip-2718)).
+///
+/// Typed transactions are prefixed with their type identifier in the range `0x00..=0x7f` when
+/// encoded, whereas legacy transactions carry no prefix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TxType {
+    /// Original transaction format with no type prefix.
+    Legacy,
+    /// Access list transaction (type 1, see [`EIP-2930`](https://eips.ethereum.org/EIPS/eip-2930)).
+    Eip2930,
+    /// Free market transaction (type 2, see [`EIP-1559`](https://eips.ethereum.org/EIPS/eip-1559)).
+    Eip1559,
+    /// Blob transaction (type 3, see [`EIP-4844`](https://eips.ethereum.org/EIPS/eip-4844)).
+    Eip4844,
+    /// Set code transaction (type 4, see [`EIP-7702`](https://eips.ethereum.org/EIPS/eip-7702)).
+    Eip7702,
+    /// Any other transaction type. Should be obtained with `TxType::try_from`, which validates the
+    /// identifier and maps the known identifiers to their variants.
+    Custom(u8),
+}
+
+impl TxType {
+    /// Returns the transaction type identifier, i.e. `0x00` for legacy transactions.
+    pub fn id(&self) -> u8 {
+        match self {
+            TxType::Legacy => 0x00,
+            TxType::Eip2930 => 0x01,
+            TxType::Eip1559 => 0x02,
+            TxType::Eip4844 => 0x03,
+            TxType::Eip7702 => 0x04,
+            TxType::Custom(id) => *id,
+        }
+    }
+
+    /// Returns the byte prefixing the transaction encoding or `None` for legacy transactions.
+    pub fn prefix(&self) -> Option<u8> {
+        match self {
+            TxType::Legacy => None,
+            tx_type => Some(tx_type.id()),
+        }
+    }
+
+    /// Detects the type of the transaction encoding (signed or unsigned), e.g. to select the type
+    /// to decode it into. Typed transaction encodings start with the type identifier, whereas
+    /// legacy transaction encodings start with an RLP list prefix (i.e. `0xc0` or above).
+    pub fn from_encoding(encoding: &[u8]) -> Self {
+        encoding
+            .first()
+            .and_then(|&id| TxType::try_from(id).ok())
+            .unwrap_or(TxType::Legacy)
+    }
+}
+
+impl TryFrom<u8> for TxType {
+    type Error = Error;
+
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        match id {
+            0x00 => Ok(TxType::Legacy),
+            0x01 => Ok(TxType::Eip2930),
+            0x02 => Ok(TxType::Eip1559),
+            0x03 => Ok(TxType::Eip4844),
+            0x04 => Ok(TxType::Eip7702),
+            id if id <= MAX_TX_TYPE_ID => Ok(TxType::Custom(id)),
+            id => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Invalid transaction type: {:#04x}", id),
+            )),
+        }
+    }
+}
+
+impl From<TxType> for u8 {
+    fn from(tx_type: TxType) -> Self {
+        tx_type.id()
+    }
+}
 
 /// Trait for all transaction types.
 ///
@@ -46,27 +434,102 @@ pub trait Transaction:
     // To satisfy ServiceFn bound required by Lambda runtime
     serde::de::DeserializeOwned + serde::ser::Serialize
 {
+    /// Type of the transaction format.
+    const TX_TYPE: TxType;
+
     fn encode(&self) -> Vec<u8>;
+
+    /// Chain ID the transaction is bound to or `None` if the transaction format carries no chain
+    /// ID (i.e. legacy transactions).
+    fn chain_id(&self) -> Option<u64>;
+
+    /// Transaction data (i.e. calldata or contract initialization code).
+    fn data(&self) -> &[u8];
+
+    /// Sequence number of the transaction sent from the account.
+    fn nonce(&self) -> u128;
+
+    /// Sets the sequence number of the transaction, e.g. to the next nonce of the account.
+    fn set_nonce(&mut self, nonce: u128);
+
+    /// Sets the maximum amount of gas the transaction is allowed to consume.
+    fn set_gas_limit(&mut self, gas_limit: u128);
+
+    /// Maximum amount of gas the transaction is allowed to consume.
+    fn gas_limit(&self) -> u128;
+
+    /// Maximum price paid per unit of gas, i.e. `gas_price` or `max_fee_per_gas` depending on the
+    /// transaction format.
+    fn max_fee_per_gas(&self) -> U256;
+
+    /// Raises all fees paid per unit of gas by `bump_percent`, rounding up, e.g. to replace a
+    /// pending transaction with the same nonce.
+    fn bump_fees(&mut self, bump_percent: u32);
+
+    /// Recipient of the transaction or `None` for contract creation.
+    fn to(&self) -> Option<AccountAddress>;
+
+    /// Sets the recipient of the transaction, e.g. once resolved from an ENS name.
+    fn set_to(&mut self, to: Option<AccountAddress>);
+
+    /// Amount of wei transferred to the recipient.
+    fn value(&self) -> U256;
+
+    /// Upper bound of the signed transaction encoding size in bytes, e.g. to enforce mempool size
+    /// limits before signing.
+    fn encoded_size_hint(&self) -> usize {
+        self.encode().len() + MAX_SIGNATURE_ENCODING_LENGTH
+    }
+
+    /// Maximum fee paid for blob gas (see [`EIP-4844`](https://eips.ethereum.org/EIPS/eip-4844)),
+    /// i.e. zero for transaction formats which carry no blobs.
+    fn max_blob_fee(&self) -> U256 {
+        U256::ZERO
+    }
+
+    /// Maximum amount of wei the transaction can deduct from the sender balance, i.e.
+    /// `value + gas_limit * max_fee_per_gas` plus the maximum blob fee.
+    ///
+    /// Fails if the amount overflows 256 bits.
+    fn max_cost(&self) -> Result<U256, Error> {
+        U256::from(self.gas_limit())
+            .checked_mul(self.max_fee_per_gas())
+            .and_then(|gas_cost| gas_cost.checked_add(self.value()))
+            .and_then(|cost| cost.checked_add(self.max_blob_fee()))
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    "Maximum transaction cost overflows 256 bits",
+                )
+            })
+    }
 }
 
 /// Representation of signed transaction.
-#[derive(Debug, PartialEq)]
+///
+/// Serializes to the `0x`-prefixed hex string of the raw transaction encoding (i.e. the format
+/// accepted by `eth_sendRawTransaction`) and deserializes from it.
+///
+/// Signed transactions are identified by their signed encoding, i.e. two signed transactions are
+/// equal (and hash equally) if their unsigned encodings and signatures match, so that they can be
+/// deduplicated in sets and maps.
+///
+/// The transaction and signature are read through accessors, as the signed encoding reuses the
+/// unsigned encoding the transaction was created with.
+#[derive(Debug)]
 pub struct SignedTransaction<T>
 where
     T: Transaction,
 {
-    /// Transaction type identifier (see [`EIP-2718`](https://eips.ethereum.org/EIPS/eip-2718)).
-    pub tx_type: u8,
-    /// Unsigned transaction body.
-    pub tx: T,
-    /// Digest of the transaction payload.
-    pub digest: Keccak256Digest,
-    /// Parity of the signature.
-    pub v: u32,
-    /// Signature component `r`, i.e. parameter on x-axis.
-    pub r: SignatureComponent,
-    /// Signature component `s`, i.e. elliptic curve point.
-    pub s: SignatureComponent,
+    tx_type: TxType,
+    tx: T,
+    digest: Keccak256Digest,
+    // Parity of the signature in the convention it was signed or decoded with (see `v`)
+    v: u64,
+    r: SignatureComponent,
+    s: SignatureComponent,
+    // Unsigned transaction encoding, so that the transaction body is not encoded again
+    encoding: Vec<u8>,
 }
 
 impl<T> SignedTransaction<T>
@@ -75,10 +538,11 @@ where
 {
     /// Creates a new signed transaction.
     ///
-    /// The unsigned transaction, transaction digest as well as the signature components are stored
-    /// as-is. The encoding is used to determine the transaction type identifier and the parity
-    /// value, depending on the transaction type i.e. `v = {27, 28}` for legacy transactions and
-    /// `v = {0, 1}` for type 1 and type 2 transactions.
+    /// The unsigned transaction, its encoding, transaction digest as well as the signature
+    /// components are stored as-is. The encoding is used to determine the transaction type
+    /// identifier and the parity value, depending on the transaction type i.e. `v = {27, 28}` for
+    /// legacy transactions and `v = {0, 1}` for type 1 and type 2 transactions. It is also reused
+    /// when encoding the signed transaction, so it has to be the encoding of `tx`.
     pub fn new(
         tx: T,
         encoding: &[u8],
@@ -87,11 +551,25 @@ where
         r: SignatureComponent,
         s: SignatureComponent,
     ) -> Self {
-        let (tx_type, v) = if encoding[0] < MAX_TX_TYPE_ID {
-            (encoding[0], v)
-        } else {
-            (0x0, v + LEGACY_TX_MIN_PARITY)
-        };
+        Self::with_encoding(tx, encoding.to_vec(), digest, v, r, s)
+    }
+
+    /// Creates a new signed transaction like `new`, taking ownership of the encoding rather than
+    /// copying it.
+    pub fn with_encoding(
+        tx: T,
+        encoding: Vec<u8>,
+        digest: Keccak256Digest,
+        v: u32,
+        r: SignatureComponent,
+        s: SignatureComponent,
+    ) -> Self {
+        let tx_type = TxType::from_encoding(&encoding);
+        let v = match tx_type {
+            TxType::Legacy => legacy_v(v),
+            _ => v,
+        }
+        .into();
 
         Self {
             tx_type,
@@ -100,27 +578,408 @@ where
             v,
             r,
             s,
+            encoding,
         }
     }
 
+    /// Creates the signed transaction from the signature of its digest, i.e. of
+    /// `keccak256(tx.encode())`, e.g. produced by a remote signing backend.
+    pub fn from_signature(tx: T, signature: &Signature) -> Self {
+        let encoding = tx.encode();
+        let digest = keccak256(&encoding);
+        let v = signature.y_parity().into();
+
+        Self::with_encoding(tx, encoding, digest, v, *signature.r(), *signature.s())
+    }
+
+    /// Transaction type identifier (see [`EIP-2718`](https://eips.ethereum.org/EIPS/eip-2718)).
+    pub fn tx_type(&self) -> TxType {
+        self.tx_type
+    }
+
+    /// Unsigned transaction body.
+    pub fn tx(&self) -> &T {
+        &self.tx
+    }
+
+    /// Returns the unsigned transaction body, e.g. to sign it again with other fees.
+    pub fn into_tx(self) -> T {
+        self.tx
+    }
+
+    /// Digest of the transaction payload.
+    pub fn digest(&self) -> &Keccak256Digest {
+        &self.digest
+    }
+
+    /// Parity of the signature.
+    ///
+    /// Holds `yParity` (i.e. `0` or `1`) for typed transactions and `27` or `28` for legacy
+    /// transactions signed by `EvmAccount`. Legacy transactions decoded from raw encodings may also
+    /// carry the [`EIP-155`](https://eips.ethereum.org/EIPS/eip-155) replay protected value
+    /// `35 + 2 * chainId + yParity`. Use `y_parity`, `legacy_v` or `eip155_v` to convert between
+    /// the conventions.
+    pub fn v(&self) -> u64 {
+        self.v
+    }
+
+    /// Signature component `r`, i.e. parameter on x-axis.
+    pub fn r(&self) -> &SignatureComponent {
+        &self.r
+    }
+
+    /// Signature component `s`, i.e. elliptic curve point.
+    pub fn s(&self) -> &SignatureComponent {
+        &self.s
+    }
+
+    /// Returns the encoding of the unsigned transaction, i.e. the payload of the digest. The digest
+    /// of a legacy transaction signed according to EIP-155 also covers the chain ID (see
+    /// `chain_id`).
+    pub fn unsigned_encoding(&self) -> &[u8] {
+        &self.encoding
+    }
+
     /// Encodes the signed transaction using RLP encoding.
+    ///
+    /// The transaction fields are copied from the unsigned encoding rather than encoded again.
     pub fn encode(&self) -> Vec<u8> {
-        let mut rlp_stream = RlpStream::new();
+        let tx_fields = self.tx_fields();
+
+        let mut buffer =
+            BytesMut::with_capacity(self.encoding.len() + MAX_SIGNATURE_ENCODING_LENGTH);
+        buffer.extend(self.tx_type.prefix());
+
+        let mut rlp_stream = RlpStream::new_with_buffer(buffer);
+        rlp_stream.begin_unbounded_list();
+        match tx_fields {
+            Some(tx_fields) => rlp_stream.append_raw(tx_fields, 1),
+            None => rlp_stream.append(&self.tx),
+        };
         rlp_stream
-            .begin_unbounded_list()
-            .append(&self.tx)
             .append(&self.v)
             .append(&self.r.as_slice())
             .append(&self.s.as_slice())
             .finalize_unbounded_list();
 
-        let mut rlp_bytes = rlp_stream.out().to_vec();
+        rlp_stream.out().into()
+    }
+
+    /// Appends the `0x` prefixed hex encoding of the signed transaction to the string, e.g. to
+    /// serialize many transactions into a reused buffer without intermediate allocations.
+    pub fn encode_hex_into(&self, output: &mut String) {
+        output
+            .reserve(HEX_PREFIX.len() + 2 * (self.encoding.len() + MAX_SIGNATURE_ENCODING_LENGTH));
+        output.push_str(HEX_PREFIX);
 
-        if self.tx_type > 0x0 {
-            rlp_bytes.insert(0, self.tx_type);
+        self.write_chunks(|chunk| {
+            for byte in chunk {
+                output.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+                output.push(char::from(HEX_DIGITS[usize::from(byte & 0x0f)]));
+            }
+            Ok::<(), Infallible>(())
+        })
+        .unwrap_or_else(|never| match never {})
+    }
+
+    /// Writes the encoding of the signed transaction to the writer, e.g. a socket or a file, without
+    /// assembling it in a buffer first.
+    pub fn write_to(&self, writer: &mut impl std::io::Write) -> Result<(), Error> {
+        self.write_chunks(|chunk| writer.write_all(chunk))
+    }
+
+    // Transaction fields which follow the list header of the unsigned encoding
+    fn tx_fields(&self) -> Option<&[u8]> {
+        let payload = match self.tx_type.prefix() {
+            Some(_) => &self.encoding[1..],
+            None => &self.encoding[..],
+        };
+
+        Rlp::new(payload)
+            .payload_info()
+            .ok()
+            .and_then(|payload_info| payload.get(payload_info.header_len..payload_info.total()))
+    }
+
+    // Passes the signed transaction encoding to `write` in chunks, i.e. the type identifier, the
+    // list header, the transaction fields copied from the unsigned encoding and the signature
+    // fields, so that it is never assembled
+    fn write_chunks<E>(&self, mut write: impl FnMut(&[u8]) -> Result<(), E>) -> Result<(), E> {
+        let tx_fields = match self.tx_fields() {
+            Some(tx_fields) => tx_fields,
+            None => return write(&self.encode()),
+        };
+        let mut signature = [0u8; MAX_SIGNATURE_ENCODING_LENGTH];
+        let signature_length = encode_signature_fields(self.v, &self.r, &self.s, &mut signature);
+        let mut header = [0u8; MAX_LIST_HEADER_LENGTH];
+        let header_length = encode_list_header(tx_fields.len() + signature_length, &mut header);
+
+        if let Some(prefix) = self.tx_type.prefix() {
+            write(&[prefix])?;
         }
+        write(&header[..header_length])?;
+        write(tx_fields)?;
+        write(&signature[..signature_length])
+    }
+
+    // Unsigned encoding and signature, which determine the signed encoding without the cost of
+    // assembling it
+    fn signed_fields(&self) -> (&[u8], u64, &SignatureComponent, &SignatureComponent) {
+        (&self.encoding, self.v, &self.r, &self.s)
+    }
 
-        rlp_bytes
+    /// Computes the transaction hash, i.e. the digest of the signed transaction encoding which
+    /// identifies the transaction on chain.
+    pub fn hash(&self) -> Keccak256Digest {
+        keccak256(self.encode())
+    }
+
+    /// Returns the signature of the transaction, e.g. to convert it to the 65-byte `r || s || v`
+    /// format.
+    pub fn signature(&self) -> Result<Signature, Error> {
+        Ok(Signature::new(self.r, self.s, self.y_parity()? as u8))
+    }
+
+    /// Recovers the address of the account which signed the transaction.
+    pub fn sender(&self) -> Result<AccountAddress, Error> {
+        self.signature()?.recover(&self.digest)
+    }
+
+    /// Returns the chain ID of the transaction, which legacy transactions only carry in the
+    /// [`EIP-155`](https://eips.ethereum.org/EIPS/eip-155) signature parity.
+    pub fn chain_id(&self) -> Option<u64> {
+        match self.tx_type {
+            TxType::Legacy => chain_id_from_v(self.v),
+            _ => self.tx.chain_id(),
+        }
+    }
+
+    /// Builds a JSON object in the shape of the `eth_getTransactionByHash` JSON-RPC response,
+    /// e.g. for logging or auditing.
+    ///
+    /// Besides the transaction fields (with quantities hex encoded), the object holds the `hash`,
+    /// the `from` address recovered from the signature, the signature components and the
+    /// `rawTransaction` encoding. Fails if the signer cannot be recovered.
+    pub fn to_json(&self) -> Result<Value, Error> {
+        let mut json = to_rpc_json(&self.tx)?;
+
+        let raw_tx = Bytes(self.encode());
+        let hash = Bytes::from(keccak256(&raw_tx).as_slice());
+        let mut fields = vec![
+            ("hash", hash.to_string()),
+            ("type", hex_quantity(self.tx_type.id())),
+            ("from", self.sender()?.to_string()),
+            ("v", hex_quantity(self.v)),
+            ("r", hex_quantity(U256::from_be_bytes(self.r))),
+            ("s", hex_quantity(U256::from_be_bytes(self.s))),
+        ];
+        match (self.tx_type, self.chain_id()) {
+            (TxType::Legacy, Some(chain_id)) => fields.push(("chainId", hex_quantity(chain_id))),
+            (TxType::Legacy, None) => {}
+            _ => fields.push(("yParity", hex_quantity(self.y_parity()?))),
+        }
+        fields.push(("rawTransaction", raw_tx.to_string()));
+
+        for (field, value) in fields {
+            json.insert(field.to_string(), value.into());
+        }
+
+        Ok(Value::Object(json))
+    }
+
+    /// Returns the signature parity as `yParity` (i.e. `0` or `1`) regardless of the convention
+    /// used for `v`. Fails if `v` is not a valid parity for the transaction type, e.g. if the
+    /// public field was set by hand.
+    pub fn y_parity(&self) -> Result<u32, Error> {
+        match self.tx_type {
+            TxType::Legacy if self.v >= u64::from(LEGACY_TX_MIN_PARITY) => normalize_v(self.v),
+            TxType::Legacy => Err(invalid_parity(self.v)),
+            _ if self.v <= 1 => Ok(self.v as u32),
+            _ => Err(invalid_parity(self.v)),
+        }
+    }
+
+    /// Returns the signature parity in the legacy convention, i.e. `27` or `28`.
+    pub fn legacy_v(&self) -> Result<u32, Error> {
+        self.y_parity().map(legacy_v)
+    }
+
+    /// Returns the signature parity in the [`EIP-155`](https://eips.ethereum.org/EIPS/eip-155)
+    /// convention for the given chain ID, i.e. `35 + 2 * chainId + yParity`.
+    pub fn eip155_v(&self, chain_id: u64) -> Result<u64, Error> {
+        self.y_parity()
+            .and_then(|y_parity| eip155_v(y_parity, chain_id))
+    }
+
+    /// Size of the signed transaction encoding in bytes.
+    pub fn size(&self) -> usize {
+        let mut size = 0;
+        self.write_chunks(|chunk| {
+            size += chunk.len();
+            Ok::<(), Infallible>(())
+        })
+        .unwrap_or_else(|never| match never {});
+
+        size
+    }
+
+    /// Maximum amount of wei the transaction can deduct from the sender balance (see
+    /// `Transaction::max_cost`), e.g. to reserve balances before broadcasting.
+    pub fn max_cost(&self) -> Result<U256, Error> {
+        self.tx.max_cost()
+    }
+
+    /// Renders a one-line human readable summary, e.g. for approval prompts and logs:
+    ///
+    /// `type-2 tx nonce 7 on chain 1: 0.01 ETH to 0xD122...9aDb, maxFee 100 gwei`
+    pub fn summary(&self) -> String {
+        let chain = self
+            .chain_id()
+            .map(|chain_id| format!(" on chain {}", chain_id))
+            .unwrap_or_default();
+        let recipient = match self.tx.to() {
+            Some(to) => format!("to {}", abbreviate_address(&to)),
+            None => "creating contract".to_string(),
+        };
+        let fee_name = match self.tx_type {
+            TxType::Legacy | TxType::Eip2930 => "gasPrice",
+            _ => "maxFee",
+        };
+
+        format!(
+            "type-{} tx nonce {}{}: {} ETH {}, {} {} {}",
+            self.tx_type.id(),
+            self.tx.nonce(),
+            chain,
+            format_units(self.tx.value(), Unit::Ether),
+            recipient,
+            fee_name,
+            format_units(self.tx.max_fee_per_gas(), Unit::Gwei),
+            Unit::Gwei,
+        )
+    }
+}
+
+impl<T> SignedTransaction<T>
+where
+    T: Transaction + Decodable,
+{
+    /// Decodes a signed transaction from its raw encoding, i.e. the output of `encode`.
+    ///
+    /// The transaction type has to match `T`. The digest is recomputed from the decoded unsigned
+    /// transaction.
+    pub fn decode(encoding: &[u8]) -> Result<Self, Error> {
+        let tx_type = TxType::from_encoding(encoding);
+        if tx_type != T::TX_TYPE {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Expected transaction type {:#04x}, got {:#04x}",
+                    T::TX_TYPE.id(),
+                    tx_type.id()
+                ),
+            ));
+        }
+
+        let payload = match tx_type.prefix() {
+            Some(_) => &encoding[1..],
+            None => encoding,
+        };
+
+        Self::decode_payload(tx_type, payload).map_err(|error| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Failed to decode signed transaction: {}", error),
+            )
+        })
+    }
+
+    fn decode_payload(tx_type: TxType, payload: &[u8]) -> Result<Self, DecoderError> {
+        let rlp = Rlp::new(payload);
+
+        // Reject trailing bytes following the RLP list
+        if rlp.payload_info()?.total() != payload.len() {
+            return Err(DecoderError::RlpInconsistentLengthAndData);
+        }
+
+        let tx_field_count = rlp
+            .item_count()?
+            .checked_sub(SIGNATURE_FIELD_COUNT)
+            .ok_or(DecoderError::RlpIncorrectListLen)?;
+
+        // The unsigned transaction is encoded as a list of its fields only
+        let mut tx_stream = RlpStream::new_list(tx_field_count);
+        for index in 0..tx_field_count {
+            tx_stream.append_raw(rlp.at(index)?.as_raw(), 1);
+        }
+        let tx = T::decode(&Rlp::new(&tx_stream.out()))?;
+        let encoding = tx.encode();
+
+        let v: u64 = rlp.val_at(tx_field_count)?;
+        let valid_v = match tx_type {
+            TxType::Legacy => v >= u64::from(LEGACY_TX_MIN_PARITY) && normalize_v(v).is_ok(),
+            _ => v <= 1,
+        };
+        if !valid_v {
+            return Err(DecoderError::Custom("Invalid signature parity"));
+        }
+
+        // EIP-155 signatures of legacy transactions also cover the chain ID and two empty fields
+        let digest = match (tx_type, chain_id_from_v(v)) {
+            (TxType::Legacy, Some(chain_id)) => {
+                let mut eip155_stream = RlpStream::new_list(tx_field_count + EIP_155_FIELD_COUNT);
+                for index in 0..tx_field_count {
+                    eip155_stream.append_raw(rlp.at(index)?.as_raw(), 1);
+                }
+                eip155_stream
+                    .append(&chain_id)
+                    .append_empty_data()
+                    .append_empty_data();
+
+                keccak256(eip155_stream.out())
+            }
+            _ => keccak256(&encoding),
+        };
+
+        Ok(Self {
+            tx_type,
+            tx,
+            digest,
+            v,
+            r: decode_signature_component(&rlp.at(tx_field_count + 1)?)?,
+            s: decode_signature_component(&rlp.at(tx_field_count + 2)?)?,
+            encoding,
+        })
+    }
+}
+
+impl<T> Display for SignedTransaction<T>
+where
+    T: Transaction,
+{
+    /// Formats the summary of the transaction (see `summary`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.summary())
+    }
+}
+
+impl<T> PartialEq for SignedTransaction<T>
+where
+    T: Transaction,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.signed_fields() == other.signed_fields()
+    }
+}
+
+impl<T> Eq for SignedTransaction<T> where T: Transaction {}
+
+impl<T> Hash for SignedTransaction<T>
+where
+    T: Transaction,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.signed_fields().hash(state);
     }
 }
 
@@ -142,38 +1001,217 @@ where
     }
 }
 
-fn hex_data_string_to_bytes(hex_data: &str) -> Result<Vec<u8>, Error> {
-    const STEP_BY: usize = 2;
+impl<'de, T> Deserialize<'de> for SignedTransaction<T>
+where
+    T: Transaction + Decodable,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoding = Bytes::deserialize(deserializer)?;
+
+        Self::decode(&encoding).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Converts the signature parity `v` in any of the conventions to `yParity` (i.e. `0` or `1`).
+///
+/// Accepts `yParity` itself, the legacy `27` or `28`, and the
+/// [`EIP-155`](https://eips.ethereum.org/EIPS/eip-155) `35 + 2 * chainId + yParity`.
+pub fn normalize_v(v: u64) -> Result<u32, Error> {
+    let legacy_min_parity = u64::from(LEGACY_TX_MIN_PARITY);
+    let eip155_min_parity = u64::from(EIP_155_MIN_PARITY);
+
+    match v {
+        0 | 1 => Ok(v as u32),
+        v if v >= eip155_min_parity => Ok(((v - eip155_min_parity) % 2) as u32),
+        v if v == legacy_min_parity || v == legacy_min_parity + 1 => {
+            Ok((v - legacy_min_parity) as u32)
+        }
+        v => Err(invalid_parity(v)),
+    }
+}
+
+fn invalid_parity(v: u64) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("Invalid signature parity: {}", v),
+    )
+}
+
+/// Converts `yParity` to the legacy signature parity, i.e. `27` or `28`.
+pub fn legacy_v(y_parity: u32) -> u32 {
+    y_parity + LEGACY_TX_MIN_PARITY
+}
+
+/// Converts `yParity` to the [`EIP-155`](https://eips.ethereum.org/EIPS/eip-155) signature parity
+/// of the given chain, i.e. `35 + 2 * chainId + yParity`.
+///
+/// Fails if the parity of the chain ID does not fit 64 bits.
+pub fn eip155_v(y_parity: u32, chain_id: u64) -> Result<u64, Error> {
+    chain_id
+        .checked_mul(2)
+        .and_then(|v| v.checked_add(u64::from(EIP_155_MIN_PARITY)))
+        .and_then(|v| v.checked_add(u64::from(y_parity)))
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("Chain ID {} is too large for an EIP-155 parity", chain_id),
+            )
+        })
+}
+
+/// Extracts the chain ID from an [`EIP-155`](https://eips.ethereum.org/EIPS/eip-155) signature
+/// parity or returns `None` if `v` is not in the EIP-155 convention.
+pub fn chain_id_from_v(v: u64) -> Option<u64> {
+    v.checked_sub(EIP_155_MIN_PARITY.into()).map(|v| v / 2)
+}
+
+/// Decodes the raw signed transaction of any supported type into the JSON object of
+/// `SignedTransaction::to_json`, i.e. in the shape Foundry's `cast` prints transactions with
+/// `--json`.
+pub fn decode_to_json(encoding: &[u8]) -> Result<Value, Error> {
+    match TxType::from_encoding(encoding) {
+        TxType::Legacy => {
+            SignedTransaction::<legacy_transaction::LegacyTransaction>::decode(encoding)?.to_json()
+        }
+        TxType::Eip2930 => {
+            SignedTransaction::<access_list_transaction::AccessListTransaction>::decode(encoding)?
+                .to_json()
+        }
+        TxType::Eip1559 => {
+            SignedTransaction::<free_market_transaction::FreeMarketTransaction>::decode(encoding)?
+                .to_json()
+        }
+        tx_type => Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("Unsupported transaction type: {:#04x}", tx_type.id()),
+        )),
+    }
+}
+
+/// Raises the fee by `bump_percent`, rounding up so that the increase is never below the
+/// percentage.
+pub(crate) fn bump_fee(fee: U256, bump_percent: u32) -> U256 {
+    let bumped = fee.saturating_mul(U256::from(PERCENT + u64::from(bump_percent)));
+    let percent = U256::from(PERCENT);
+
+    if bumped % percent == U256::ZERO {
+        bumped / percent
+    } else {
+        bumped / percent + U256::ONE
+    }
+}
+
+// Shortens the checksummed address to its leading and trailing characters, e.g. `0xD122...9aDb`.
+fn abbreviate_address(address: &AccountAddress) -> String {
+    let address = address.to_string();
+
+    format!(
+        "{}...{}",
+        &address[..SUMMARY_ADDRESS_PREFIX_LEN],
+        &address[address.len() - SUMMARY_ADDRESS_SUFFIX_LEN..]
+    )
+}
+
+/// Converts the transaction to a JSON object with the field names and hex encoded quantities used
+/// by the JSON-RPC API.
+pub(crate) fn to_rpc_json<T: Transaction>(tx: &T) -> Result<Map<String, Value>, Error> {
+    let mut json = match serde_json::to_value(tx) {
+        Ok(Value::Object(json)) => json,
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Transaction does not serialize to a JSON object",
+            ))
+        }
+    };
+
+    for field in JSON_QUANTITY_FIELDS {
+        if let Some(value) = json.get_mut(field) {
+            *value = to_hex_quantity(value)?;
+        }
+    }
+    for (field, rpc_field) in JSON_RENAMED_FIELDS {
+        if let Some(value) = json.remove(field) {
+            json.insert(rpc_field.to_string(), value);
+        }
+    }
+
+    Ok(json)
+}
 
+// Converts a quantity serialized as a JSON number or a decimal string to a hex string.
+fn to_hex_quantity(value: &Value) -> Result<Value, Error> {
+    let quantity = match value {
+        Value::Number(number) => number.as_u64().map(U256::from),
+        Value::String(string) => U256::from_str_radix(string, 10).ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Invalid quantity: {}", value),
+        )
+    })?;
+
+    Ok(hex_quantity(quantity).into())
+}
+
+pub(crate) fn hex_quantity(quantity: impl fmt::LowerHex) -> String {
+    format!("{}{:x}", HEX_PREFIX, quantity)
+}
+
+fn decode_signature_component(rlp: &Rlp) -> Result<SignatureComponent, DecoderError> {
+    let bytes = rlp.data()?;
+    let mut component = SignatureComponent::default();
+
+    if bytes.len() > component.len() {
+        return Err(DecoderError::RlpIsTooBig);
+    }
+
+    // Components are left-padded with zeros if encoded as integers
+    let offset = component.len() - bytes.len();
+    component[offset..].copy_from_slice(bytes);
+
+    Ok(component)
+}
+
+fn decode_address_option(rlp: &Rlp) -> Result<Option<AccountAddress>, DecoderError> {
+    // Empty address denotes contract deployment
+    match rlp.data()? {
+        [] => Ok(None),
+        _ => AccountAddress::decode(rlp).map(Some),
+    }
+}
+
+fn hex_data_string_to_bytes(hex_data: &str) -> Result<Vec<u8>, Error> {
     let hex_data = hex_data.trim_start_matches(HEX_PREFIX);
 
-    (0..hex_data.len())
-        .step_by(STEP_BY)
-        .map(|i| {
-            u8::from_str_radix(&hex_data[i..i + STEP_BY], HEX_RADIX)
-                .map_err(|error| Error::new(ErrorKind::InvalidData, error))
-        })
-        .collect()
+    // Rejects odd length and non-hex characters without panicking on multi-byte characters
+    hex::decode(hex_data).map_err(|error| Error::new(ErrorKind::InvalidData, error))
 }
 
-fn deserialize_hex_data_string<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let hex_string = String::deserialize(deserializer)?;
-
-    hex_data_string_to_bytes(&hex_string).map_err(|error| {
-        serde::de::Error::custom(format!("Failed to deserialize hex data: {}", error))
-    })
+pub(crate) fn check_data_length(
+    length: usize,
+    max_data_length: Option<usize>,
+) -> Result<(), Error> {
+    match max_data_length {
+        Some(max_data_length) if length > max_data_length => Err(PolicyError::DataTooLong {
+            length,
+            max_data_length,
+        }
+        .into()),
+        _ => Ok(()),
+    }
 }
 
 fn compute_address_checksum(address: &str) -> Result<String, Error> {
     let address_ascii_lowercase = address.trim_start_matches(HEX_PREFIX).to_ascii_lowercase();
 
     // Compute the hash of the address and represent it as a string of hex digits
-    let mut hasher = Keccak256::new();
-    hasher.update(address_ascii_lowercase.clone());
-    let hex_hash = hex::encode(hasher.finalize());
+    let hex_hash = hex::encode(keccak256(&address_ascii_lowercase));
 
     // Construct the checksummed address prefixed with '0x'
     let mut address_checksum = HEX_PREFIX.to_string();
@@ -227,37 +1265,104 @@ fn deserialize_address_string_option<'de, D>(
 where
     D: Deserializer<'de>,
 {
-    let address_string = String::deserialize(deserializer)?;
+    // Empty or null address denotes contract deployment
+    let address_string = match Option::<String>::deserialize(deserializer)? {
+        Some(address_string) if !address_string.trim_start_matches(HEX_PREFIX).is_empty() => {
+            address_string
+        }
+        _ => return Ok(None),
+    };
+
+    address_string.parse().map(Some).map_err(|error| {
+        serde::de::Error::custom(format!("Failed to deserialize address: {}", error))
+    })
+}
 
-    if !validate_address_checksum(&address_string) {
-        return Err(serde::de::Error::custom("Invalid address checksum"));
+// Encodes `v` as an RLP integer and `r` and `s` as RLP strings into the buffer, like `RlpStream`
+// does, and returns the length of the encoding
+fn encode_signature_fields(
+    v: u64,
+    r: &SignatureComponent,
+    s: &SignatureComponent,
+    buffer: &mut [u8; MAX_SIGNATURE_ENCODING_LENGTH],
+) -> usize {
+    let v_bytes = v.to_be_bytes();
+    let v_bytes = &v_bytes[v.leading_zeros() as usize / 8..];
+    let mut length = match v_bytes {
+        [byte] if *byte < 0x80 => {
+            buffer[0] = *byte;
+            1
+        }
+        _ => {
+            buffer[0] = 0x80 + v_bytes.len() as u8;
+            buffer[1..=v_bytes.len()].copy_from_slice(v_bytes);
+            1 + v_bytes.len()
+        }
+    };
+    for component in [r, s] {
+        buffer[length] = 0x80 + component.len() as u8;
+        buffer[length + 1..length + 1 + component.len()].copy_from_slice(component);
+        length += 1 + component.len();
     }
 
-    let address_bytes = hex_data_string_to_bytes(&address_string).map_err(|error| {
-        serde::de::Error::custom(format!("Failed to deserialize address: {}", error))
-    })?;
+    length
+}
 
-    if address_bytes.is_empty() {
-        return Ok(None);
+// Encodes the RLP header of a list with the payload length into the buffer and returns its length
+fn encode_list_header(payload_length: usize, buffer: &mut [u8; MAX_LIST_HEADER_LENGTH]) -> usize {
+    if payload_length < 56 {
+        buffer[0] = 0xc0 + payload_length as u8;
+        return 1;
     }
 
-    let address = address_bytes
-        // Checks whether address is of proper length
-        .try_into()
-        .map_err(|_| serde::de::Error::custom("Invalid address length"))?;
+    let payload_length = payload_length as u64;
+    let length_bytes = payload_length.to_be_bytes();
+    let length_bytes = &length_bytes[payload_length.leading_zeros() as usize / 8..];
+    buffer[0] = 0xf7 + length_bytes.len() as u8;
+    buffer[1..=length_bytes.len()].copy_from_slice(length_bytes);
+
+    1 + length_bytes.len()
+}
+
+/// Encodes the transaction as an RLP list, preceded by the identifier of typed transactions, into a
+/// buffer pre-sized with `encoding_capacity`, so that it is neither reallocated nor copied.
+pub(crate) fn encode_rlp_list<T: Encodable>(tx_type: TxType, tx: &T, capacity: usize) -> Vec<u8> {
+    let mut buffer = BytesMut::with_capacity(capacity);
+    buffer.extend(tx_type.prefix());
+
+    let mut rlp_stream = RlpStream::new_with_buffer(buffer);
+    rlp_stream
+        .begin_unbounded_list()
+        .append(tx)
+        .finalize_unbounded_list();
+
+    rlp_stream.out().into()
+}
+
+/// Upper bound of the unsigned encoding size of a transaction with the data and the access list.
+pub(crate) fn encoding_capacity(data: &[u8], access_list: &[Access]) -> usize {
+    let access_list_length: usize = access_list
+        .iter()
+        .map(|access| MAX_ACCESS_ENCODING_LENGTH + access.storage_keys.len() * 33)
+        .sum();
 
-    Ok(Some(address))
+    MAX_FIELDS_ENCODING_LENGTH + data.len() + access_list_length
 }
 
 #[cfg(test)]
 mod unit_tests {
     use super::*;
+    use crate::evm_account::signature_vector::{TEST_DIGEST, TEST_R, TEST_S, TEST_SENDER};
+    use fixtures::{free_market_tx, legacy_tx, signed_tx};
+    use free_market_transaction::FreeMarketTransaction;
+    use legacy_transaction::LegacyTransaction;
+    use std::collections::HashSet;
 
     const TEST_ADDR_STR_1: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
     const TEST_ADDR_STR_2: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
     const TEST_ADDR_STR_3: &str = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
 
-    const TEST_ADDR_BYTES: AccountAddress = [
+    const TEST_ADDR_BYTES: [u8; ADDRESS_LENGTH] = [
         0xa9, 0xd8, 0x91, 0x86, 0xca, 0xa6, 0x63, 0xc8, 0xef, 0x03, 0x52, 0xfd, 0x1d, 0xb3, 0x59,
         0x62, 0x80, 0x62, 0x55, 0x73,
     ];
@@ -272,6 +1377,514 @@ mod unit_tests {
         assert_eq!(left, right);
     }
 
+    #[test]
+    fn address_from_str_succeed() {
+        let left = AccountAddress(TEST_ADDR_BYTES);
+
+        let right: AccountAddress = TEST_ADDR_STR_1.parse().unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn address_from_str_invalid_checksum_fail() {
+        let input = "0xA9d89186caA663C8Ef0352Fd1Db3596280625573";
+
+        assert!(input.parse::<AccountAddress>().is_err());
+    }
+
+    #[test]
+    fn address_from_str_invalid_length_fail() {
+        let input = &TEST_ADDR_STR_1.to_ascii_lowercase()[..41];
+
+        assert!(AccountAddress::try_from(input).is_err());
+    }
+
+    #[test]
+    fn address_display_checksum_succeed() {
+        let input = AccountAddress(TEST_ADDR_BYTES);
+
+        assert_eq!(TEST_ADDR_STR_1, input.to_string());
+    }
+
+    #[test]
+    fn address_try_from_slice_fail() {
+        let input = &TEST_ADDR_BYTES[1..];
+
+        assert!(AccountAddress::try_from(input).is_err());
+    }
+
+    #[test]
+    fn address_serialize_checksum_succeed() {
+        let input = AccountAddress(TEST_ADDR_BYTES);
+
+        let right = serde_json::to_string(&input).unwrap();
+
+        assert_eq!(format!("\"{}\"", TEST_ADDR_STR_1), right);
+    }
+
+    #[test]
+    fn bytes_serialize_hex_succeed() {
+        let input = Bytes(vec![0xab, 0xcd]);
+
+        assert_eq!("\"0xabcd\"", serde_json::to_string(&input).unwrap());
+    }
+
+    #[test]
+    fn bytes_round_trip_succeed() {
+        let left = Bytes(vec![0xab, 0xcd]);
+
+        let right: Bytes = serde_json::from_str(&serde_json::to_string(&left).unwrap()).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn bytes_from_str_odd_length_fail() {
+        assert!("0xabc".parse::<Bytes>().is_err());
+    }
+
+    #[test]
+    fn tx_type_try_from_known_id_succeed() {
+        assert_eq!(TxType::Legacy, TxType::try_from(0x00).unwrap());
+        assert_eq!(TxType::Eip1559, TxType::try_from(0x02).unwrap());
+        assert_eq!(TxType::Eip7702, TxType::try_from(0x04).unwrap());
+    }
+
+    #[test]
+    fn tx_type_try_from_custom_id_succeed() {
+        let left = TxType::Custom(0x7e);
+
+        let right = TxType::try_from(0x7e).unwrap();
+
+        assert_eq!(left, right);
+        assert_eq!(0x7e, u8::from(right));
+    }
+
+    #[test]
+    fn tx_type_try_from_invalid_id_fail() {
+        assert!(TxType::try_from(0x80).is_err());
+        assert!(TxType::try_from(0xc0).is_err());
+    }
+
+    #[test]
+    fn tx_type_from_encoding_succeed() {
+        assert_eq!(TxType::Eip2930, TxType::from_encoding(&[0x01, 0xc0]));
+        assert_eq!(TxType::Legacy, TxType::from_encoding(&[0xc0]));
+        assert_eq!(TxType::Legacy, TxType::from_encoding(&[]));
+    }
+
+    #[test]
+    fn tx_type_prefix_succeed() {
+        assert_eq!(None, TxType::Legacy.prefix());
+        assert_eq!(Some(0x02), TxType::Eip1559.prefix());
+    }
+
+    #[test]
+    fn signed_tx_decode_legacy_succeed() {
+        let left = signed_tx(legacy_tx(), 1);
+
+        let right = SignedTransaction::decode(&left.encode()).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn signed_tx_deserialize_free_market_succeed() {
+        let left = signed_tx(free_market_tx(), 1);
+
+        let right: SignedTransaction<FreeMarketTransaction> =
+            serde_json::from_str(&serde_json::to_string(&left).unwrap()).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn signed_tx_encode_matches_tx_succeed() {
+        let input = signed_tx(free_market_tx(), 1);
+        let mut rlp_stream = RlpStream::new();
+        rlp_stream
+            .begin_unbounded_list()
+            .append(&input.tx)
+            .append(&input.v)
+            .append(&input.r.as_slice())
+            .append(&input.s.as_slice())
+            .finalize_unbounded_list();
+        let mut left = vec![TxType::Eip1559.id()];
+        left.extend_from_slice(&rlp_stream.out());
+
+        let right = input.encode();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn encode_hex_into_succeed() {
+        let mut input = legacy_tx();
+        input.data = Bytes(vec![0xab; 1024]);
+        let mut right = "prefix:".to_string();
+
+        for input in [signed_tx(input, 1), signed_tx(legacy_tx(), 1)] {
+            right.truncate("prefix:".len());
+            input.encode_hex_into(&mut right);
+
+            assert_eq!(format!("prefix:{}", Bytes(input.encode())), right);
+            assert_eq!(input.encode().len(), input.size());
+        }
+    }
+
+    #[test]
+    fn write_to_succeed() {
+        let input = signed_tx(free_market_tx(), 1);
+        let mut right = Vec::new();
+
+        input.write_to(&mut right).unwrap();
+
+        assert_eq!(input.encode(), right);
+    }
+
+    #[test]
+    fn encode_signature_fields_succeed() {
+        let mut rlp_stream = RlpStream::new();
+        rlp_stream
+            .append(&0u32)
+            .append(&[0x11u8; 32].as_slice())
+            .append(&[0x22u8; 32].as_slice());
+        let left = rlp_stream.out().to_vec();
+
+        let mut right = [0u8; MAX_SIGNATURE_ENCODING_LENGTH];
+        let right_length = encode_signature_fields(0, &[0x11; 32], &[0x22; 32], &mut right);
+
+        assert_eq!(left, right[..right_length]);
+        assert_eq!(
+            1,
+            encode_signature_fields(0x7f, &[0; 32], &[0; 32], &mut right) - 66
+        );
+        assert_eq!(
+            2,
+            encode_signature_fields(0x80, &[0; 32], &[0; 32], &mut right) - 66
+        );
+        assert_eq!(
+            5,
+            encode_signature_fields(u32::MAX.into(), &[0; 32], &[0; 32], &mut right) - 66
+        );
+        assert_eq!(
+            9,
+            encode_signature_fields(u64::MAX, &[0; 32], &[0; 32], &mut right) - 66
+        );
+    }
+
+    #[test]
+    fn signed_tx_decode_type_mismatch_fail() {
+        let input = signed_tx(legacy_tx(), 1).encode();
+
+        let error = SignedTransaction::<FreeMarketTransaction>::decode(&input).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn signed_tx_decode_trailing_bytes_fail() {
+        let mut input = signed_tx(free_market_tx(), 1).encode();
+        input.push(0x00);
+
+        assert!(SignedTransaction::<FreeMarketTransaction>::decode(&input).is_err());
+    }
+
+    #[test]
+    fn signed_tx_deduplicate_succeed() {
+        let input = [
+            signed_tx(free_market_tx(), 1),
+            signed_tx(free_market_tx(), 1),
+            SignedTransaction::decode(&signed_tx(free_market_tx(), 1).encode()).unwrap(),
+        ];
+
+        let right: HashSet<_> = input.into_iter().collect();
+
+        assert_eq!(1, right.len());
+    }
+
+    #[test]
+    fn signed_tx_different_signature_not_equal_succeed() {
+        let left = signed_tx(free_market_tx(), 1);
+        let mut right = signed_tx(free_market_tx(), 1);
+        right.s = [0x33; 32];
+
+        assert_ne!(left, right);
+    }
+
+    #[test]
+    fn encoded_size_hint_legacy_succeed() {
+        let input = legacy_tx();
+        let left = input.encoded_size_hint();
+
+        let right = SignedTransaction::new(
+            input,
+            &legacy_tx().encode(),
+            [0; 32],
+            1,
+            [0xff; 32],
+            [0xff; 32],
+        );
+
+        assert_eq!(right.size(), right.encode().len());
+        assert!(left >= right.size());
+    }
+
+    #[test]
+    fn encoded_size_hint_large_data_succeed() {
+        let mut input = free_market_tx();
+        input.data = Bytes(vec![0xab; 128 * 1024]);
+        let left = input.encoded_size_hint();
+
+        let right = signed_tx(input, 1);
+
+        assert!(left >= right.size());
+        assert!(left - right.size() < MAX_SIGNATURE_ENCODING_LENGTH);
+    }
+
+    #[test]
+    fn encoding_capacity_succeed() {
+        let mut input = free_market_tx();
+        input.data = Bytes(vec![0xab; 1024]);
+        input.access_list[0]
+            .storage_keys
+            .push(access_list::StorageKey::from_slot(8));
+
+        let right = encoding_capacity(&input.data, &input.access_list);
+
+        assert!(input.encode().len() <= right);
+    }
+
+    #[test]
+    fn bump_fee_succeed() {
+        assert_eq!(U256::new(110), bump_fee(U256::new(100), 10));
+        assert_eq!(U256::new(13), bump_fee(U256::new(11), 10));
+        assert_eq!(U256::ZERO, bump_fee(U256::ZERO, 10));
+    }
+
+    #[test]
+    fn bump_fees_free_market_succeed() {
+        let mut input = free_market_tx();
+
+        input.bump_fees(MIN_FEE_BUMP_PERCENT);
+
+        assert_eq!(U256::new(110_000_000_000), input.max_fee_per_gas);
+        assert_eq!(U256::new(3_300_000_000), input.max_priority_fee_per_gas);
+    }
+
+    #[test]
+    fn signed_tx_max_cost_succeed() {
+        // 0.01 ETH + 21000 * 100 gwei
+        let left = U256::new(12_100_000_000_000_000);
+
+        let right = signed_tx(legacy_tx(), 1).max_cost().unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn max_cost_overflow_fail() {
+        let mut input = free_market_tx();
+        input.max_fee_per_gas = U256::MAX;
+
+        let error = input.max_cost().unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn signed_tx_summary_legacy_succeed() {
+        let left = "type-0 tx nonce 5: 0.01 ETH to 0xa9d8...5573, gasPrice 100 gwei";
+
+        let right = signed_tx(legacy_tx(), 1).summary();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn signed_tx_display_contract_creation_succeed() {
+        let left = "type-2 tx nonce 0 on chain 11155111: 0 ETH creating contract, maxFee 100 gwei";
+
+        let right = signed_tx(free_market_tx(), 1).to_string();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn signed_tx_decode_missing_signature_fail() {
+        let input = free_market_tx().encode();
+
+        assert!(SignedTransaction::<FreeMarketTransaction>::decode(&input).is_err());
+    }
+
+    #[test]
+    fn signed_tx_sender_legacy_succeed() {
+        let tx = legacy_tx();
+        let encoding = tx.encode();
+        let input = SignedTransaction::new(tx, &encoding, TEST_DIGEST, 0, TEST_R, TEST_S);
+
+        assert_eq!(TEST_SENDER, input.sender().unwrap().to_string());
+    }
+
+    // Example transaction of EIP-155, signed on chain 1 with the private key `0x4646...46`
+    const TEST_EIP_155_TX: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+    const TEST_EIP_155_DIGEST: &str =
+        "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53";
+    const TEST_EIP_155_SENDER: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+
+    #[test]
+    fn signed_tx_decode_eip155_succeed() {
+        let input = hex::decode(TEST_EIP_155_TX).unwrap();
+
+        let right = SignedTransaction::<LegacyTransaction>::decode(&input).unwrap();
+
+        assert_eq!(TEST_EIP_155_DIGEST, hex::encode(right.digest));
+        assert_eq!(Some(1), right.chain_id());
+        assert_eq!(TEST_EIP_155_SENDER, right.sender().unwrap().to_string());
+        assert_eq!(input, right.encode());
+    }
+
+    #[test]
+    fn decode_to_json_eip155_succeed() {
+        let input = hex::decode(TEST_EIP_155_TX).unwrap();
+
+        let right = decode_to_json(&input).unwrap();
+
+        assert_eq!(TEST_EIP_155_SENDER, right["from"]);
+        assert_eq!("0x1", right["chainId"]);
+        assert_eq!("0x25", right["v"]);
+    }
+
+    #[test]
+    fn signed_tx_to_json_succeed() {
+        let tx = free_market_tx();
+        let encoding = tx.encode();
+        let input = SignedTransaction::new(tx, &encoding, TEST_DIGEST, 0, TEST_R, TEST_S);
+
+        let right = input.to_json().unwrap();
+
+        assert_eq!(right["type"], "0x2");
+        assert_eq!(right["from"], TEST_SENDER);
+        assert_eq!(right["chainId"], "0xaa36a7");
+        assert_eq!(right["gas"], "0x5208");
+        assert_eq!(right["maxFeePerGas"], "0x174876e800");
+        assert_eq!(right["value"], "0x0");
+        assert_eq!(right["input"], "0xabcd");
+        assert_eq!(right["to"], Value::Null);
+        assert_eq!(right["yParity"], "0x0");
+        assert_eq!(
+            right["hash"],
+            Bytes(keccak256(input.encode()).to_vec()).to_string()
+        );
+        assert_eq!(right["rawTransaction"], Bytes(input.encode()).to_string());
+        assert!(right.get("gasLimit").is_none());
+    }
+
+    #[test]
+    fn signed_tx_from_signature_succeed() {
+        let encoding = legacy_tx().encode();
+        let left = SignedTransaction::new(legacy_tx(), &encoding, TEST_DIGEST, 1, TEST_R, TEST_S);
+
+        let right =
+            SignedTransaction::from_signature(legacy_tx(), &Signature::new(TEST_R, TEST_S, 1));
+
+        assert_eq!(left.encode(), right.encode());
+        assert_eq!(keccak256(&encoding), right.digest);
+    }
+
+    #[test]
+    fn decode_to_json_succeed() {
+        let tx = free_market_tx();
+        let encoding = tx.encode();
+        let left = SignedTransaction::new(tx, &encoding, keccak256(&encoding), 0, TEST_R, TEST_S);
+
+        let right = decode_to_json(&left.encode()).unwrap();
+
+        assert_eq!(left.to_json().unwrap(), right);
+    }
+
+    #[test]
+    fn decode_to_json_unsupported_type_fail() {
+        let input = [0x04, 0xc0];
+
+        let error = decode_to_json(&input).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn normalize_v_succeed() {
+        assert_eq!(0, normalize_v(0).unwrap());
+        assert_eq!(1, normalize_v(28).unwrap());
+        assert_eq!(0, normalize_v(eip155_v(0, 11155111).unwrap()).unwrap());
+        assert_eq!(1, normalize_v(eip155_v(1, 1).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn normalize_v_invalid_fail() {
+        assert!(normalize_v(2).is_err());
+        assert!(normalize_v(29).is_err());
+    }
+
+    #[test]
+    fn chain_id_from_v_succeed() {
+        assert_eq!(
+            Some(11155111),
+            chain_id_from_v(eip155_v(1, 11155111).unwrap())
+        );
+        assert_eq!(None, chain_id_from_v(27));
+    }
+
+    #[test]
+    fn eip155_v_overflow_fail() {
+        assert_eq!(u64::MAX, eip155_v(0, (u64::MAX - 35) / 2).unwrap());
+        assert_eq!(
+            ErrorKind::InvalidInput,
+            eip155_v(1, (u64::MAX - 35) / 2).unwrap_err().kind()
+        );
+        assert!(eip155_v(0, u64::MAX / 2 + 1).is_err());
+    }
+
+    #[test]
+    fn signed_tx_invalid_parity_fail() {
+        let mut input = signed_tx(legacy_tx(), 1);
+        input.v = 2;
+
+        assert_eq!(ErrorKind::InvalidData, input.y_parity().unwrap_err().kind());
+        assert!(input.legacy_v().is_err());
+        assert!(input.sender().is_err());
+    }
+
+    #[test]
+    fn signed_tx_parity_conventions_succeed() {
+        let input = signed_tx(legacy_tx(), 1);
+
+        assert_eq!(28, input.v);
+        assert_eq!(1, input.y_parity().unwrap());
+        assert_eq!(28, input.legacy_v().unwrap());
+        assert_eq!(38, input.eip155_v(1).unwrap());
+    }
+
+    #[test]
+    fn signed_tx_signature_succeed() {
+        let input = signed_tx(legacy_tx(), 1);
+
+        let right = input.signature().unwrap().to_bytes();
+
+        assert_eq!([0x11; 32], right[..32]);
+        assert_eq!([0x22; 32], right[32..64]);
+        assert_eq!(28, right[64]);
+    }
+
+    #[test]
+    fn signed_tx_decode_invalid_parity_fail() {
+        let mut input = signed_tx(free_market_tx(), 1);
+        input.v = 27;
+
+        assert!(SignedTransaction::<FreeMarketTransaction>::decode(&input.encode()).is_err());
+    }
+
     #[test]
     fn validate_recipient_address_test_1_succeed() {
         let input = TEST_ADDR_STR_1;
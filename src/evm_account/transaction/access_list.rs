@@ -1,14 +1,121 @@
-use rlp::Encodable;
+use std::{
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+
+use rlp::{Decodable, DecoderError, Encodable, Rlp};
 use serde::{Deserialize, Deserializer, Serialize};
 
-use super::{hex_data_string_to_bytes, validate_address_checksum, AccountAddress};
+use super::{hex_data_string_to_bytes, AccountAddress, HEX_PREFIX, U256};
 
 const STORAGE_KEY_LEN: usize = 32;
+const ACCESS_FIELD_COUNT: usize = 2;
+
+/// 32-byte key of a storage slot accessed by a transaction.
+///
+/// Besides fully padded hex strings, storage keys can be parsed from shorter hex strings or
+/// constructed from slot indices, e.g.:
+///
+/// ```rust
+/// use evm_signer_kms::evm_account::transaction::access_list::StorageKey;
+///
+/// let storage_key: StorageKey = "0x3".parse().unwrap();
+///
+/// assert_eq!(storage_key, StorageKey::from_slot(3));
+/// assert_eq!(
+///     storage_key.to_string(),
+///     "0x0000000000000000000000000000000000000000000000000000000000000003"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StorageKey(pub [u8; STORAGE_KEY_LEN]);
+
+impl StorageKey {
+    /// Creates a storage key for the slot with the given index.
+    pub fn from_slot(slot: u64) -> Self {
+        Self::from(U256::from(slot))
+    }
+
+    /// Returns the storage key as a byte slice.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; STORAGE_KEY_LEN]> for StorageKey {
+    fn from(bytes: [u8; STORAGE_KEY_LEN]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<U256> for StorageKey {
+    fn from(slot: U256) -> Self {
+        Self(slot.to_be_bytes())
+    }
+}
+
+impl From<StorageKey> for U256 {
+    fn from(storage_key: StorageKey) -> Self {
+        U256::from_be_bytes(storage_key.0)
+    }
+}
+
+impl FromStr for StorageKey {
+    type Err = Error;
+
+    /// Parses a hex string of up to 32 bytes. Shorter strings are left-padded with zeros.
+    fn from_str(storage_key: &str) -> Result<Self, Self::Err> {
+        let hex_digits = storage_key.trim_start_matches(HEX_PREFIX);
+
+        if hex_digits.is_empty() || hex_digits.len() > 2 * STORAGE_KEY_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Invalid storage key length",
+            ));
+        }
 
-type StorageKey = [u8; STORAGE_KEY_LEN];
+        // Pad odd number of digits, so that the string can be decoded byte by byte
+        let hex_digits = format!("{:0>width$}", hex_digits, width = 2 * STORAGE_KEY_LEN);
+        let bytes = hex_data_string_to_bytes(&hex_digits)?;
+
+        let mut storage_key = [0u8; STORAGE_KEY_LEN];
+        storage_key.copy_from_slice(&bytes);
+
+        Ok(Self(storage_key))
+    }
+}
+
+impl Display for StorageKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", HEX_PREFIX, hex::encode(self.0))
+    }
+}
+
+impl Decodable for StorageKey {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        rlp.data()?
+            .try_into()
+            .map(Self)
+            .map_err(|_| DecoderError::RlpInvalidLength)
+    }
+}
+
+impl Serialize for StorageKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
 
 /// Structure of an access i.e. an address and a list of storage keys accessed by a transaction.
+///
+/// Deserializes from either an `[address, [storage_keys]]` array or an object with `address` and
+/// `storageKeys` fields. Serializes to the latter.
 #[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Access {
     /// Address of the account accessed by the transaction.
     #[serde(deserialize_with = "deserialize_address_string")]
@@ -31,23 +138,26 @@ impl Encodable for Access {
     }
 }
 
+impl Decodable for Access {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        if rlp.item_count()? != ACCESS_FIELD_COUNT {
+            return Err(DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            address: rlp.val_at(0)?,
+            storage_keys: rlp.list_at(1)?,
+        })
+    }
+}
+
 fn deserialize_address_string<'de, D>(deserializer: D) -> Result<AccountAddress, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let address_string = String::deserialize(deserializer)?;
-
-    if !validate_address_checksum(&address_string) {
-        return Err(serde::de::Error::custom("Invalid address checksum"));
-    }
-
-    hex_data_string_to_bytes(&address_string)
-        .map_err(|error| {
-            serde::de::Error::custom(format!("Failed to deserialize address: {}", error))
-        })?
-        // Checks whether address is of proper length
-        .try_into()
-        .map_err(|_| serde::de::Error::custom("Invalid address length"))
+    String::deserialize(deserializer)?.parse().map_err(|error| {
+        serde::de::Error::custom(format!("Failed to deserialize address: {}", error))
+    })
 }
 
 fn deserialize_storage_keys_string_list<'de, D>(
@@ -69,11 +179,115 @@ where
                     ))
                 })?;
 
-            let storage_key_bytes: StorageKey = storage_key_bytes_slice
+            // Storage keys in transaction payloads are always fully padded
+            let storage_key_bytes = storage_key_bytes_slice
                 .try_into()
                 .map_err(|_| serde::de::Error::custom("Invalid storage key length"))?;
 
-            Ok(storage_key_bytes)
+            Ok(StorageKey(storage_key_bytes))
         })
         .collect()
 }
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    const TEST_STORAGE_KEY_STR: &str =
+        "0x0000000000000000000000000000000000000000000000000000000000000007";
+
+    #[test]
+    fn storage_key_from_str_padded_succeed() {
+        let left = StorageKey::from_slot(7);
+
+        let right: StorageKey = TEST_STORAGE_KEY_STR.parse().unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn storage_key_from_str_short_succeed() {
+        let left = StorageKey::from_slot(0xabc);
+
+        let right: StorageKey = "0xabc".parse().unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn storage_key_from_str_too_long_fail() {
+        let input = format!("{}0", TEST_STORAGE_KEY_STR);
+
+        assert!(input.parse::<StorageKey>().is_err());
+    }
+
+    #[test]
+    fn storage_key_from_str_invalid_digit_fail() {
+        assert!("0x0g".parse::<StorageKey>().is_err());
+    }
+
+    #[test]
+    fn storage_key_display_succeed() {
+        let input = StorageKey::from_slot(7);
+
+        assert_eq!(TEST_STORAGE_KEY_STR, input.to_string());
+    }
+
+    #[test]
+    fn access_deserialize_formats_succeed() {
+        let array = format!(
+            r#"["0xde0b295669a9fd93d5f28d9ec85e40f4cb697bae", ["{}"]]"#,
+            TEST_STORAGE_KEY_STR
+        );
+        let object = format!(
+            r#"{{"address": "0xde0b295669a9fd93d5f28d9ec85e40f4cb697bae", "storageKeys": ["{}"]}}"#,
+            TEST_STORAGE_KEY_STR
+        );
+
+        let left: Access = serde_json::from_str(&array).unwrap();
+        let right: Access = serde_json::from_str(&object).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn access_serialize_object_succeed() {
+        let input = Access {
+            address: "0xde0b295669a9fd93d5f28d9ec85e40f4cb697bae"
+                .parse()
+                .unwrap(),
+            storage_keys: vec![StorageKey::from_slot(7)],
+        };
+        let left = format!(
+            r#"{{"address":"0xde0B295669a9FD93d5F28D9Ec85E40f4cb697BAe","storageKeys":["{}"]}}"#,
+            TEST_STORAGE_KEY_STR
+        );
+
+        let right = serde_json::to_string(&input).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn access_rlp_round_trip_succeed() {
+        let left = Access {
+            address: "0xde0b295669a9fd93d5f28d9ec85e40f4cb697bae"
+                .parse()
+                .unwrap(),
+            storage_keys: vec![StorageKey::from_slot(3), StorageKey::from_slot(7)],
+        };
+
+        let right: Access = rlp::decode(&rlp::encode(&left)).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn storage_key_u256_round_trip_succeed() {
+        let left = U256::MAX - 1;
+
+        let right = U256::from(StorageKey::from(left));
+
+        assert_eq!(left, right);
+    }
+}
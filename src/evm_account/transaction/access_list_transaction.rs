@@ -1,12 +1,15 @@
-use rlp::{Encodable, RlpStream};
+use rlp::{Decodable, DecoderError, Encodable, Rlp};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    access_list::Access, deserialize_address_string_option, deserialize_hex_data_string,
-    AccountAddress, Transaction,
+    access_list::Access,
+    bump_fee, decode_address_option, deserialize_address_string_option, encode_rlp_list,
+    encoding_capacity,
+    quantity::{decode_u256, deserialize_quantity, serialize_u128, serialize_u256, RlpU256},
+    AccountAddress, Bytes, Transaction, TxType, U256,
 };
 
-const EIP_2930_TX_TYPE_ID: u8 = 0x01;
+const EIP_2930_TX_FIELD_COUNT: usize = 8;
 
 /// Represents an access list (i.e. type 1) transaction.
 ///
@@ -17,39 +20,96 @@ const EIP_2930_TX_TYPE_ID: u8 = 0x01;
 pub struct AccessListTransaction {
     /// Chain ID of the network to prevent replay attacks
     /// (see [`EIP-155`](https://eips.ethereum.org/EIPS/eip-155)).
+    #[serde(deserialize_with = "deserialize_quantity")]
     pub chain_id: u64,
     /// Sequence number of transaction from the account.
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u128"
+    )]
     pub nonce: u128,
     /// Gas price in wei (see
     /// [this article](https://ethereum.org/en/developers/docs/gas)).
-    pub gas_price: u128,
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u256"
+    )]
+    pub gas_price: U256,
     /// The maximum amount of gas that can be used by the transaction.
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u128"
+    )]
     pub gas_limit: u128,
     /// The address of the recipient of the transaction or `None` for smart contract deployment.
     #[serde(deserialize_with = "deserialize_address_string_option")]
     pub to: Option<AccountAddress>,
     /// The amount of wei to transfer to the recipient.
-    pub value: u128,
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u256"
+    )]
+    pub value: U256,
     /// Transaction data to be sent with the transaction (see
     /// [this article](https://ethereum.org/en/developers/docs/transactions/#the-data-field)).
-    #[serde(deserialize_with = "deserialize_hex_data_string")]
-    pub data: Vec<u8>,
+    pub data: Bytes,
     /// List of addresses and storage keys that the transaction plans to access.
     pub access_list: Vec<Access>,
 }
 
 impl Transaction for AccessListTransaction {
+    const TX_TYPE: TxType = TxType::Eip2930;
+
     fn encode(&self) -> Vec<u8> {
-        let mut rlp_stream = RlpStream::new();
-        rlp_stream
-            .begin_unbounded_list()
-            .append(self)
-            .finalize_unbounded_list();
+        encode_rlp_list(
+            Self::TX_TYPE,
+            self,
+            encoding_capacity(&self.data, &self.access_list),
+        )
+    }
+
+    fn chain_id(&self) -> Option<u64> {
+        Some(self.chain_id)
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn nonce(&self) -> u128 {
+        self.nonce
+    }
+
+    fn set_nonce(&mut self, nonce: u128) {
+        self.nonce = nonce;
+    }
+
+    fn gas_limit(&self) -> u128 {
+        self.gas_limit
+    }
+
+    fn set_gas_limit(&mut self, gas_limit: u128) {
+        self.gas_limit = gas_limit;
+    }
+
+    fn max_fee_per_gas(&self) -> U256 {
+        self.gas_price
+    }
+
+    fn bump_fees(&mut self, bump_percent: u32) {
+        self.gas_price = bump_fee(self.gas_price, bump_percent);
+    }
+
+    fn to(&self) -> Option<AccountAddress> {
+        self.to
+    }
 
-        let mut rlp_bytes = rlp_stream.out().to_vec();
-        rlp_bytes.insert(0, EIP_2930_TX_TYPE_ID);
+    fn set_to(&mut self, to: Option<AccountAddress>) {
+        self.to = to;
+    }
 
-        rlp_bytes
+    fn value(&self) -> U256 {
+        self.value
     }
 }
 
@@ -62,10 +122,10 @@ impl Encodable for AccessListTransaction {
 
         s.append(&self.chain_id)
             .append(&self.nonce)
-            .append(&self.gas_price)
+            .append(&RlpU256(&self.gas_price))
             .append(&self.gas_limit)
             .append(&to)
-            .append(&self.value)
+            .append(&RlpU256(&self.value))
             .append(&self.data)
             .begin_unbounded_list();
         for access in &self.access_list {
@@ -75,14 +135,35 @@ impl Encodable for AccessListTransaction {
     }
 }
 
+impl Decodable for AccessListTransaction {
+    /// Decodes the transaction from the RLP list of its fields (i.e. the unsigned encoding with no
+    /// transaction type prefix).
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        if rlp.item_count()? != EIP_2930_TX_FIELD_COUNT {
+            return Err(DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            nonce: rlp.val_at(1)?,
+            gas_price: decode_u256(&rlp.at(2)?)?,
+            gas_limit: rlp.val_at(3)?,
+            to: decode_address_option(&rlp.at(4)?)?,
+            value: decode_u256(&rlp.at(5)?)?,
+            data: rlp.val_at(6)?,
+            access_list: rlp.list_at(7)?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
 
-    const TEST_ADDRESS: AccountAddress = [
+    const TEST_ADDRESS: AccountAddress = AccountAddress([
         0x70, 0xad, 0x75, 0x4f, 0xf6, 0x70, 0x07, 0x74, 0x11, 0xdf, 0x59, 0x8f, 0xcf, 0xfd, 0x61,
         0xc4, 0x82, 0x99, 0xf1, 0x2f,
-    ];
+    ]);
 
     const TEST_ENCODING: [u8; 71] = [
         0x01, 0xf8, 0x44, 0x83, 0x06, 0x6e, 0xee, 0x05, 0x85, 0x17, 0x48, 0x76, 0xe8, 0x00, 0x82,
@@ -92,26 +173,37 @@ mod unit_tests {
         0x3f, 0xcc, 0x1c, 0x72, 0xd3, 0xbb, 0x8c, 0x18, 0x94, 0x13, 0xc0,
     ];
 
-    #[test]
-    fn encode_valid_tx_01_succeed() {
-        let left = TEST_ENCODING.to_vec();
-        let right = AccessListTransaction {
+    fn test_tx() -> AccessListTransaction {
+        AccessListTransaction {
             chain_id: 421614,
             nonce: 5,
-            gas_price: 100_000_000_000,
+            gas_price: U256::new(100_000_000_000),
             gas_limit: 21_000,
             to: Some(TEST_ADDRESS),
-            value: 10_000_000_000_000_000,
-            data: vec![],
+            value: U256::new(10_000_000_000_000_000),
+            data: Bytes::default(),
             access_list: vec![Access {
-                address: [
+                address: AccountAddress([
                     0xbb, 0x9b, 0xc2, 0x44, 0xd7, 0x98, 0x12, 0x3f, 0xde, 0x78, 0x3f, 0xcc, 0x1c,
                     0x72, 0xd3, 0xbb, 0x8c, 0x18, 0x94, 0x13,
-                ],
+                ]),
                 storage_keys: vec![],
             }],
         }
-        .encode();
+    }
+
+    #[test]
+    fn encode_valid_tx_01_succeed() {
+        let left = TEST_ENCODING.to_vec();
+        let right = test_tx().encode();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn decode_valid_tx_01_succeed() {
+        let left = test_tx();
+        let right: AccessListTransaction = rlp::decode(&TEST_ENCODING[1..]).unwrap();
 
         assert_eq!(left, right);
     }
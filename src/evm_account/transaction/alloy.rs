@@ -0,0 +1,284 @@
+use std::io::{Error, ErrorKind};
+
+use alloy_consensus::{Signed, TxEip1559, TxEip2930, TxEnvelope, TxLegacy, TypedTransaction};
+use alloy_eips::eip2930::{AccessList, AccessListItem};
+use alloy_primitives::{
+    Address, Bytes as AlloyBytes, PrimitiveSignature, TxKind, B256, U256 as AlloyU256,
+};
+
+use super::{
+    access_list::Access, access_list_transaction::AccessListTransaction, chain_id_from_v,
+    free_market_transaction::FreeMarketTransaction, legacy_transaction::LegacyTransaction,
+    AccountAddress, Bytes, SignedTransaction, Transaction, TxType, U256,
+};
+
+impl From<Address> for AccountAddress {
+    fn from(address: Address) -> Self {
+        Self(address.into())
+    }
+}
+
+impl From<AccountAddress> for Address {
+    fn from(address: AccountAddress) -> Self {
+        Address::from(address.0)
+    }
+}
+
+impl From<Access> for AccessListItem {
+    fn from(access: Access) -> Self {
+        Self {
+            address: access.address.into(),
+            storage_keys: access
+                .storage_keys
+                .into_iter()
+                .map(|key| B256::from(key.0))
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<LegacyTransaction> for TxLegacy {
+    type Error = Error;
+
+    /// Fails if `nonce`, `gas_limit` or `gas_price` do not fit the alloy field types.
+    fn try_from(tx: LegacyTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: None,
+            nonce: to_u64(tx.nonce, "nonce")?,
+            gas_price: to_u128(tx.gas_price, "gas_price")?,
+            gas_limit: to_u64(tx.gas_limit, "gas_limit")?,
+            to: to_tx_kind(tx.to),
+            value: to_alloy_u256(&tx.value),
+            input: to_alloy_bytes(tx.data),
+        })
+    }
+}
+
+impl TryFrom<AccessListTransaction> for TxEip2930 {
+    type Error = Error;
+
+    /// Fails if `nonce`, `gas_limit` or `gas_price` do not fit the alloy field types.
+    fn try_from(tx: AccessListTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: tx.chain_id,
+            nonce: to_u64(tx.nonce, "nonce")?,
+            gas_price: to_u128(tx.gas_price, "gas_price")?,
+            gas_limit: to_u64(tx.gas_limit, "gas_limit")?,
+            to: to_tx_kind(tx.to),
+            value: to_alloy_u256(&tx.value),
+            access_list: to_access_list(tx.access_list),
+            input: to_alloy_bytes(tx.data),
+        })
+    }
+}
+
+impl TryFrom<FreeMarketTransaction> for TxEip1559 {
+    type Error = Error;
+
+    /// Fails if `nonce`, `gas_limit` or any of the fees do not fit the alloy field types.
+    fn try_from(tx: FreeMarketTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chain_id: tx.chain_id,
+            nonce: to_u64(tx.nonce, "nonce")?,
+            gas_limit: to_u64(tx.gas_limit, "gas_limit")?,
+            max_fee_per_gas: to_u128(tx.max_fee_per_gas, "max_fee_per_gas")?,
+            max_priority_fee_per_gas: to_u128(
+                tx.max_priority_fee_per_gas,
+                "max_priority_fee_per_gas",
+            )?,
+            to: to_tx_kind(tx.to),
+            value: to_alloy_u256(&tx.value),
+            access_list: to_access_list(tx.access_list),
+            input: to_alloy_bytes(tx.data),
+        })
+    }
+}
+
+impl TryFrom<LegacyTransaction> for TypedTransaction {
+    type Error = Error;
+
+    fn try_from(tx: LegacyTransaction) -> Result<Self, Self::Error> {
+        TxLegacy::try_from(tx).map(Self::Legacy)
+    }
+}
+
+impl TryFrom<AccessListTransaction> for TypedTransaction {
+    type Error = Error;
+
+    fn try_from(tx: AccessListTransaction) -> Result<Self, Self::Error> {
+        TxEip2930::try_from(tx).map(Self::Eip2930)
+    }
+}
+
+impl TryFrom<FreeMarketTransaction> for TypedTransaction {
+    type Error = Error;
+
+    fn try_from(tx: FreeMarketTransaction) -> Result<Self, Self::Error> {
+        TxEip1559::try_from(tx).map(Self::Eip1559)
+    }
+}
+
+impl<T> TryFrom<SignedTransaction<T>> for TxEnvelope
+where
+    T: Transaction + TryInto<TypedTransaction, Error = Error>,
+{
+    type Error = Error;
+
+    /// Wraps the transaction and its signature in the alloy envelope, e.g. to broadcast it with an
+    /// alloy provider. The envelope encodes to the same bytes as `SignedTransaction::encode`.
+    fn try_from(signed_tx: SignedTransaction<T>) -> Result<Self, Self::Error> {
+        let hash = B256::from(signed_tx.hash());
+        let signature = PrimitiveSignature::new(
+            AlloyU256::from_be_bytes(signed_tx.r),
+            AlloyU256::from_be_bytes(signed_tx.s),
+            signed_tx.y_parity()? != 0,
+        );
+        // Legacy transactions decoded with an EIP-155 parity have to keep it in the encoding
+        let legacy_chain_id = match signed_tx.tx_type {
+            TxType::Legacy => chain_id_from_v(signed_tx.v),
+            _ => None,
+        };
+
+        match signed_tx.tx.try_into()? {
+            TypedTransaction::Legacy(mut tx) => {
+                tx.chain_id = legacy_chain_id;
+                Ok(Self::Legacy(Signed::new_unchecked(tx, signature, hash)))
+            }
+            TypedTransaction::Eip2930(tx) => {
+                Ok(Self::Eip2930(Signed::new_unchecked(tx, signature, hash)))
+            }
+            TypedTransaction::Eip1559(tx) => {
+                Ok(Self::Eip1559(Signed::new_unchecked(tx, signature, hash)))
+            }
+            _ => Err(Error::new(
+                ErrorKind::Unsupported,
+                "Transaction type is not supported",
+            )),
+        }
+    }
+}
+
+fn to_alloy_u256(value: &U256) -> AlloyU256 {
+    AlloyU256::from_be_bytes(value.to_be_bytes())
+}
+
+fn to_alloy_bytes(data: Bytes) -> AlloyBytes {
+    data.0.into()
+}
+
+fn to_access_list(access_list: Vec<Access>) -> AccessList {
+    AccessList(access_list.into_iter().map(AccessListItem::from).collect())
+}
+
+fn to_tx_kind(to: Option<AccountAddress>) -> TxKind {
+    match to {
+        Some(address) => TxKind::Call(address.into()),
+        None => TxKind::Create,
+    }
+}
+
+fn to_u64(value: u128, field: &str) -> Result<u64, Error> {
+    u64::try_from(value).map_err(|_| out_of_range(field))
+}
+
+fn to_u128(value: U256, field: &str) -> Result<u128, Error> {
+    u128::try_from(value).map_err(|_| out_of_range(field))
+}
+
+fn out_of_range(field: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!("Field `{}` is out of range", field),
+    )
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::transaction::fixtures::{
+        access_list_tx, free_market_tx, legacy_tx, signed_tx, TEST_ADDRESS_STR,
+    };
+    use alloy_eips::eip2718::Encodable2718;
+
+    #[test]
+    fn address_round_trip_succeed() {
+        let left: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+
+        let right = AccountAddress::from(Address::from(left));
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn address_checksum_matches_succeed() {
+        let input: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+
+        assert_eq!(input.to_string(), Address::from(input).to_checksum(None));
+    }
+
+    #[test]
+    fn free_market_tx_fields_match_succeed() {
+        let left = free_market_tx();
+        let mut input = free_market_tx();
+        input.value = U256::MAX;
+
+        let right = TxEip1559::try_from(input).unwrap();
+
+        assert_eq!(left.chain_id, right.chain_id);
+        assert_eq!(left.gas_limit, u128::from(right.gas_limit));
+        assert_eq!(left.max_fee_per_gas, U256::from(right.max_fee_per_gas));
+        assert_eq!(AlloyU256::MAX, right.value);
+        assert_eq!(TxKind::Create, right.to);
+        assert_eq!(left.data.0, right.input.to_vec());
+    }
+
+    #[test]
+    fn free_market_tx_nonce_out_of_range_fail() {
+        let mut input = free_market_tx();
+        input.nonce = u128::from(u64::MAX) + 1;
+
+        let error = TxEip1559::try_from(input).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn access_list_tx_gas_price_out_of_range_fail() {
+        let mut input = access_list_tx();
+        input.gas_price = U256::MAX;
+
+        assert!(TxEip2930::try_from(input).is_err());
+    }
+
+    #[test]
+    fn legacy_signed_tx_encoding_match_succeed() {
+        let input = signed_tx(legacy_tx(), 28);
+        let left = (input.encode(), input.hash());
+
+        let right = TxEnvelope::try_from(input).unwrap();
+
+        assert_eq!(left.0, right.encoded_2718());
+        assert_eq!(left.1, right.tx_hash().0);
+    }
+
+    #[test]
+    fn access_list_signed_tx_encoding_match_succeed() {
+        let input = signed_tx(access_list_tx(), 0);
+        let left = input.encode();
+
+        let right = TxEnvelope::try_from(input).unwrap();
+
+        assert_eq!(left, right.encoded_2718());
+    }
+
+    #[test]
+    fn free_market_signed_tx_encoding_match_succeed() {
+        let input = signed_tx(free_market_tx(), 1);
+        let left = (input.encode(), input.digest);
+
+        let right = TxEnvelope::try_from(input).unwrap();
+
+        assert_eq!(left.0, right.encoded_2718());
+        assert_eq!(left.1, right.signature_hash().0);
+    }
+}
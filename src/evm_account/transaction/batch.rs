@@ -0,0 +1,124 @@
+use std::io::{Error, Write};
+
+use super::{Bytes, SignedTransaction, Transaction};
+
+const CSV_HEADER: &str = "hash,nonce,rawTransaction";
+
+/// Writes the signed transactions as newline-delimited JSON, one `SignedTransaction::to_json`
+/// object per line.
+///
+/// Fails if writing fails or if the signer of any transaction cannot be recovered.
+pub fn write_ndjson<'a, T, I, W>(mut writer: W, signed_txs: I) -> Result<(), Error>
+where
+    T: Transaction + 'a,
+    I: IntoIterator<Item = &'a SignedTransaction<T>>,
+    W: Write,
+{
+    for signed_tx in signed_txs {
+        serde_json::to_writer(&mut writer, &signed_tx.to_json()?)?;
+        writer.write_all(b"\n")?;
+    }
+
+    writer.flush()
+}
+
+/// Writes the signed transactions as CSV with a header row and the `hash`, `nonce` and
+/// `rawTransaction` (i.e. `0x`-prefixed hex encoding) columns.
+pub fn write_csv<'a, T, I, W>(mut writer: W, signed_txs: I) -> Result<(), Error>
+where
+    T: Transaction + 'a,
+    I: IntoIterator<Item = &'a SignedTransaction<T>>,
+    W: Write,
+{
+    writeln!(writer, "{}", CSV_HEADER)?;
+    for signed_tx in signed_txs {
+        let raw_tx = Bytes(signed_tx.encode());
+        let hash = Bytes::from(signed_tx.hash().as_slice());
+
+        writeln!(writer, "{},{},{}", hash, signed_tx.tx.nonce(), raw_tx)?;
+    }
+
+    writer.flush()
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::signature_vector::{TEST_DIGEST, TEST_R, TEST_S, TEST_SENDER};
+    use crate::evm_account::transaction::{
+        legacy_transaction::LegacyTransaction, AccountAddress, U256,
+    };
+
+    fn signed_txs() -> Vec<SignedTransaction<LegacyTransaction>> {
+        (0..2)
+            .map(|nonce| {
+                let tx = LegacyTransaction {
+                    nonce,
+                    gas_price: U256::new(100_000_000_000),
+                    gas_limit: 21_000,
+                    to: Some(AccountAddress([0xab; 20])),
+                    value: U256::new(10_000_000_000_000_000),
+                    data: Bytes::default(),
+                };
+                let encoding = tx.encode();
+
+                SignedTransaction::new(tx, &encoding, TEST_DIGEST, 0, TEST_R, TEST_S)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn write_ndjson_succeed() {
+        let input = signed_txs();
+        let mut output = Vec::new();
+
+        write_ndjson(&mut output, &input).unwrap();
+
+        let right: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(input.len(), right.len());
+        for (left, right) in input.iter().zip(right) {
+            assert_eq!(right["from"], TEST_SENDER);
+            assert_eq!(
+                right["hash"],
+                Bytes::from(left.hash().as_slice()).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn write_csv_succeed() {
+        let input = signed_txs();
+        let mut output = Vec::new();
+
+        write_csv(&mut output, &input).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let right: Vec<&str> = output.lines().collect();
+        assert_eq!(CSV_HEADER, right[0]);
+        assert_eq!(input.len() + 1, right.len());
+        for (left, right) in input.iter().zip(&right[1..]) {
+            let left = format!(
+                "{},{},{}",
+                Bytes::from(left.hash().as_slice()),
+                left.tx.nonce,
+                Bytes(left.encode())
+            );
+
+            assert_eq!(&left, right);
+        }
+    }
+
+    #[test]
+    fn write_csv_empty_succeed() {
+        let input: Vec<SignedTransaction<LegacyTransaction>> = vec![];
+        let mut output = Vec::new();
+
+        write_csv(&mut output, &input).unwrap();
+
+        assert_eq!(format!("{}\n", CSV_HEADER).as_bytes(), output.as_slice());
+    }
+}
@@ -0,0 +1,388 @@
+use std::io::{Error, ErrorKind};
+
+use ethers_core::types::{
+    transaction::{
+        eip1559::Eip1559TransactionRequest,
+        eip2718::TypedTransaction,
+        eip2930::{AccessList, AccessListItem, Eip2930TransactionRequest},
+    },
+    Bytes as EthersBytes, NameOrAddress, Signature as EthersSignature, TransactionRequest, H160,
+    H256, U256 as EthersU256, U64,
+};
+
+use super::{
+    access_list::{Access, StorageKey},
+    access_list_transaction::AccessListTransaction,
+    free_market_transaction::FreeMarketTransaction,
+    legacy_transaction::LegacyTransaction,
+    AccountAddress, Bytes, SignedTransaction, Transaction, U256,
+};
+
+impl From<H160> for AccountAddress {
+    fn from(address: H160) -> Self {
+        Self(address.0)
+    }
+}
+
+impl From<AccountAddress> for H160 {
+    fn from(address: AccountAddress) -> Self {
+        H160(address.0)
+    }
+}
+
+impl From<Access> for AccessListItem {
+    fn from(access: Access) -> Self {
+        Self {
+            address: access.address.into(),
+            storage_keys: access
+                .storage_keys
+                .into_iter()
+                .map(|key| H256(key.0))
+                .collect(),
+        }
+    }
+}
+
+impl From<AccessListItem> for Access {
+    fn from(item: AccessListItem) -> Self {
+        Self {
+            address: item.address.into(),
+            storage_keys: item
+                .storage_keys
+                .into_iter()
+                .map(|key| StorageKey(key.0))
+                .collect(),
+        }
+    }
+}
+
+impl From<LegacyTransaction> for TransactionRequest {
+    fn from(tx: LegacyTransaction) -> Self {
+        Self {
+            to: tx.to.map(|to| NameOrAddress::Address(to.into())),
+            gas: Some(tx.gas_limit.into()),
+            gas_price: Some(to_ethers_u256(&tx.gas_price)),
+            value: Some(to_ethers_u256(&tx.value)),
+            data: Some(to_ethers_bytes(tx.data)),
+            nonce: Some(tx.nonce.into()),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<TransactionRequest> for LegacyTransaction {
+    type Error = Error;
+
+    /// Fails if any of `nonce`, `gas` or `gas_price` is missing, if `to` is an unresolved ENS
+    /// name or if the request is bound to a chain, as EIP-155 legacy transactions are not
+    /// supported.
+    fn try_from(request: TransactionRequest) -> Result<Self, Self::Error> {
+        if request.chain_id.is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Legacy transactions bound to a chain are not supported",
+            ));
+        }
+
+        Ok(Self {
+            nonce: to_u128(required(request.nonce, "nonce")?, "nonce")?,
+            gas_price: from_ethers_u256(required(request.gas_price, "gas_price")?),
+            gas_limit: to_u128(required(request.gas, "gas")?, "gas")?,
+            to: to_address(request.to)?,
+            value: request.value.map(from_ethers_u256).unwrap_or_default(),
+            data: request.data.map(from_ethers_bytes).unwrap_or_default(),
+        })
+    }
+}
+
+impl From<AccessListTransaction> for Eip2930TransactionRequest {
+    fn from(tx: AccessListTransaction) -> Self {
+        let request = TransactionRequest {
+            to: tx.to.map(|to| NameOrAddress::Address(to.into())),
+            gas: Some(tx.gas_limit.into()),
+            gas_price: Some(to_ethers_u256(&tx.gas_price)),
+            value: Some(to_ethers_u256(&tx.value)),
+            data: Some(to_ethers_bytes(tx.data)),
+            nonce: Some(tx.nonce.into()),
+            chain_id: Some(U64::from(tx.chain_id)),
+            ..Default::default()
+        };
+
+        Self::new(request, to_access_list(tx.access_list))
+    }
+}
+
+impl TryFrom<Eip2930TransactionRequest> for AccessListTransaction {
+    type Error = Error;
+
+    /// Fails if any of `chain_id`, `nonce`, `gas` or `gas_price` is missing or if `to` is an
+    /// unresolved ENS name.
+    fn try_from(request: Eip2930TransactionRequest) -> Result<Self, Self::Error> {
+        let tx = request.tx;
+
+        Ok(Self {
+            chain_id: required(tx.chain_id, "chain_id")?.as_u64(),
+            nonce: to_u128(required(tx.nonce, "nonce")?, "nonce")?,
+            gas_price: from_ethers_u256(required(tx.gas_price, "gas_price")?),
+            gas_limit: to_u128(required(tx.gas, "gas")?, "gas")?,
+            to: to_address(tx.to)?,
+            value: tx.value.map(from_ethers_u256).unwrap_or_default(),
+            data: tx.data.map(from_ethers_bytes).unwrap_or_default(),
+            access_list: from_access_list(request.access_list),
+        })
+    }
+}
+
+impl From<FreeMarketTransaction> for Eip1559TransactionRequest {
+    fn from(tx: FreeMarketTransaction) -> Self {
+        Self {
+            to: tx.to.map(|to| NameOrAddress::Address(to.into())),
+            gas: Some(tx.gas_limit.into()),
+            value: Some(to_ethers_u256(&tx.value)),
+            data: Some(to_ethers_bytes(tx.data)),
+            nonce: Some(tx.nonce.into()),
+            access_list: to_access_list(tx.access_list),
+            max_priority_fee_per_gas: Some(to_ethers_u256(&tx.max_priority_fee_per_gas)),
+            max_fee_per_gas: Some(to_ethers_u256(&tx.max_fee_per_gas)),
+            chain_id: Some(U64::from(tx.chain_id)),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<Eip1559TransactionRequest> for FreeMarketTransaction {
+    type Error = Error;
+
+    /// Fails if any of `chain_id`, `nonce`, `gas`, `max_fee_per_gas` or
+    /// `max_priority_fee_per_gas` is missing or if `to` is an unresolved ENS name.
+    fn try_from(request: Eip1559TransactionRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            gas_limit: to_u128(required(request.gas, "gas")?, "gas")?,
+            max_fee_per_gas: from_ethers_u256(required(
+                request.max_fee_per_gas,
+                "max_fee_per_gas",
+            )?),
+            max_priority_fee_per_gas: from_ethers_u256(required(
+                request.max_priority_fee_per_gas,
+                "max_priority_fee_per_gas",
+            )?),
+            chain_id: required(request.chain_id, "chain_id")?.as_u64(),
+            nonce: to_u128(required(request.nonce, "nonce")?, "nonce")?,
+            to: to_address(request.to)?,
+            value: request.value.map(from_ethers_u256).unwrap_or_default(),
+            data: request.data.map(from_ethers_bytes).unwrap_or_default(),
+            access_list: from_access_list(request.access_list),
+        })
+    }
+}
+
+impl From<LegacyTransaction> for TypedTransaction {
+    fn from(tx: LegacyTransaction) -> Self {
+        Self::Legacy(tx.into())
+    }
+}
+
+impl From<AccessListTransaction> for TypedTransaction {
+    fn from(tx: AccessListTransaction) -> Self {
+        Self::Eip2930(tx.into())
+    }
+}
+
+impl From<FreeMarketTransaction> for TypedTransaction {
+    fn from(tx: FreeMarketTransaction) -> Self {
+        Self::Eip1559(tx.into())
+    }
+}
+
+impl<T> From<SignedTransaction<T>> for (TypedTransaction, EthersSignature)
+where
+    T: Transaction + Into<TypedTransaction>,
+{
+    /// Splits the signed transaction into the `ethers` transaction and signature, so that it can
+    /// be encoded with `TypedTransaction::rlp_signed`.
+    fn from(signed_tx: SignedTransaction<T>) -> Self {
+        let signature = EthersSignature {
+            r: EthersU256::from_big_endian(&signed_tx.r),
+            s: EthersU256::from_big_endian(&signed_tx.s),
+            v: signed_tx.v,
+        };
+
+        (signed_tx.tx.into(), signature)
+    }
+}
+
+fn to_ethers_u256(value: &U256) -> EthersU256 {
+    EthersU256::from_big_endian(&value.to_be_bytes())
+}
+
+fn from_ethers_u256(value: EthersU256) -> U256 {
+    let mut bytes = [0u8; 32];
+    value.to_big_endian(&mut bytes);
+
+    U256::from_be_bytes(bytes)
+}
+
+fn to_u128(value: EthersU256, field: &str) -> Result<u128, Error> {
+    u128::try_from(value).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("Field `{}` is out of range", field),
+        )
+    })
+}
+
+fn to_ethers_bytes(data: Bytes) -> EthersBytes {
+    data.0.into()
+}
+
+fn from_ethers_bytes(data: EthersBytes) -> Bytes {
+    Bytes(data.to_vec())
+}
+
+fn to_access_list(access_list: Vec<Access>) -> AccessList {
+    AccessList(access_list.into_iter().map(AccessListItem::from).collect())
+}
+
+fn from_access_list(access_list: AccessList) -> Vec<Access> {
+    access_list.0.into_iter().map(Access::from).collect()
+}
+
+fn to_address(to: Option<NameOrAddress>) -> Result<Option<AccountAddress>, Error> {
+    match to {
+        Some(NameOrAddress::Address(address)) => Ok(Some(address.into())),
+        Some(NameOrAddress::Name(name)) => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("ENS name `{}` has to be resolved first", name),
+        )),
+        None => Ok(None),
+    }
+}
+
+fn required<V>(value: Option<V>, field: &str) -> Result<V, Error> {
+    value.ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("Field `{}` is missing", field),
+        )
+    })
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::transaction::fixtures::{
+        access_list_tx, free_market_tx, legacy_tx, signed_tx, TEST_ADDRESS_STR,
+    };
+
+    #[test]
+    fn address_round_trip_succeed() {
+        let left: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+
+        let right = AccountAddress::from(H160::from(left));
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn address_bytes_match_succeed() {
+        let input: AccountAddress = TEST_ADDRESS_STR.parse().unwrap();
+
+        assert_eq!(input.as_slice(), H160::from(input).as_bytes());
+    }
+
+    #[test]
+    fn legacy_tx_round_trip_succeed() {
+        let left = legacy_tx();
+        let input: TransactionRequest = legacy_tx().into();
+
+        let right = LegacyTransaction::try_from(input).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn access_list_tx_round_trip_succeed() {
+        let left = access_list_tx();
+        let input: Eip2930TransactionRequest = access_list_tx().into();
+
+        let right = AccessListTransaction::try_from(input).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn free_market_tx_round_trip_succeed() {
+        let mut left = free_market_tx();
+        left.value = U256::MAX;
+        let mut input = free_market_tx();
+        input.value = U256::MAX;
+        let input: Eip1559TransactionRequest = input.into();
+
+        let right = FreeMarketTransaction::try_from(input).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn free_market_tx_missing_field_fail() {
+        let mut input: Eip1559TransactionRequest = free_market_tx().into();
+        input.max_fee_per_gas = None;
+
+        let error = FreeMarketTransaction::try_from(input).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn free_market_tx_ens_name_fail() {
+        let mut input: Eip1559TransactionRequest = free_market_tx().into();
+        input.to = Some(NameOrAddress::Name("vitalik.eth".to_string()));
+
+        let error = FreeMarketTransaction::try_from(input).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn legacy_tx_chain_id_fail() {
+        let mut input: TransactionRequest = legacy_tx().into();
+        input.chain_id = Some(U64::one());
+
+        assert!(LegacyTransaction::try_from(input).is_err());
+    }
+
+    #[test]
+    fn legacy_signed_tx_encoding_match_succeed() {
+        let input = signed_tx(legacy_tx(), 27);
+        let left = input.encode();
+
+        let (tx, signature) = <(TypedTransaction, EthersSignature)>::from(input);
+        let right = tx.rlp_signed(&signature);
+
+        assert_eq!(left, right.to_vec());
+    }
+
+    #[test]
+    fn access_list_signed_tx_encoding_match_succeed() {
+        let input = signed_tx(access_list_tx(), 1);
+        let left = input.encode();
+
+        let (tx, signature) = <(TypedTransaction, EthersSignature)>::from(input);
+        let right = tx.rlp_signed(&signature);
+
+        assert_eq!(left, right.to_vec());
+    }
+
+    #[test]
+    fn free_market_signed_tx_encoding_match_succeed() {
+        let input = signed_tx(free_market_tx(), 1);
+        let left = input.encode();
+        let digest = input.digest;
+
+        let (tx, signature) = <(TypedTransaction, EthersSignature)>::from(input);
+        let right = tx.rlp_signed(&signature);
+
+        assert_eq!(left, right.to_vec());
+        assert_eq!(digest, tx.sighash().0);
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(any(feature = "alloy", feature = "ethers"))]
+use super::access_list_transaction::AccessListTransaction;
+use super::{
+    access_list::{Access, StorageKey},
+    free_market_transaction::FreeMarketTransaction,
+    legacy_transaction::LegacyTransaction,
+    AccountAddress, Bytes, SignedTransaction, Transaction, U256,
+};
+use crate::evm_account::hash::keccak256;
+
+pub(super) const TEST_ADDRESS_STR: &str = "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573";
+
+fn test_address() -> AccountAddress {
+    TEST_ADDRESS_STR.parse().unwrap()
+}
+
+pub(super) fn signed_tx<T: Transaction>(tx: T, v: u32) -> SignedTransaction<T> {
+    let encoding = tx.encode();
+    let digest = keccak256(&encoding);
+
+    SignedTransaction::new(tx, &encoding, digest, v, [0x11; 32], [0x22; 32])
+}
+
+pub(super) fn legacy_tx() -> LegacyTransaction {
+    LegacyTransaction {
+        nonce: 5,
+        gas_price: U256::new(100_000_000_000),
+        gas_limit: 21_000,
+        to: Some(test_address()),
+        value: U256::new(10_000_000_000_000_000),
+        data: Bytes::default(),
+    }
+}
+
+#[cfg(any(feature = "alloy", feature = "ethers"))]
+pub(super) fn access_list_tx() -> AccessListTransaction {
+    AccessListTransaction {
+        chain_id: 11155111,
+        nonce: 1,
+        gas_price: U256::new(100_000_000_000),
+        gas_limit: 50_000,
+        to: Some(test_address()),
+        value: U256::ZERO,
+        data: Bytes(vec![0xab, 0xcd]),
+        access_list: vec![Access {
+            address: test_address(),
+            storage_keys: vec![StorageKey::from_slot(7)],
+        }],
+    }
+}
+
+pub(super) fn free_market_tx() -> FreeMarketTransaction {
+    FreeMarketTransaction {
+        gas_limit: 21_000,
+        max_fee_per_gas: U256::new(100_000_000_000),
+        max_priority_fee_per_gas: U256::new(3_000_000_000),
+        chain_id: 11155111,
+        nonce: 0,
+        to: None,
+        value: U256::ZERO,
+        data: Bytes(vec![0xab, 0xcd]),
+        access_list: vec![Access {
+            address: test_address(),
+            storage_keys: vec![StorageKey::from_slot(7)],
+        }],
+    }
+}
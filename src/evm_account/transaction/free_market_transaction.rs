@@ -1,12 +1,27 @@
-use rlp::{Encodable, RlpStream};
+use rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
 use serde::{Deserialize, Serialize};
 
 use crate::evm_account::transaction::{
-    deserialize_address_string_option, deserialize_hex_data_string, Access, AccountAddress,
-    Transaction,
+    bump_fee, decode_address_option, deserialize_address_string_option, encode_rlp_list,
+    encoding_capacity,
+    quantity::{decode_u256, deserialize_quantity, serialize_u128, serialize_u256, RlpU256},
+    Access, AccountAddress, Bytes, Transaction, TxType, U256,
 };
 
-const EIP_1559_TX_TYPE_ID: u8 = 0x02;
+const EIP_1559_TX_FIELD_COUNT: usize = 9;
+// Gas consumed by plain ether transfers.
+const TRANSFER_GAS_LIMIT: u128 = 21_000;
+
+/// Chain and fees of transactions built by the library, e.g. cancellations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeSettings {
+    /// Chain ID of the network the transaction is bound to.
+    pub chain_id: u64,
+    /// The maximum fee per gas that the sender is willing to pay.
+    pub max_fee_per_gas: U256,
+    /// The maximum fee the sender is willing to pay as a tip to the validator.
+    pub max_priority_fee_per_gas: U256,
+}
 
 /// Represents a free market (i.e. type 2) transaction.
 ///
@@ -15,41 +30,146 @@ const EIP_1559_TX_TYPE_ID: u8 = 0x02;
 #[serde(rename_all = "camelCase")]
 pub struct FreeMarketTransaction {
     /// The maximum amount of gas that can be used by the transaction.
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u128"
+    )]
     pub gas_limit: u128,
     /// The maximum fee per gas that the sender is willing to pay.
-    pub max_fee_per_gas: u128,
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u256"
+    )]
+    pub max_fee_per_gas: U256,
     /// The maximum fee is willing to pay as a tip to the validator.
-    pub max_priority_fee_per_gas: u128,
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u256"
+    )]
+    pub max_priority_fee_per_gas: U256,
     /// Chain ID of the network to prevent replay attacks
     /// (see [`EIP-155`](https://eips.ethereum.org/EIPS/eip-155)).
+    #[serde(deserialize_with = "deserialize_quantity")]
     pub chain_id: u64,
     /// Sequence number of transaction from the account.
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u128"
+    )]
     pub nonce: u128,
     /// The address of the recipient of the transaction or `None` for smart contract deployment.
     #[serde(deserialize_with = "deserialize_address_string_option")]
     pub to: Option<AccountAddress>,
     /// The amount of wei to transfer to the recipient.
-    pub value: u128,
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u256"
+    )]
+    pub value: U256,
     /// Transaction data to be sent with the transaction (see
     /// [this article](https://ethereum.org/en/developers/docs/transactions/#the-data-field)).
-    #[serde(deserialize_with = "deserialize_hex_data_string")]
-    pub data: Vec<u8>,
+    pub data: Bytes,
     /// List of addresses and storage keys that the transaction plans to access.
     pub access_list: Vec<Access>,
 }
 
+impl FreeMarketTransaction {
+    /// Creates a zero-value transfer from the account to itself with the given nonce, i.e. the
+    /// standard way to void a pending transaction with the same nonce.
+    ///
+    /// The fees have to exceed those of the pending transaction (see `MIN_FEE_BUMP_PERCENT`) for
+    /// nodes to accept the replacement.
+    pub fn cancellation(address: AccountAddress, nonce: u128, fee_settings: FeeSettings) -> Self {
+        Self {
+            gas_limit: TRANSFER_GAS_LIMIT,
+            max_fee_per_gas: fee_settings.max_fee_per_gas,
+            max_priority_fee_per_gas: fee_settings.max_priority_fee_per_gas,
+            chain_id: fee_settings.chain_id,
+            nonce,
+            to: Some(address),
+            value: U256::ZERO,
+            data: Bytes::default(),
+            access_list: vec![],
+        }
+    }
+
+    /// Builds a call of the contract with the calldata and no value attached.
+    ///
+    /// The nonce is left at `0`, so it has to be set before signing (e.g. with `fill_nonce`).
+    pub fn contract_call(
+        contract: AccountAddress,
+        data: Bytes,
+        gas_limit: u128,
+        fee_settings: FeeSettings,
+    ) -> Self {
+        Self {
+            gas_limit,
+            max_fee_per_gas: fee_settings.max_fee_per_gas,
+            max_priority_fee_per_gas: fee_settings.max_priority_fee_per_gas,
+            chain_id: fee_settings.chain_id,
+            nonce: 0,
+            to: Some(contract),
+            value: U256::ZERO,
+            data,
+            access_list: vec![],
+        }
+    }
+}
+
 impl Transaction for FreeMarketTransaction {
+    const TX_TYPE: TxType = TxType::Eip1559;
+
     fn encode(&self) -> Vec<u8> {
-        let mut rlp_stream = RlpStream::new();
-        rlp_stream
-            .begin_unbounded_list()
-            .append(self)
-            .finalize_unbounded_list();
+        encode_rlp_list(
+            Self::TX_TYPE,
+            self,
+            encoding_capacity(&self.data, &self.access_list),
+        )
+    }
+
+    fn chain_id(&self) -> Option<u64> {
+        Some(self.chain_id)
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn nonce(&self) -> u128 {
+        self.nonce
+    }
+
+    fn set_nonce(&mut self, nonce: u128) {
+        self.nonce = nonce;
+    }
+
+    fn gas_limit(&self) -> u128 {
+        self.gas_limit
+    }
+
+    fn set_gas_limit(&mut self, gas_limit: u128) {
+        self.gas_limit = gas_limit;
+    }
+
+    fn max_fee_per_gas(&self) -> U256 {
+        self.max_fee_per_gas
+    }
+
+    fn bump_fees(&mut self, bump_percent: u32) {
+        self.max_fee_per_gas = bump_fee(self.max_fee_per_gas, bump_percent);
+        self.max_priority_fee_per_gas = bump_fee(self.max_priority_fee_per_gas, bump_percent);
+    }
+
+    fn to(&self) -> Option<AccountAddress> {
+        self.to
+    }
 
-        let mut rlp_bytes = rlp_stream.out().to_vec();
-        rlp_bytes.insert(0, EIP_1559_TX_TYPE_ID);
+    fn set_to(&mut self, to: Option<AccountAddress>) {
+        self.to = to;
+    }
 
-        rlp_bytes
+    fn value(&self) -> U256 {
+        self.value
     }
 }
 
@@ -62,11 +182,11 @@ impl Encodable for FreeMarketTransaction {
 
         s.append(&self.chain_id)
             .append(&self.nonce)
-            .append(&self.max_priority_fee_per_gas)
-            .append(&self.max_fee_per_gas)
+            .append(&RlpU256(&self.max_priority_fee_per_gas))
+            .append(&RlpU256(&self.max_fee_per_gas))
             .append(&self.gas_limit)
             .append(&to)
-            .append(&self.value)
+            .append(&RlpU256(&self.value))
             .append(&self.data)
             .begin_unbounded_list();
         for access in &self.access_list {
@@ -76,14 +196,39 @@ impl Encodable for FreeMarketTransaction {
     }
 }
 
+impl Decodable for FreeMarketTransaction {
+    /// Decodes the transaction from the RLP list of its fields (i.e. the unsigned encoding with no
+    /// transaction type prefix).
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        if rlp.item_count()? != EIP_1559_TX_FIELD_COUNT {
+            return Err(DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            nonce: rlp.val_at(1)?,
+            max_priority_fee_per_gas: decode_u256(&rlp.at(2)?)?,
+            max_fee_per_gas: decode_u256(&rlp.at(3)?)?,
+            gas_limit: rlp.val_at(4)?,
+            to: decode_address_option(&rlp.at(5)?)?,
+            value: decode_u256(&rlp.at(6)?)?,
+            data: rlp.val_at(7)?,
+            access_list: rlp.list_at(8)?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod unit_tests {
-    use super::{Access, AccountAddress, FreeMarketTransaction, Transaction};
+    use super::{
+        Access, AccountAddress, Bytes, FeeSettings, FreeMarketTransaction, Transaction, U256,
+    };
+    use crate::evm_account::transaction::access_list::StorageKey;
 
-    const TEST_ADDRESS: AccountAddress = [
+    const TEST_ADDRESS: AccountAddress = AccountAddress([
         0x70, 0xad, 0x75, 0x4f, 0xf6, 0x70, 0x07, 0x74, 0x11, 0xdf, 0x59, 0x8f, 0xcf, 0xfd, 0x61,
         0xc4, 0x82, 0x99, 0xf1, 0x2f,
-    ];
+    ]);
 
     const TEST_ENCODING_NO_ACCESS_LIST: [u8; 49] = [
         0x02, 0xef, 0x01, 0x80, 0x84, 0xb2, 0xd0, 0x5e, 0x00, 0x85, 0x17, 0x48, 0x76, 0xe8, 0x00,
@@ -120,13 +265,13 @@ mod unit_tests {
 
         let right = FreeMarketTransaction {
             gas_limit: 21_000,
-            max_fee_per_gas: 100_000_000_000,
-            max_priority_fee_per_gas: 3_000_000_000,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
             chain_id: 1,
             nonce: 0,
             to: Some(TEST_ADDRESS),
-            value: 10_000_000_000_000_000,
-            data: vec![],
+            value: U256::new(10_000_000_000_000_000),
+            data: Bytes::default(),
             access_list: vec![],
         }
         .encode();
@@ -134,24 +279,43 @@ mod unit_tests {
         assert_eq!(left, right);
     }
 
+    #[test]
+    fn unsigned_tx_decode_no_access_list() {
+        let left = FreeMarketTransaction {
+            gas_limit: 21_000,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
+            chain_id: 1,
+            nonce: 0,
+            to: Some(TEST_ADDRESS),
+            value: U256::new(10_000_000_000_000_000),
+            data: Bytes::default(),
+            access_list: vec![],
+        };
+
+        let right: FreeMarketTransaction = rlp::decode(&TEST_ENCODING_NO_ACCESS_LIST[1..]).unwrap();
+
+        assert_eq!(left, right);
+    }
+
     #[test]
     fn unsigned_tx_encode_with_access_list_1() {
         let left = TEST_ENCODING_WITH_ACCESS_LIST_1.to_vec();
 
         let right = FreeMarketTransaction {
             gas_limit: 21_000,
-            max_fee_per_gas: 100_000_000_000,
-            max_priority_fee_per_gas: 3_000_000_000,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
             chain_id: 1,
             nonce: 0,
             to: Some(TEST_ADDRESS),
-            value: 10_000_000_000_000_000,
-            data: vec![],
+            value: U256::new(10_000_000_000_000_000),
+            data: Bytes::default(),
             access_list: vec![Access {
-                address: [
+                address: AccountAddress([
                     0xbb, 0x9b, 0xc2, 0x44, 0xd7, 0x98, 0x12, 0x3f, 0xde, 0x78, 0x3f, 0xcc, 0x1c,
                     0x72, 0xd3, 0xbb, 0x8c, 0x18, 0x94, 0x13,
-                ],
+                ]),
                 storage_keys: vec![],
             }],
         }
@@ -166,37 +330,37 @@ mod unit_tests {
 
         let right = FreeMarketTransaction {
             gas_limit: 21_000,
-            max_fee_per_gas: 100_000_000_000,
-            max_priority_fee_per_gas: 3_000_000_000,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
             chain_id: 1,
             nonce: 0,
             to: Some(TEST_ADDRESS),
-            value: 10_000_000_000_000_000,
-            data: vec![],
+            value: U256::new(10_000_000_000_000_000),
+            data: Bytes::default(),
             access_list: vec![
                 Access {
-                    address: [
+                    address: AccountAddress([
                         0xde, 0x0b, 0x29, 0x56, 0x69, 0xa9, 0xfd, 0x93, 0xd5, 0xf2, 0x8d, 0x9e,
                         0xc8, 0x5e, 0x40, 0xf4, 0xcb, 0x69, 0x7b, 0xae,
-                    ],
+                    ]),
                     storage_keys: vec![
-                        [
+                        StorageKey([
                             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
-                        ],
-                        [
+                        ]),
+                        StorageKey([
                             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07,
-                        ],
+                        ]),
                     ],
                 },
                 Access {
-                    address: [
+                    address: AccountAddress([
                         0xbb, 0x9b, 0xc2, 0x44, 0xd7, 0x98, 0x12, 0x3f, 0xde, 0x78, 0x3f, 0xcc,
                         0x1c, 0x72, 0xd3, 0xbb, 0x8c, 0x18, 0x94, 0x13,
-                    ],
+                    ]),
                     storage_keys: vec![],
                 },
             ],
@@ -205,4 +369,22 @@ mod unit_tests {
 
         assert_eq!(left, right);
     }
+
+    #[test]
+    fn cancellation_succeed() {
+        let fee_settings = FeeSettings {
+            chain_id: 1,
+            max_fee_per_gas: U256::new(100_000_000_000),
+            max_priority_fee_per_gas: U256::new(3_000_000_000),
+        };
+
+        let right = FreeMarketTransaction::cancellation(TEST_ADDRESS, 7, fee_settings);
+
+        assert_eq!(Some(TEST_ADDRESS), right.to);
+        assert_eq!(7, right.nonce);
+        assert_eq!(U256::ZERO, right.value);
+        assert!(right.data.is_empty());
+        assert_eq!(21_000, right.gas_limit);
+        assert_eq!(Some(1), right.chain_id());
+    }
 }
@@ -0,0 +1,327 @@
+use std::io::{Error, ErrorKind, Read};
+
+use serde_json::Value;
+
+use super::{quantity::with_units, with_max_data_length, Transaction, TxType};
+
+// Field carrying the transaction type identifier in JSON-RPC transaction objects.
+const TYPE_FIELD: &str = "type";
+// Fields specific to the transaction formats, used when no type identifier is present.
+const MAX_FEE_PER_GAS_FIELD: &str = "maxFeePerGas";
+const ACCESS_LIST_FIELD: &str = "accessList";
+
+/// Strictness of transaction JSON deserialization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Rejects documents containing fields which are not part of the transaction format, e.g.
+    /// typos like `gasprice` instead of `gasPrice`.
+    Strict,
+    /// Silently ignores fields which are not part of the transaction format.
+    Lenient,
+    /// Same as `Lenient`, but additionally accepts wei denominated quantities (i.e. `value` and
+    /// gas prices) in human readable units, e.g. `"maxFeePerGas": "100 gwei"` (see
+    /// [`parse_units`](crate::evm_account::units::parse_units)).
+    Tolerant,
+}
+
+/// Options controlling transaction JSON deserialization.
+///
+/// Can be constructed from a `Mode` if no further options are required.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Options {
+    /// Strictness of deserialization.
+    pub mode: Mode,
+    /// Maximum length of transaction `data` in bytes or `None` for no limit.
+    pub max_data_length: Option<usize>,
+}
+
+impl Options {
+    /// Creates new options with the selected mode and no data length limit.
+    pub fn new(mode: Mode) -> Self {
+        Self {
+            mode,
+            max_data_length: None,
+        }
+    }
+
+    /// Rejects transactions whose `data` is longer than `max_data_length` bytes.
+    pub fn with_max_data_length(mut self, max_data_length: usize) -> Self {
+        self.max_data_length = Some(max_data_length);
+        self
+    }
+}
+
+impl From<Mode> for Options {
+    fn from(mode: Mode) -> Self {
+        Self::new(mode)
+    }
+}
+
+/// Deserializes a transaction from a JSON string using the selected options.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "parse_transaction_json",
+        level = "debug",
+        skip_all,
+        fields(tx_type = T::TX_TYPE.id()),
+        err
+    )
+)]
+pub fn from_str<T: Transaction>(json: &str, options: impl Into<Options>) -> Result<T, Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+
+    deserialize(&mut deserializer, options.into())
+}
+
+/// Deserializes a transaction from a JSON byte slice using the selected options.
+pub fn from_slice<T: Transaction>(json: &[u8], options: impl Into<Options>) -> Result<T, Error> {
+    let mut deserializer = serde_json::Deserializer::from_slice(json);
+
+    deserialize(&mut deserializer, options.into())
+}
+
+/// Deserializes a transaction from a JSON reader (e.g. a file) using the selected options.
+pub fn from_reader<T: Transaction, R: Read>(
+    reader: R,
+    options: impl Into<Options>,
+) -> Result<T, Error> {
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+
+    deserialize(&mut deserializer, options.into())
+}
+
+/// Detects the format of the transaction JSON, so that it can be deserialized into the matching
+/// transaction type.
+///
+/// The `type` identifier is used if present (as a number, decimal or hex string). Otherwise
+/// transactions with `maxFeePerGas` are EIP-1559 transactions, transactions with `accessList` are
+/// EIP-2930 transactions and all others are legacy transactions. Fails with
+/// `ErrorKind::Unsupported` for types which cannot be deserialized.
+pub fn detect_tx_type(json: &Value) -> Result<TxType, Error> {
+    let fields = json
+        .as_object()
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "Transaction JSON is not an object"))?;
+
+    let tx_type = match fields.get(TYPE_FIELD) {
+        Some(type_id) => parse_type_id(type_id).and_then(TxType::try_from)?,
+        None if fields.contains_key(MAX_FEE_PER_GAS_FIELD) => TxType::Eip1559,
+        None if fields.contains_key(ACCESS_LIST_FIELD) => TxType::Eip2930,
+        None => TxType::Legacy,
+    };
+
+    match tx_type {
+        TxType::Legacy | TxType::Eip2930 | TxType::Eip1559 => Ok(tx_type),
+        tx_type => Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("Unsupported transaction type: {:#04x}", tx_type.id()),
+        )),
+    }
+}
+
+fn parse_type_id(type_id: &Value) -> Result<u8, Error> {
+    let parsed = match type_id {
+        Value::Number(number) => number.as_u64().and_then(|id| u8::try_from(id).ok()),
+        Value::String(id) => match id.strip_prefix("0x") {
+            Some(hex_id) => u8::from_str_radix(hex_id, 16).ok(),
+            None => id.parse().ok(),
+        },
+        _ => None,
+    };
+
+    parsed.ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Invalid transaction type: {}", type_id),
+        )
+    })
+}
+
+fn deserialize<'de, T, R>(
+    deserializer: &mut serde_json::Deserializer<R>,
+    options: Options,
+) -> Result<T, Error>
+where
+    T: Transaction,
+    R: serde_json::de::Read<'de>,
+{
+    let mut unknown_fields = Vec::new();
+
+    // The data length is limited while deserializing, so that oversized data is not decoded
+    let tx: T = with_max_data_length(options.max_data_length, || {
+        match options.mode {
+            Mode::Strict => serde_ignored::deserialize(&mut *deserializer, |path| {
+                unknown_fields.push(path.to_string())
+            }),
+            Mode::Lenient => T::deserialize(&mut *deserializer),
+            Mode::Tolerant => with_units(|| T::deserialize(&mut *deserializer)),
+        }
+        .and_then(|tx| deserializer.end().map(|_| tx))
+        .map_err(|error| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Failed to deserialize transaction: {}", error),
+            )
+        })
+    })?;
+
+    if !unknown_fields.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("Unknown transaction fields: {}", unknown_fields.join(", ")),
+        ));
+    }
+
+    Ok(tx)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::evm_account::transaction::{legacy_transaction::LegacyTransaction, Bytes};
+
+    const TEST_TX_JSON: &str = r#"
+    {
+        "nonce": 5,
+        "gasPrice": 100000000000,
+        "gasLimit": 21000,
+        "to": "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573",
+        "value": 10000000000000000,
+        "data": "0x"
+    }
+    "#;
+
+    const TEST_TX_JSON_UNKNOWN_FIELD: &str = r#"
+    {
+        "nonce": 5,
+        "gasPrice": 100000000000,
+        "gasprice": 200000000000,
+        "gasLimit": 21000,
+        "to": "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573",
+        "value": 10000000000000000,
+        "data": "0x"
+    }
+    "#;
+
+    #[test]
+    fn strict_mode_known_fields_succeed() {
+        let left: LegacyTransaction = serde_json::from_str(TEST_TX_JSON).unwrap();
+
+        let right: LegacyTransaction = from_str(TEST_TX_JSON, Mode::Strict).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn strict_mode_unknown_field_fail() {
+        let error =
+            from_str::<LegacyTransaction>(TEST_TX_JSON_UNKNOWN_FIELD, Mode::Strict).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert!(error.to_string().contains("gasprice"));
+    }
+
+    #[test]
+    fn max_data_length_succeed() {
+        let options = Options::new(Mode::Strict).with_max_data_length(0);
+
+        assert!(from_str::<LegacyTransaction>(TEST_TX_JSON, options).is_ok());
+    }
+
+    #[test]
+    fn max_data_length_exceeded_fail() {
+        let json = TEST_TX_JSON.replace(r#""data": "0x""#, r#""data": "0xabcd""#);
+        let options = Options::new(Mode::Lenient).with_max_data_length(1);
+
+        let error = from_str::<LegacyTransaction>(&json, options).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn max_data_length_exceeded_before_decoding_fail() {
+        // Not valid hex, so the data is only rejected by its length if it is not decoded
+        let json = TEST_TX_JSON.replace(r#""data": "0x""#, r#""data": "0xzzzzzz""#);
+        let options = Options::new(Mode::Lenient).with_max_data_length(2);
+
+        let error = from_str::<LegacyTransaction>(&json, options).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(serde_json::from_str::<Bytes>(r#""0xabcdef""#).is_ok());
+    }
+
+    #[test]
+    fn tolerant_mode_units_succeed() {
+        let json = TEST_TX_JSON
+            .replace(r#""gasPrice": 100000000000"#, r#""gasPrice": "100 gwei""#)
+            .replace(r#""value": 10000000000000000"#, r#""value": "0.01 ether""#);
+        let left: LegacyTransaction = serde_json::from_str(TEST_TX_JSON).unwrap();
+
+        let right: LegacyTransaction = from_str(&json, Mode::Tolerant).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn lenient_mode_units_fail() {
+        let json = TEST_TX_JSON.replace(r#""gasPrice": 100000000000"#, r#""gasPrice": "100 gwei""#);
+
+        assert!(from_str::<LegacyTransaction>(&json, Mode::Lenient).is_err());
+    }
+
+    #[test]
+    fn lenient_mode_unknown_field_succeed() {
+        let left: LegacyTransaction = serde_json::from_str(TEST_TX_JSON).unwrap();
+
+        let right: LegacyTransaction = from_str(TEST_TX_JSON_UNKNOWN_FIELD, Mode::Lenient).unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn detect_tx_type_succeed() {
+        let inputs = [
+            (TxType::Legacy, TEST_TX_JSON.to_string()),
+            (
+                TxType::Eip2930,
+                TEST_TX_JSON.replace(r#""nonce": 5"#, r#""nonce": 5, "accessList": []"#),
+            ),
+            (
+                TxType::Eip1559,
+                TEST_TX_JSON.replace(r#""gasPrice""#, r#""maxFeePerGas""#),
+            ),
+            (
+                TxType::Eip1559,
+                TEST_TX_JSON.replace(r#""nonce": 5"#, r#""nonce": 5, "type": "0x2""#),
+            ),
+            (
+                TxType::Eip2930,
+                TEST_TX_JSON.replace(r#""nonce": 5"#, r#""nonce": 5, "type": 1"#),
+            ),
+        ];
+
+        for (left, input) in inputs {
+            let json: Value = serde_json::from_str(&input).unwrap();
+
+            assert_eq!(left, detect_tx_type(&json).unwrap());
+        }
+    }
+
+    #[test]
+    fn detect_tx_type_unsupported_fail() {
+        let json = TEST_TX_JSON.replace(r#""nonce": 5"#, r#""nonce": 5, "type": "0x3""#);
+
+        let error = detect_tx_type(&serde_json::from_str(&json).unwrap()).unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn detect_tx_type_invalid_fail() {
+        for input in [r#"{"type": "0xzz"}"#, r#"{"type": 300}"#, "[]"] {
+            let error = detect_tx_type(&serde_json::from_str(input).unwrap()).unwrap_err();
+
+            assert_eq!(error.kind(), ErrorKind::InvalidData);
+        }
+    }
+}
@@ -1,10 +1,15 @@
-use rlp::Encodable;
+use rlp::{Decodable, DecoderError, Encodable, Rlp};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    deserialize_address_string_option, deserialize_hex_data_string, AccountAddress, Transaction,
+    bump_fee, decode_address_option, deserialize_address_string_option, encode_rlp_list,
+    encoding_capacity,
+    quantity::{decode_u256, deserialize_quantity, serialize_u128, serialize_u256, RlpU256},
+    AccountAddress, Bytes, Transaction, TxType, U256,
 };
 
+const LEGACY_TX_FIELD_COUNT: usize = 6;
+
 /// Represents a legacy Ethereum transaction.
 ///
 /// The format of a legacy transaction roughly follows the structure described
@@ -13,32 +18,87 @@ use super::{
 #[serde(rename_all = "camelCase")]
 pub struct LegacyTransaction {
     /// Sequence number of transaction from the account.
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u128"
+    )]
     pub nonce: u128,
     /// Gas price in wei (see
     /// [this article](https://ethereum.org/en/developers/docs/gas)).
-    pub gas_price: u128,
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u256"
+    )]
+    pub gas_price: U256,
     /// The maximum amount of gas that can be used by the transaction.
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u128"
+    )]
     pub gas_limit: u128,
     /// The address of the recipient of the transaction or `None` for smart contract deployment.
     #[serde(deserialize_with = "deserialize_address_string_option")]
     pub to: Option<AccountAddress>,
     /// The amount of wei to transfer to the recipient.
-    pub value: u128,
-    #[serde(deserialize_with = "deserialize_hex_data_string")]
+    #[serde(
+        deserialize_with = "deserialize_quantity",
+        serialize_with = "serialize_u256"
+    )]
+    pub value: U256,
     /// Transaction data to be sent with the transaction (see
     /// [this article](https://ethereum.org/en/developers/docs/transactions/#the-data-field)).
-    pub data: Vec<u8>,
+    pub data: Bytes,
 }
 
 impl Transaction for LegacyTransaction {
+    const TX_TYPE: TxType = TxType::Legacy;
+
     fn encode(&self) -> Vec<u8> {
-        let mut rlp_stream = rlp::RlpStream::new();
-        rlp_stream
-            .begin_unbounded_list()
-            .append(self)
-            .finalize_unbounded_list();
+        encode_rlp_list(Self::TX_TYPE, self, encoding_capacity(&self.data, &[]))
+    }
+
+    fn chain_id(&self) -> Option<u64> {
+        None
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn nonce(&self) -> u128 {
+        self.nonce
+    }
+
+    fn set_nonce(&mut self, nonce: u128) {
+        self.nonce = nonce;
+    }
+
+    fn gas_limit(&self) -> u128 {
+        self.gas_limit
+    }
+
+    fn set_gas_limit(&mut self, gas_limit: u128) {
+        self.gas_limit = gas_limit;
+    }
+
+    fn max_fee_per_gas(&self) -> U256 {
+        self.gas_price
+    }
+
+    fn bump_fees(&mut self, bump_percent: u32) {
+        self.gas_price = bump_fee(self.gas_price, bump_percent);
+    }
+
+    fn to(&self) -> Option<AccountAddress> {
+        self.to
+    }
 
-        rlp_stream.out().to_vec()
+    fn set_to(&mut self, to: Option<AccountAddress>) {
+        self.to = to;
+    }
+
+    fn value(&self) -> U256 {
+        self.value
     }
 }
 
@@ -50,22 +110,40 @@ impl Encodable for LegacyTransaction {
         };
 
         s.append(&self.nonce)
-            .append(&self.gas_price)
+            .append(&RlpU256(&self.gas_price))
             .append(&self.gas_limit)
             .append(&to)
-            .append(&self.value)
+            .append(&RlpU256(&self.value))
             .append(&self.data);
     }
 }
 
+impl Decodable for LegacyTransaction {
+    /// Decodes the transaction from the RLP list of its fields (i.e. the unsigned encoding).
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        if rlp.item_count()? != LEGACY_TX_FIELD_COUNT {
+            return Err(DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            nonce: rlp.val_at(0)?,
+            gas_price: decode_u256(&rlp.at(1)?)?,
+            gas_limit: rlp.val_at(2)?,
+            to: decode_address_option(&rlp.at(3)?)?,
+            value: decode_u256(&rlp.at(4)?)?,
+            data: rlp.val_at(5)?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
 
-    const TEST_ADDRESS: AccountAddress = [
+    const TEST_ADDRESS: AccountAddress = AccountAddress([
         0x70, 0xad, 0x75, 0x4f, 0xf6, 0x70, 0x07, 0x74, 0x11, 0xdf, 0x59, 0x8f, 0xcf, 0xfd, 0x61,
         0xc4, 0x82, 0x99, 0xf1, 0x2f,
-    ];
+    ]);
 
     const TEST_ENCODING: [u8; 41] = [
         0xe8, 0x05, 0x85, 0x17, 0x48, 0x76, 0xe8, 0x00, 0x82, 0x52, 0x08, 0x94, 0x70, 0xad, 0x75,
@@ -73,18 +151,29 @@ mod unit_tests {
         0xf1, 0x2f, 0x87, 0x23, 0x86, 0xf2, 0x6f, 0xc1, 0x00, 0x00, 0x80,
     ];
 
-    #[test]
-    fn encode_valid_tx_01_succeed() {
-        let left = TEST_ENCODING.to_vec();
-        let right = LegacyTransaction {
+    fn test_tx() -> LegacyTransaction {
+        LegacyTransaction {
             nonce: 5,
-            gas_price: 100_000_000_000,
+            gas_price: U256::new(100_000_000_000),
             gas_limit: 21_000,
             to: Some(TEST_ADDRESS),
-            value: 10_000_000_000_000_000,
-            data: vec![],
+            value: U256::new(10_000_000_000_000_000),
+            data: Bytes::default(),
         }
-        .encode();
+    }
+
+    #[test]
+    fn encode_valid_tx_01_succeed() {
+        let left = TEST_ENCODING.to_vec();
+        let right = test_tx().encode();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn decode_valid_tx_01_succeed() {
+        let left = test_tx();
+        let right: LegacyTransaction = rlp::decode(&TEST_ENCODING).unwrap();
 
         assert_eq!(left, right);
     }
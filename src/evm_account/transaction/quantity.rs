@@ -0,0 +1,411 @@
+use std::{cell::Cell, fmt, marker::PhantomData};
+
+use rlp::{DecoderError, Encodable, Rlp, RlpStream};
+use serde::{
+    de::{self, value::MapAccessDeserializer, MapAccess, Unexpected, Visitor},
+    Deserialize, Deserializer, Serializer,
+};
+use serde_json::Number;
+
+use super::{HEX_PREFIX, HEX_RADIX, U256};
+use crate::evm_account::units::parse_units;
+
+const DECIMAL_RADIX: u32 = 10;
+const U256_LENGTH: usize = 32;
+// Largest integer which can be represented by a double precision float without loss of precision.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+thread_local! {
+    // Serde provides no way of passing context to deserialization functions, so the flag is set
+    // for the duration of tolerant deserialization on the current thread.
+    static ACCEPT_UNITS: Cell<bool> = const { Cell::new(false) };
+}
+
+// Restores the previous state of the flag when dropped, also if deserialization panics.
+struct AcceptUnitsGuard(bool);
+
+impl Drop for AcceptUnitsGuard {
+    fn drop(&mut self) {
+        ACCEPT_UNITS.with(|accept_units| accept_units.set(self.0));
+    }
+}
+
+/// Runs `deserialize` accepting wei denominated quantities in human readable units, e.g.
+/// `"1.5 gwei"`.
+pub(super) fn with_units<R>(deserialize: impl FnOnce() -> R) -> R {
+    let _guard = AcceptUnitsGuard(ACCEPT_UNITS.with(|accept_units| accept_units.replace(true)));
+
+    deserialize()
+}
+
+/// Wrapper for RLP encoding of 256-bit quantities as minimal big-endian byte strings.
+pub(super) struct RlpU256<'a>(pub &'a U256);
+
+impl Encodable for RlpU256<'_> {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        let bytes = self.0.to_be_bytes();
+        // Leading zero bytes are not part of the encoding, so zero is encoded as empty string
+        let leading_zero_bytes = (self.0.leading_zeros() / 8) as usize;
+
+        s.append(&&bytes[leading_zero_bytes..]);
+    }
+}
+
+/// Decodes a 256-bit quantity from a minimal big-endian byte string.
+pub(super) fn decode_u256(rlp: &Rlp) -> Result<U256, DecoderError> {
+    let bytes = rlp.data()?;
+
+    if bytes.len() > U256_LENGTH {
+        return Err(DecoderError::RlpIsTooBig);
+    }
+
+    // Same as for the integer types decoded by the `rlp` crate, leading zeros are not canonical
+    if bytes.first() == Some(&0) {
+        return Err(DecoderError::RlpInvalidIndirection);
+    }
+
+    let mut be_bytes = [0u8; U256_LENGTH];
+    be_bytes[U256_LENGTH - bytes.len()..].copy_from_slice(bytes);
+
+    Ok(U256::from_be_bytes(be_bytes))
+}
+
+/// Unsigned integer types used for transaction quantities.
+pub(super) trait Quantity: Sized {
+    /// Whether the type holds wei denominated amounts, which can be specified in other units.
+    const DENOMINATED: bool = false;
+
+    /// Narrows a 256-bit quantity down to the type or returns `None` if it doesn't fit.
+    fn from_u256(value: U256) -> Option<Self>;
+}
+
+impl Quantity for U256 {
+    const DENOMINATED: bool = true;
+
+    fn from_u256(value: U256) -> Option<Self> {
+        Some(value)
+    }
+}
+
+impl Quantity for u128 {
+    fn from_u256(value: U256) -> Option<Self> {
+        match value.into_words() {
+            (0, low) => Some(low),
+            _ => None,
+        }
+    }
+}
+
+impl Quantity for u64 {
+    fn from_u256(value: U256) -> Option<Self> {
+        u128::from_u256(value).and_then(|value| u64::try_from(value).ok())
+    }
+}
+
+fn parse_quantity_string(value: &str) -> Option<U256> {
+    let (digits, radix) = match value.strip_prefix(HEX_PREFIX) {
+        Some(hex) => (hex, HEX_RADIX),
+        None => (value, DECIMAL_RADIX),
+    };
+
+    // Reject empty strings (e.g. `0x` alone), signs and whitespace which the radix parser may
+    // otherwise tolerate
+    if digits.is_empty() || !digits.chars().all(|ch| ch.is_digit(radix)) {
+        return None;
+    }
+
+    U256::from_str_radix(digits, radix).ok()
+}
+
+struct QuantityVisitor<T>(PhantomData<T>);
+
+impl<T: Quantity> QuantityVisitor<T> {
+    fn narrow<E: de::Error>(&self, value: U256, unexpected: Unexpected) -> Result<T, E> {
+        T::from_u256(value).ok_or_else(|| E::invalid_value(unexpected, self))
+    }
+}
+
+impl<'de, T: Quantity> Visitor<'de> for QuantityVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(
+            "a non-negative integer, a decimal string or a 0x-prefixed hex string within range",
+        )
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        self.narrow(U256::from(value), Unexpected::Unsigned(value))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+        self.narrow(U256::new(value), Unexpected::Other("128-bit integer"))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        let value =
+            u64::try_from(value).map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))?;
+
+        self.visit_u64(value)
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        // Integers exceeding 64 bits are parsed as floats by most parsers other than serde_json,
+        // which silently loses precision. Only accept floats which are guaranteed to be exact.
+        if value >= 0.0 && value.fract() == 0.0 && value <= MAX_SAFE_INTEGER {
+            return self.visit_u64(value as u64);
+        }
+
+        Err(E::invalid_value(
+            Unexpected::Float(value),
+            &"an integer not exceeding 2^53, a decimal string or a 0x-prefixed hex string",
+        ))
+    }
+
+    // serde_json passes numbers as maps holding their exact decimal representation (see the
+    // `arbitrary_precision` feature), so integers of any size are parsed without rounding
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let number = Number::deserialize(MapAccessDeserializer::new(map))?.to_string();
+        let quantity = parse_quantity_string(&number)
+            .ok_or_else(|| de::Error::invalid_value(Unexpected::Other(&number), &self))?;
+
+        self.narrow(quantity, Unexpected::Other(&number))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let quantity = parse_quantity_string(value)
+            .or_else(|| {
+                let accept_units = T::DENOMINATED && ACCEPT_UNITS.with(|accept| accept.get());
+                accept_units.then(|| parse_units(value).ok()).flatten()
+            })
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))?;
+
+        self.narrow(quantity, Unexpected::Str(value))
+    }
+}
+
+/// Deserializes a quantity from an integer, a decimal string or a `0x`-prefixed hex string (i.e.
+/// the JSON-RPC quantity format).
+///
+/// JSON integers are parsed exactly whatever their size. Formats other than JSON may need integers
+/// exceeding 64 bits to be passed as strings.
+pub(super) fn deserialize_quantity<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Quantity,
+{
+    deserializer.deserialize_any(QuantityVisitor(PhantomData))
+}
+
+/// Serializes a 128-bit quantity as an integer or as a decimal string if it exceeds 64 bits, so
+/// that it can be deserialized without loss of precision.
+pub(super) fn serialize_u128<S>(value: &u128, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match u64::try_from(*value) {
+        Ok(value) => serializer.serialize_u64(value),
+        Err(_) => serializer.collect_str(value),
+    }
+}
+
+/// Serializes a 256-bit quantity as a decimal string.
+pub(super) fn serialize_u256<S>(value: &U256, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn rlp_encode(value: U256) -> Vec<u8> {
+        rlp::encode(&RlpU256(&value)).to_vec()
+    }
+
+    #[test]
+    fn rlp_encode_zero_succeed() {
+        assert_eq!(vec![0x80], rlp_encode(U256::ZERO));
+    }
+
+    #[test]
+    fn rlp_encode_single_byte_succeed() {
+        assert_eq!(vec![0x7f], rlp_encode(U256::new(0x7f)));
+    }
+
+    #[test]
+    fn rlp_encode_matches_u128_succeed() {
+        let input = 10_000_000_000_000_000u128;
+
+        let left = rlp::encode(&input).to_vec();
+        let right = rlp_encode(U256::new(input));
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn rlp_encode_max_succeed() {
+        let mut left = vec![0xa0];
+        left.extend_from_slice(&[0xff; 32]);
+
+        let right = rlp_encode(U256::MAX);
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn rlp_decode_round_trip_succeed() {
+        for left in [U256::ZERO, U256::new(0x7f), U256::new(21_000), U256::MAX] {
+            let encoding = rlp_encode(left);
+
+            let right = decode_u256(&Rlp::new(&encoding)).unwrap();
+
+            assert_eq!(left, right);
+        }
+    }
+
+    #[test]
+    fn rlp_decode_leading_zero_fail() {
+        assert!(decode_u256(&Rlp::new(&[0x82, 0x00, 0x01])).is_err());
+    }
+
+    #[test]
+    fn deserialize_integer_succeed() {
+        let mut deserializer = serde_json::Deserializer::from_str("10000000000000000");
+
+        let right = deserialize_quantity::<_, U256>(&mut deserializer).unwrap();
+
+        assert_eq!(U256::new(10_000_000_000_000_000), right);
+    }
+
+    #[test]
+    fn deserialize_decimal_string_succeed() {
+        let input = format!("\"{}\"", U256::MAX);
+        let mut deserializer = serde_json::Deserializer::from_str(&input);
+
+        let right = deserialize_quantity::<_, U256>(&mut deserializer).unwrap();
+
+        assert_eq!(U256::MAX, right);
+    }
+
+    #[test]
+    fn deserialize_decimal_string_u128_succeed() {
+        let mut deserializer = serde_json::Deserializer::from_str("\"21000\"");
+
+        let right = deserialize_quantity::<_, u128>(&mut deserializer).unwrap();
+
+        assert_eq!(21_000u128, right);
+    }
+
+    #[test]
+    fn deserialize_signed_decimal_string_fail() {
+        let mut deserializer = serde_json::Deserializer::from_str("\"+21000\"");
+
+        assert!(deserialize_quantity::<_, u128>(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn deserialize_empty_string_fail() {
+        let mut deserializer = serde_json::Deserializer::from_str("\"\"");
+
+        assert!(deserialize_quantity::<_, U256>(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn deserialize_integer_above_u64_succeed() {
+        let input = "100000000000000000001";
+        let left = U256::new(100_000_000_000_000_000_001);
+
+        let mut deserializer = serde_json::Deserializer::from_str(input);
+        let right = deserialize_quantity::<_, U256>(&mut deserializer).unwrap();
+        let value: serde_json::Value = serde_json::from_str(input).unwrap();
+        let right_value = deserialize_quantity::<_, U256>(value).unwrap();
+
+        assert_eq!(left, right);
+        assert_eq!(left, right_value);
+    }
+
+    #[test]
+    fn deserialize_fractional_number_fail() {
+        let mut deserializer = serde_json::Deserializer::from_str("1.5");
+
+        assert!(deserialize_quantity::<_, U256>(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn deserialize_hex_string_succeed() {
+        let mut deserializer = serde_json::Deserializer::from_str("\"0x2386f26fc10000\"");
+
+        let right = deserialize_quantity::<_, U256>(&mut deserializer).unwrap();
+
+        assert_eq!(U256::new(10_000_000_000_000_000), right);
+    }
+
+    #[test]
+    fn deserialize_hex_string_u64_succeed() {
+        let mut deserializer = serde_json::Deserializer::from_str("\"0xaa36a7\"");
+
+        let right = deserialize_quantity::<_, u64>(&mut deserializer).unwrap();
+
+        assert_eq!(11155111u64, right);
+    }
+
+    #[test]
+    fn deserialize_empty_hex_string_fail() {
+        let mut deserializer = serde_json::Deserializer::from_str("\"0x\"");
+
+        assert!(deserialize_quantity::<_, U256>(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn deserialize_hex_string_out_of_range_fail() {
+        let mut deserializer = serde_json::Deserializer::from_str("\"0x10000000000000000\"");
+
+        assert!(deserialize_quantity::<_, u64>(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn deserialize_units_succeed() {
+        let mut deserializer = serde_json::Deserializer::from_str("\"100 gwei\"");
+
+        let right = with_units(|| deserialize_quantity::<_, U256>(&mut deserializer)).unwrap();
+
+        assert_eq!(U256::new(100_000_000_000), right);
+    }
+
+    #[test]
+    fn deserialize_units_not_tolerant_fail() {
+        let mut deserializer = serde_json::Deserializer::from_str("\"100 gwei\"");
+
+        assert!(deserialize_quantity::<_, U256>(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn deserialize_units_not_denominated_fail() {
+        let mut deserializer = serde_json::Deserializer::from_str("\"5 gwei\"");
+
+        assert!(with_units(|| deserialize_quantity::<_, u128>(&mut deserializer)).is_err());
+    }
+
+    #[test]
+    fn serialize_u128_round_trip_succeed() {
+        for left in [21_000u128, u128::MAX] {
+            let mut output = Vec::new();
+            serialize_u128(&left, &mut serde_json::Serializer::new(&mut output)).unwrap();
+            let mut deserializer = serde_json::Deserializer::from_slice(&output);
+
+            let right = deserialize_quantity::<_, u128>(&mut deserializer).unwrap();
+
+            assert_eq!(left, right);
+        }
+    }
+
+    #[test]
+    fn deserialize_negative_integer_fail() {
+        let mut deserializer = serde_json::Deserializer::from_str("-1");
+
+        assert!(deserialize_quantity::<_, U256>(&mut deserializer).is_err());
+    }
+}
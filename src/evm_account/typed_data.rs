@@ -0,0 +1,439 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+
+use ethnum::I256;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use super::{
+    hash::{eip712_hash, keccak256, Keccak256Digest},
+    transaction::{AccountAddress, Bytes, U256},
+};
+
+const DOMAIN_TYPE: &str = "EIP712Domain";
+// Fields of the domain in the order of EIP-712, used if the domain type is not declared.
+const DOMAIN_FIELDS: [(&str, &str); 5] = [
+    ("name", "string"),
+    ("version", "string"),
+    ("chainId", "uint256"),
+    ("verifyingContract", "address"),
+    ("salt", "bytes32"),
+];
+const MAX_INTEGER_BITS: u32 = 256;
+const WORD_LENGTH: usize = 32;
+
+/// Member of a struct type of typed data, e.g. `{"name": "wallet", "type": "address"}`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct TypedDataField {
+    /// Name of the member.
+    pub name: String,
+    /// Type of the member, e.g. `uint256`, `Person` or `Person[]`.
+    #[serde(rename = "type")]
+    pub field_type: String,
+}
+
+/// Typed structured data as signed by `eth_signTypedData_v4` (see
+/// [`EIP-712`](https://eips.ethereum.org/EIPS/eip-712)), e.g. parsed from its JSON:
+///
+/// ```rust
+/// use evm_signer_kms::evm_account::typed_data::TypedData;
+///
+/// let typed_data: TypedData = r#"{
+///     "types": {"Vote": [{"name": "proposal", "type": "uint256"}]},
+///     "primaryType": "Vote",
+///     "domain": {"name": "Governor", "chainId": 1},
+///     "message": {"proposal": "42"}
+/// }"#
+/// .parse()
+/// .unwrap();
+///
+/// assert!(typed_data.signing_hash().is_ok());
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedData {
+    /// Struct types by name. `EIP712Domain` is derived from the domain fields if not declared.
+    pub types: BTreeMap<String, Vec<TypedDataField>>,
+    /// Type of the message.
+    pub primary_type: String,
+    /// Values of the domain fields.
+    pub domain: Map<String, Value>,
+    /// Values of the message fields.
+    pub message: Map<String, Value>,
+}
+
+impl TypedData {
+    /// Computes the domain separator, i.e. the struct hash of the domain.
+    pub fn domain_separator(&self) -> Result<Keccak256Digest, Error> {
+        let domain = Value::Object(self.domain.clone());
+
+        if self.types.contains_key(DOMAIN_TYPE) {
+            return self.hash_struct(DOMAIN_TYPE, &domain);
+        }
+
+        let mut types = self.types.clone();
+        types.insert(
+            DOMAIN_TYPE.to_string(),
+            DOMAIN_FIELDS
+                .iter()
+                .filter(|(name, _)| self.domain.contains_key(*name))
+                .map(|(name, field_type)| TypedDataField {
+                    name: name.to_string(),
+                    field_type: field_type.to_string(),
+                })
+                .collect(),
+        );
+
+        Self {
+            types,
+            ..self.clone()
+        }
+        .hash_struct(DOMAIN_TYPE, &domain)
+    }
+
+    /// Computes the struct hash of the message.
+    pub fn struct_hash(&self) -> Result<Keccak256Digest, Error> {
+        self.hash_struct(&self.primary_type, &Value::Object(self.message.clone()))
+    }
+
+    /// Computes the digest signed by `eth_signTypedData_v4`.
+    pub fn signing_hash(&self) -> Result<Keccak256Digest, Error> {
+        Ok(eip712_hash(&self.domain_separator()?, &self.struct_hash()?))
+    }
+
+    /// Encodes the struct type with the types it references, e.g.
+    /// `Mail(Person from,Person to,string contents)Person(string name,address wallet)`.
+    pub fn encode_type(&self, struct_type: &str) -> Result<String, Error> {
+        let mut referenced_types = BTreeSet::new();
+        self.collect_referenced_types(struct_type, &mut referenced_types);
+        referenced_types.remove(struct_type);
+
+        std::iter::once(struct_type)
+            .chain(referenced_types.iter().map(String::as_str))
+            .map(|type_name| {
+                let fields = self.fields(type_name)?;
+                let members = fields
+                    .iter()
+                    .map(|field| format!("{} {}", field.field_type, field.name))
+                    .collect::<Vec<_>>();
+
+                Ok(format!("{}({})", type_name, members.join(",")))
+            })
+            .collect()
+    }
+
+    fn collect_referenced_types(&self, type_name: &str, referenced_types: &mut BTreeSet<String>) {
+        let Some(fields) = self.types.get(type_name) else {
+            return;
+        };
+        if !referenced_types.insert(type_name.to_string()) {
+            return;
+        }
+
+        for field in fields {
+            self.collect_referenced_types(element_type(&field.field_type), referenced_types);
+        }
+    }
+
+    fn fields(&self, struct_type: &str) -> Result<&[TypedDataField], Error> {
+        self.types
+            .get(struct_type)
+            .map(Vec::as_slice)
+            .ok_or_else(|| invalid_data(&format!("Undeclared type `{}`", struct_type)))
+    }
+
+    fn hash_struct(&self, struct_type: &str, value: &Value) -> Result<Keccak256Digest, Error> {
+        let values = value
+            .as_object()
+            .ok_or_else(|| invalid_data(&format!("Value of `{}` is not an object", struct_type)))?;
+
+        let mut encoding = keccak256(self.encode_type(struct_type)?).to_vec();
+        for field in self.fields(struct_type)? {
+            let value = values.get(&field.name).ok_or_else(|| {
+                invalid_data(&format!("Missing `{}` of `{}`", field.name, struct_type))
+            })?;
+            encoding.extend_from_slice(&self.encode_value(&field.field_type, value)?);
+        }
+
+        Ok(keccak256(encoding))
+    }
+
+    // Encodes the value as a single word, hashing dynamic and reference types.
+    fn encode_value(&self, field_type: &str, value: &Value) -> Result<[u8; WORD_LENGTH], Error> {
+        if let Some(element_type) = array_element_type(field_type) {
+            let elements = value
+                .as_array()
+                .ok_or_else(|| invalid_value(field_type, value))?;
+            let mut encoding = Vec::with_capacity(elements.len() * WORD_LENGTH);
+            for element in elements {
+                encoding.extend_from_slice(&self.encode_value(element_type, element)?);
+            }
+
+            return Ok(keccak256(encoding));
+        }
+
+        if self.types.contains_key(field_type) {
+            return self.hash_struct(field_type, value);
+        }
+
+        encode_atomic(field_type, value)
+    }
+}
+
+impl FromStr for TypedData {
+    type Err = Error;
+
+    /// Parses the typed data JSON accepted by `eth_signTypedData_v4`.
+    fn from_str(json: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(json)
+            .map_err(|error| invalid_data(&format!("Failed to deserialize typed data: {}", error)))
+    }
+}
+
+fn encode_atomic(field_type: &str, value: &Value) -> Result<[u8; WORD_LENGTH], Error> {
+    let invalid = || invalid_value(field_type, value);
+    let mut word = [0u8; WORD_LENGTH];
+
+    match field_type {
+        "address" => {
+            let address: AccountAddress = value.as_str().ok_or_else(invalid)?.parse()?;
+            word[WORD_LENGTH - address.0.len()..].copy_from_slice(&address.0);
+        }
+        "bool" => word[WORD_LENGTH - 1] = u8::from(value.as_bool().ok_or_else(invalid)?),
+        "string" => word = keccak256(value.as_str().ok_or_else(invalid)?),
+        "bytes" => word = keccak256(parse_bytes(value).ok_or_else(invalid)?),
+        _ => {
+            if let Some(bits) = integer_bits(field_type, "uint") {
+                let number = parse_uint(value).ok_or_else(invalid)?;
+                if bits < MAX_INTEGER_BITS && number >> bits != U256::ZERO {
+                    return Err(invalid());
+                }
+                word = number.to_be_bytes();
+            } else if let Some(bits) = integer_bits(field_type, "int") {
+                let number = parse_int(value).ok_or_else(invalid)?;
+                let sign_bits = number >> (bits - 1);
+                if sign_bits != I256::ZERO && sign_bits != I256::MINUS_ONE {
+                    return Err(invalid());
+                }
+                word = number.to_be_bytes();
+            } else if let Some(length) = fixed_bytes_length(field_type) {
+                let bytes = parse_bytes(value).ok_or_else(invalid)?;
+                if bytes.len() != length {
+                    return Err(invalid());
+                }
+                word[..length].copy_from_slice(&bytes);
+            } else {
+                return Err(invalid_data(&format!("Undeclared type `{}`", field_type)));
+            }
+        }
+    }
+
+    Ok(word)
+}
+
+// Number of bits of `uint<M>` or `int<M>`, where `M` is a multiple of 8 up to 256 (or omitted).
+fn integer_bits(field_type: &str, prefix: &str) -> Option<u32> {
+    let bits = field_type.strip_prefix(prefix)?;
+    if bits.is_empty() {
+        return Some(MAX_INTEGER_BITS);
+    }
+
+    bits.parse()
+        .ok()
+        .filter(|bits| *bits > 0 && *bits <= MAX_INTEGER_BITS && bits % 8 == 0)
+}
+
+// Length of `bytes<M>`, where `M` is between 1 and 32.
+fn fixed_bytes_length(field_type: &str) -> Option<usize> {
+    field_type
+        .strip_prefix("bytes")?
+        .parse()
+        .ok()
+        .filter(|length| *length > 0 && *length <= WORD_LENGTH)
+}
+
+// Numbers are accepted as JSON numbers, decimal strings or `0x`-prefixed hex strings.
+fn parse_uint(value: &Value) -> Option<U256> {
+    match value {
+        Value::Number(number) => number.as_u64().map(U256::from),
+        Value::String(number) => match number.strip_prefix("0x") {
+            Some(hex_number) => U256::from_str_radix(hex_number, 16).ok(),
+            None => U256::from_str_radix(number, 10).ok(),
+        },
+        _ => None,
+    }
+}
+
+fn parse_int(value: &Value) -> Option<I256> {
+    match value {
+        Value::Number(number) => number.as_i64().map(I256::from),
+        Value::String(number) => match number.strip_prefix('-') {
+            Some(magnitude) => parse_uint(&Value::String(magnitude.to_string()))
+                .and_then(to_i256)
+                .map(|magnitude| -magnitude),
+            None => parse_uint(value).and_then(to_i256),
+        },
+        _ => None,
+    }
+}
+
+fn to_i256(number: U256) -> Option<I256> {
+    (number <= I256::MAX.as_u256()).then(|| number.as_i256())
+}
+
+fn parse_bytes(value: &Value) -> Option<Vec<u8>> {
+    value
+        .as_str()
+        .and_then(|hex_data| hex_data.parse::<Bytes>().ok())
+        .map(|bytes| bytes.0)
+}
+
+// Element type of `T[]` or `T[k]`.
+fn array_element_type(field_type: &str) -> Option<&str> {
+    field_type
+        .strip_suffix(']')
+        .and_then(|field_type| field_type.rsplit_once('['))
+        .map(|(element_type, _)| element_type)
+}
+
+// Innermost element type, e.g. `Person` of `Person[][2]`.
+fn element_type(field_type: &str) -> &str {
+    match array_element_type(field_type) {
+        Some(element_type) => self::element_type(element_type),
+        None => field_type,
+    }
+}
+
+fn invalid_value(field_type: &str, value: &Value) -> Error {
+    invalid_data(&format!("Invalid `{}` value: {}", field_type, value))
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    // `Mail` example of EIP-712
+    const TEST_TYPED_DATA_JSON: &str = r#"{
+        "types": {
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "version", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"}
+            ],
+            "Person": [
+                {"name": "name", "type": "string"},
+                {"name": "wallet", "type": "address"}
+            ],
+            "Mail": [
+                {"name": "from", "type": "Person"},
+                {"name": "to", "type": "Person"},
+                {"name": "contents", "type": "string"}
+            ]
+        },
+        "primaryType": "Mail",
+        "domain": {
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        },
+        "message": {
+            "from": {"name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},
+            "to": {"name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},
+            "contents": "Hello, Bob!"
+        }
+    }"#;
+
+    #[test]
+    fn encode_type_succeed() {
+        let input: TypedData = TEST_TYPED_DATA_JSON.parse().unwrap();
+
+        let right = input.encode_type("Mail").unwrap();
+
+        assert_eq!(
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)",
+            right
+        );
+    }
+
+    #[test]
+    fn signing_hash_succeed() {
+        let input: TypedData = TEST_TYPED_DATA_JSON.parse().unwrap();
+
+        assert_eq!(
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f",
+            hex::encode(input.domain_separator().unwrap())
+        );
+        assert_eq!(
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e",
+            hex::encode(input.struct_hash().unwrap())
+        );
+        assert_eq!(
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2",
+            hex::encode(input.signing_hash().unwrap())
+        );
+    }
+
+    #[test]
+    fn domain_separator_undeclared_domain_type_succeed() {
+        let left: TypedData = TEST_TYPED_DATA_JSON.parse().unwrap();
+        let mut right = left.clone();
+        right.types.remove(DOMAIN_TYPE);
+
+        assert_eq!(
+            left.domain_separator().unwrap(),
+            right.domain_separator().unwrap()
+        );
+    }
+
+    #[test]
+    fn encode_atomic_succeed() {
+        let left = [0xff; WORD_LENGTH];
+
+        assert_eq!(left, encode_atomic("int8", &Value::from(-1)).unwrap());
+        assert_eq!(left, encode_atomic("int256", &Value::from("-1")).unwrap());
+        assert_eq!(
+            U256::new(255).to_be_bytes(),
+            encode_atomic("uint8", &Value::from("0xff")).unwrap()
+        );
+        let mut fixed_bytes = [0u8; WORD_LENGTH];
+        fixed_bytes[0] = 0xab;
+        assert_eq!(
+            fixed_bytes,
+            encode_atomic("bytes1", &Value::from("0xab")).unwrap()
+        );
+    }
+
+    #[test]
+    fn encode_atomic_out_of_range_fail() {
+        assert!(encode_atomic("uint8", &Value::from(256)).is_err());
+        assert!(encode_atomic("int8", &Value::from(-129)).is_err());
+        assert!(encode_atomic("bytes2", &Value::from("0xab")).is_err());
+        assert!(encode_atomic("uint7", &Value::from(1)).is_err());
+    }
+
+    #[test]
+    fn struct_hash_missing_field_fail() {
+        let mut input: TypedData = TEST_TYPED_DATA_JSON.parse().unwrap();
+        input.message.remove("contents");
+
+        let error = input.struct_hash().unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn array_element_type_succeed() {
+        assert_eq!(Some("Person[]"), array_element_type("Person[][2]"));
+        assert_eq!("Person", element_type("Person[][2]"));
+        assert_eq!(None, array_element_type("Person"));
+    }
+}
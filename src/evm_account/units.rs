@@ -0,0 +1,264 @@
+use std::{
+    fmt::{self, Display},
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+
+use super::transaction::U256;
+
+const DECIMAL_RADIX: u32 = 10;
+const DECIMAL_SEPARATOR: char = '.';
+
+/// Denominations of ether.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unit {
+    /// The smallest denomination, i.e. 10^0 wei.
+    Wei,
+    /// 10^3 wei.
+    Kwei,
+    /// 10^6 wei.
+    Mwei,
+    /// 10^9 wei, commonly used for gas prices.
+    Gwei,
+    /// 10^12 wei.
+    Szabo,
+    /// 10^15 wei.
+    Finney,
+    /// 10^18 wei.
+    Ether,
+}
+
+impl Unit {
+    /// Number of decimal places between the unit and wei.
+    pub fn decimals(&self) -> u32 {
+        match self {
+            Unit::Wei => 0,
+            Unit::Kwei => 3,
+            Unit::Mwei => 6,
+            Unit::Gwei => 9,
+            Unit::Szabo => 12,
+            Unit::Finney => 15,
+            Unit::Ether => 18,
+        }
+    }
+
+    /// Amount of wei in one unit.
+    pub fn multiplier(&self) -> U256 {
+        U256::from(DECIMAL_RADIX).pow(self.decimals())
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Unit::Wei => "wei",
+            Unit::Kwei => "kwei",
+            Unit::Mwei => "mwei",
+            Unit::Gwei => "gwei",
+            Unit::Szabo => "szabo",
+            Unit::Finney => "finney",
+            Unit::Ether => "ether",
+        };
+
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Unit {
+    type Err = Error;
+
+    /// Parses the unit name case-insensitively. `eth` is accepted as an alias for `ether`.
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        match unit.to_ascii_lowercase().as_str() {
+            "wei" => Ok(Unit::Wei),
+            "kwei" => Ok(Unit::Kwei),
+            "mwei" => Ok(Unit::Mwei),
+            "gwei" => Ok(Unit::Gwei),
+            "szabo" => Ok(Unit::Szabo),
+            "finney" => Ok(Unit::Finney),
+            "ether" | "eth" => Ok(Unit::Ether),
+            _ => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unknown unit: {}", unit),
+            )),
+        }
+    }
+}
+
+/// Amount of gwei, e.g. for specifying gas prices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Gwei(pub u128);
+
+impl From<Gwei> for U256 {
+    fn from(amount: Gwei) -> Self {
+        U256::new(amount.0) * Unit::Gwei.multiplier()
+    }
+}
+
+/// Amount of ether, e.g. for specifying transferred value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ether(pub u128);
+
+impl From<Ether> for U256 {
+    fn from(amount: Ether) -> Self {
+        U256::new(amount.0) * Unit::Ether.multiplier()
+    }
+}
+
+/// Converts a decimal amount denominated in `unit` to wei, e.g. `("1.5", Unit::Gwei)`.
+///
+/// Fails if the amount is not a valid decimal number, has more fractional digits than the unit
+/// allows (i.e. it is not an integer amount of wei) or overflows 256 bits.
+pub fn parse_amount(amount: &str, unit: Unit) -> Result<U256, Error> {
+    let invalid_amount = || {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("Invalid amount: {}", amount),
+        )
+    };
+
+    let (integer, fraction) = amount.split_once(DECIMAL_SEPARATOR).unwrap_or((amount, ""));
+    let fraction = fraction.trim_end_matches('0');
+
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|ch| ch.is_ascii_digit())
+    {
+        return Err(invalid_amount());
+    }
+
+    let decimals = unit.decimals() as usize;
+    if fraction.len() > decimals {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("Amount {} {} is not an integer amount of wei", amount, unit),
+        ));
+    }
+
+    let digits = format!(
+        "{}{}{}",
+        integer,
+        fraction,
+        "0".repeat(decimals - fraction.len())
+    );
+
+    U256::from_str_radix(&digits, DECIMAL_RADIX).map_err(|_| invalid_amount())
+}
+
+/// Parses a human readable amount with an optional unit to wei, e.g. `"1.5 gwei"` or `"0.01eth"`.
+///
+/// Amounts without a unit are treated as wei.
+pub fn parse_units(value: &str) -> Result<U256, Error> {
+    let value = value.trim();
+    let unit_start = value
+        .find(|ch: char| !(ch.is_ascii_digit() || ch == DECIMAL_SEPARATOR))
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(unit_start);
+
+    let unit = match unit.trim() {
+        "" => Unit::Wei,
+        unit => unit.parse()?,
+    };
+
+    parse_amount(amount, unit)
+}
+
+/// Formats an amount of wei as a decimal number denominated in `unit`, e.g. `"1.5"` for
+/// `1_500_000_000` wei in `Unit::Gwei`. Insignificant trailing zeros are omitted.
+pub fn format_units(value: U256, unit: Unit) -> String {
+    let multiplier = unit.multiplier();
+    let integer = value / multiplier;
+    let fraction = value % multiplier;
+
+    if fraction == U256::ZERO {
+        return integer.to_string();
+    }
+
+    let fraction = fraction.to_string();
+    let padding = "0".repeat(unit.decimals() as usize - fraction.len());
+
+    format!(
+        "{}{}{}{}",
+        integer,
+        DECIMAL_SEPARATOR,
+        padding,
+        fraction.trim_end_matches('0')
+    )
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn parse_units_gwei_succeed() {
+        let left = U256::new(1_500_000_000);
+
+        let right = parse_units("1.5 gwei").unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn parse_units_ether_no_whitespace_succeed() {
+        let left = U256::new(10_000_000_000_000_000);
+
+        let right = parse_units("0.01ETH").unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn parse_units_no_unit_succeed() {
+        let left = U256::new(21_000);
+
+        let right = parse_units("21000").unwrap();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn parse_units_sub_wei_fail() {
+        assert!(parse_units("1.5 wei").is_err());
+    }
+
+    #[test]
+    fn parse_units_unknown_unit_fail() {
+        assert!(parse_units("1 bitcoin").is_err());
+    }
+
+    #[test]
+    fn parse_units_invalid_amount_fail() {
+        assert!(parse_units("1.2.3 gwei").is_err());
+        assert!(parse_units("gwei").is_err());
+    }
+
+    #[test]
+    fn format_units_fraction_succeed() {
+        let input = U256::new(1_500_000_000);
+
+        assert_eq!("1.5", format_units(input, Unit::Gwei));
+    }
+
+    #[test]
+    fn format_units_leading_fraction_zeros_succeed() {
+        let input = U256::new(10_000_000_000_000_000);
+
+        assert_eq!("0.01", format_units(input, Unit::Ether));
+    }
+
+    #[test]
+    fn format_units_integer_succeed() {
+        let input = U256::new(100_000_000_000);
+
+        assert_eq!("100", format_units(input, Unit::Gwei));
+    }
+
+    #[test]
+    fn typed_wrappers_succeed() {
+        assert_eq!(U256::new(100_000_000_000), U256::from(Gwei(100)));
+        assert_eq!(U256::new(2_000_000_000_000_000_000), U256::from(Ether(2)));
+    }
+}
@@ -0,0 +1,119 @@
+use std::io;
+
+use tokio::{runtime::Handle, task};
+use web3::{
+    signing::{Key, Signature as Web3Signature, SigningError},
+    types::{Address, H256},
+};
+
+use super::{
+    hash::Keccak256Digest,
+    signature::Signature,
+    transaction::{eip155_v, legacy_v},
+    EvmAccount,
+};
+
+/// Key of the [rust-web3](https://github.com/tomusdrw/rust-web3) signing hooks, e.g.
+/// `Accounts::sign_transaction`, backed by the KMS key of the account.
+///
+/// web3 hands over the hash of the transaction only, so the signing policy of the account is not
+/// verified. As web3 signs synchronously, the KMS call is made with
+/// `tokio::task::block_in_place`, which requires the multi-threaded tokio runtime.
+///
+/// ```rust,no_run
+/// use evm_signer_kms::evm_account::{kms_key::KmsKey, web3::Web3Key, EvmAccount};
+/// use web3::{transports::Http, types::TransactionParameters, Web3};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let kms_key = KmsKey::new("alias/treasury").await;
+///     let evm_account = EvmAccount::new(&kms_key).await?;
+///     let web3 = Web3::new(Http::new("https://sepolia.example.com")?);
+///
+///     let tx = TransactionParameters::default();
+///     let signed_tx = web3
+///         .accounts()
+///         .sign_transaction(tx, Web3Key::new(&evm_account))
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Web3Key<'a> {
+    evm_account: &'a EvmAccount<'a>,
+    runtime: Handle,
+}
+
+impl<'a> Web3Key<'a> {
+    /// Creates the key of the account.
+    ///
+    /// Panics if called outside of a tokio runtime.
+    pub fn new(evm_account: &'a EvmAccount<'a>) -> Self {
+        Self {
+            evm_account,
+            runtime: Handle::current(),
+        }
+    }
+
+    fn sign_hash(&self, message: &[u8]) -> Result<Signature, SigningError> {
+        let digest: Keccak256Digest = message
+            .try_into()
+            .map_err(|_| SigningError::InvalidMessage)?;
+
+        task::block_in_place(|| self.runtime.block_on(self.evm_account.sign_digest(&digest)))
+            .map_err(|_: io::Error| SigningError::InvalidMessage)
+    }
+}
+
+impl Key for Web3Key<'_> {
+    /// Signs the hash of a legacy transaction, with the `v` value replay protected according to
+    /// [`EIP-155`](https://eips.ethereum.org/EIPS/eip-155) if the chain ID is provided.
+    fn sign(&self, message: &[u8], chain_id: Option<u64>) -> Result<Web3Signature, SigningError> {
+        let signature = self.sign_hash(message)?;
+        let y_parity = signature.y_parity().into();
+        let v = match chain_id {
+            Some(chain_id) => {
+                eip155_v(y_parity, chain_id).map_err(|_| SigningError::InvalidMessage)?
+            }
+            None => legacy_v(y_parity).into(),
+        };
+
+        Ok(to_web3_signature(&signature, v))
+    }
+
+    /// Signs the hash of a typed transaction or a message, with the `v` value being the parity.
+    fn sign_message(&self, message: &[u8]) -> Result<Web3Signature, SigningError> {
+        let signature = self.sign_hash(message)?;
+
+        Ok(to_web3_signature(&signature, signature.y_parity().into()))
+    }
+
+    fn address(&self) -> Address {
+        Address::from(self.evm_account.address().0)
+    }
+}
+
+fn to_web3_signature(signature: &Signature, v: u64) -> Web3Signature {
+    Web3Signature {
+        v,
+        r: H256::from(*signature.r()),
+        s: H256::from(*signature.s()),
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn to_web3_signature_succeed() {
+        let input = Signature::new([0x11; 32], [0x22; 32], 1);
+
+        let right = to_web3_signature(&input, eip155_v(1, 11155111).unwrap());
+
+        assert_eq!(22310258, right.v);
+        assert_eq!(H256::from([0x11; 32]), right.r);
+        assert_eq!(H256::from([0x22; 32]), right.s);
+    }
+}
@@ -0,0 +1,289 @@
+use std::{
+    cell::RefCell,
+    ffi::{c_char, c_int, CStr, CString},
+    io::{Error, ErrorKind},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+use crate::{
+    evm_account::{
+        policy::SigningPolicy,
+        transaction::{json::Mode, Bytes},
+    },
+    owned_account::OwnedAccount,
+};
+
+/// The call succeeded.
+pub const EVM_SIGNER_KMS_OK: c_int = 0;
+/// An argument is null, not valid UTF-8 or invalid, e.g. malformed transaction JSON.
+pub const EVM_SIGNER_KMS_INVALID_ARGUMENT: c_int = 1;
+/// KMS denied the request or the transaction violates the signing policy.
+pub const EVM_SIGNER_KMS_PERMISSION_DENIED: c_int = 2;
+/// The KMS key was not found.
+pub const EVM_SIGNER_KMS_NOT_FOUND: c_int = 3;
+/// Any other failure, including panics.
+pub const EVM_SIGNER_KMS_ERROR: c_int = 4;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Opaque handle of an account backed by a KMS key, created with `evm_signer_kms_account_new`.
+///
+/// Each handle owns the runtime its KMS calls are blocked on, so the functions can be called from
+/// any thread of the host application.
+pub struct EvmSignerKmsAccount {
+    owned_account: OwnedAccount,
+}
+
+/// Creates the account of the KMS key, with the AWS configuration from the environment, and
+/// stores its handle in `account`. The handle has to be released with
+/// `evm_signer_kms_account_free`.
+///
+/// # Safety
+///
+/// `key_id` has to be a valid NUL terminated string and `account` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn evm_signer_kms_account_new(
+    key_id: *const c_char,
+    account: *mut *mut EvmSignerKmsAccount,
+) -> c_int {
+    call(|| {
+        let key_id = to_str(key_id)?;
+        check_out(account)?;
+        let owned_account = OwnedAccount::new(key_id.to_string(), SigningPolicy::default())?;
+
+        *account = Box::into_raw(Box::new(EvmSignerKmsAccount { owned_account }));
+        Ok(())
+    })
+}
+
+/// Releases the account. Null is ignored.
+///
+/// # Safety
+///
+/// `account` has to be null or a handle created with `evm_signer_kms_account_new`, which is not
+/// used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn evm_signer_kms_account_free(account: *mut EvmSignerKmsAccount) {
+    if !account.is_null() {
+        drop(Box::from_raw(account));
+    }
+}
+
+/// Stores the checksummed address of the account in `address`. The string has to be released
+/// with `evm_signer_kms_string_free`.
+///
+/// # Safety
+///
+/// `account` has to be a handle created with `evm_signer_kms_account_new` and `address` a valid
+/// pointer.
+#[no_mangle]
+pub unsafe extern "C" fn evm_signer_kms_account_address(
+    account: *const EvmSignerKmsAccount,
+    address: *mut *mut c_char,
+) -> c_int {
+    call(|| {
+        let account = to_account(account)?;
+        check_out(address)?;
+
+        *address = to_c_string(account.evm_account().checksummed_address().to_string())?;
+        Ok(())
+    })
+}
+
+/// Signs the transaction JSON of any supported type, enforcing the default signing policy, and
+/// stores the hex encoding of the signed transaction in `raw_tx`. The string has to be released
+/// with `evm_signer_kms_string_free`.
+///
+/// Fields which are not part of the transaction format are ignored.
+///
+/// # Safety
+///
+/// `account` has to be a handle created with `evm_signer_kms_account_new`, `tx_json` a valid NUL
+/// terminated string and `raw_tx` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn evm_signer_kms_sign_transaction_json(
+    account: *const EvmSignerKmsAccount,
+    tx_json: *const c_char,
+    raw_tx: *mut *mut c_char,
+) -> c_int {
+    call(|| {
+        let account = to_account(account)?;
+        let tx_json = to_str(tx_json)?;
+        check_out(raw_tx)?;
+
+        let encoding = account.block_on(
+            account
+                .evm_account()
+                .sign_transaction_json(tx_json, Mode::Lenient),
+        )?;
+        *raw_tx = to_c_string(Bytes(encoding).to_string())?;
+        Ok(())
+    })
+}
+
+/// Releases a string returned by the library. Null is ignored.
+///
+/// # Safety
+///
+/// `string` has to be null or a string returned by the library, which is not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn evm_signer_kms_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Returns the message of the last error on the calling thread, or null if the last call
+/// succeeded. The string is owned by the library and valid until the next call on the thread.
+#[no_mangle]
+pub extern "C" fn evm_signer_kms_last_error() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+// Runs the body of an exported function, recording its error and mapping it to a status code.
+// Panics must not unwind into the caller.
+fn call(body: impl FnOnce() -> Result<(), Error>) -> c_int {
+    let result = panic::catch_unwind(AssertUnwindSafe(body))
+        .unwrap_or_else(|_| Err(Error::other("Unexpected panic")));
+
+    let (status, message) = match result {
+        Ok(()) => (EVM_SIGNER_KMS_OK, None),
+        Err(error) => (status_code(&error), CString::new(error.to_string()).ok()),
+    };
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+
+    status
+}
+
+fn status_code(error: &Error) -> c_int {
+    match error.kind() {
+        ErrorKind::InvalidInput | ErrorKind::InvalidData | ErrorKind::Unsupported => {
+            EVM_SIGNER_KMS_INVALID_ARGUMENT
+        }
+        ErrorKind::PermissionDenied => EVM_SIGNER_KMS_PERMISSION_DENIED,
+        ErrorKind::NotFound => EVM_SIGNER_KMS_NOT_FOUND,
+        _ => EVM_SIGNER_KMS_ERROR,
+    }
+}
+
+unsafe fn to_str<'a>(string: *const c_char) -> Result<&'a str, Error> {
+    if string.is_null() {
+        return Err(null_argument());
+    }
+
+    CStr::from_ptr(string)
+        .to_str()
+        .map_err(|error| Error::new(ErrorKind::InvalidInput, error))
+}
+
+unsafe fn to_account<'a>(account: *const EvmSignerKmsAccount) -> Result<&'a OwnedAccount, Error> {
+    account
+        .as_ref()
+        .map(|account| &account.owned_account)
+        .ok_or_else(null_argument)
+}
+
+fn check_out<T>(out: *mut T) -> Result<(), Error> {
+    if out.is_null() {
+        return Err(null_argument());
+    }
+
+    Ok(())
+}
+
+fn to_c_string(string: String) -> Result<*mut c_char, Error> {
+    CString::new(string)
+        .map(CString::into_raw)
+        .map_err(|error| Error::new(ErrorKind::InvalidData, error))
+}
+
+fn null_argument() -> Error {
+    Error::new(ErrorKind::InvalidInput, "Argument must not be null")
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn last_error() -> Option<String> {
+        let message = evm_signer_kms_last_error();
+        if message.is_null() {
+            return None;
+        }
+
+        // SAFETY: Valid until the next call on the thread
+        Some(
+            unsafe { CStr::from_ptr(message) }
+                .to_str()
+                .unwrap()
+                .to_string(),
+        )
+    }
+
+    #[test]
+    fn account_new_null_key_id_fail() {
+        let mut account = ptr::null_mut();
+
+        let right = unsafe { evm_signer_kms_account_new(ptr::null(), &mut account) };
+
+        assert_eq!(EVM_SIGNER_KMS_INVALID_ARGUMENT, right);
+        assert_eq!(Some("Argument must not be null".to_string()), last_error());
+        assert!(account.is_null());
+    }
+
+    #[test]
+    fn sign_transaction_json_null_account_fail() {
+        let input = CString::new("{}").unwrap();
+        let mut raw_tx = ptr::null_mut();
+
+        let right = unsafe {
+            evm_signer_kms_sign_transaction_json(ptr::null(), input.as_ptr(), &mut raw_tx)
+        };
+
+        assert_eq!(EVM_SIGNER_KMS_INVALID_ARGUMENT, right);
+        assert!(raw_tx.is_null());
+    }
+
+    #[test]
+    fn call_succeed() {
+        call(|| Err(Error::from(ErrorKind::NotFound)));
+
+        let right = call(|| Ok(()));
+
+        assert_eq!(EVM_SIGNER_KMS_OK, right);
+        assert_eq!(None, last_error());
+    }
+
+    #[test]
+    fn call_panic_fail() {
+        let right = call(|| panic!("Unexpected"));
+
+        assert_eq!(EVM_SIGNER_KMS_ERROR, right);
+        assert_eq!(Some("Unexpected panic".to_string()), last_error());
+    }
+
+    #[test]
+    fn status_code_succeed() {
+        let input = Error::from(ErrorKind::PermissionDenied);
+
+        assert_eq!(EVM_SIGNER_KMS_PERMISSION_DENIED, status_code(&input));
+    }
+
+    #[test]
+    fn string_free_succeed() {
+        let input = to_c_string("0xabcd".to_string()).unwrap();
+
+        unsafe {
+            evm_signer_kms_string_free(input);
+            evm_signer_kms_string_free(ptr::null_mut());
+        }
+    }
+}
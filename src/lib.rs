@@ -13,9 +13,11 @@
 //! ## Free market transaction (i.e. type 2 transaction)
 //!
 //! The following example demonstrates how to sign an
-//! [`EIP-1559`](https://eips.ethereum.org/EIPS/eip-1559) transaction:
+//! [`EIP-1559`](https://eips.ethereum.org/EIPS/eip-1559) transaction (with the default `aws`
+//! feature):
 //!
-//! ```rust
+#![cfg_attr(feature = "aws", doc = "```rust")]
+#![cfg_attr(not(feature = "aws"), doc = "```ignore")]
 //! use evm_signer_kms::evm_account::{
 //!     kms_key::KmsKey,
 //!     transaction::{
@@ -131,6 +133,10 @@
 //!
 //! You will also want to serialize the transaction as an `AccessListTransaction`.
 //!
+//! Access list entries can also be specified in the object format used by JSON-RPC, i.e.
+//! `{"address": "0x...", "storageKeys": ["0x..."]}`. The format of each entry is detected
+//! automatically, and transactions are always serialized using the object format.
+//!
 //! ## Legacy transaction (i.e. type 0 transaction)
 //!
 //! Legacy transactions are also supported. You can use the `LegacyTransaction` struct during
@@ -149,6 +155,42 @@
 //! "#;
 //! ```
 //!
+//! ## Numeric fields
+//!
+//! Numeric fields (e.g. `value`, `nonce` or `chainId`) can be specified as JSON numbers, decimal
+//! strings (e.g. `"10000000000000000"`) or JSON-RPC style hex quantities
+//! (e.g. `"0x2386f26fc10000"`). Many JSON producers (e.g. JavaScript backends) lose precision for
+//! numbers above 2^53, so JSON numbers which cannot be represented exactly are rejected. Use one of
+//! the string forms instead.
+//!
+//! ## Strict deserialization
+//!
+//! By default, fields which are not part of the transaction format are silently ignored. Use the
+//! [`json`](evm_account::transaction::json) module to reject them instead, e.g. to catch typos:
+//!
+//! ```rust
+//! use evm_signer_kms::evm_account::transaction::{
+//!     json::{self, Mode},
+//!     legacy_transaction::LegacyTransaction,
+//! };
+//!
+//! const LEGACY_TX_JSON: &str = r#"
+//! {
+//!     "gasLimit": 21000,
+//!     "gasPrice": 100000000000,
+//!     "gasLimt": 21000,
+//!     "nonce": 0,
+//!     "to": "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+//!     "value": 10000000000000000,
+//!     "data": ""
+//! }
+//! "#;
+//!
+//! // The misspelled `gasLimt` is ignored, unless deserializing strictly
+//! assert!(json::from_str::<LegacyTransaction>(LEGACY_TX_JSON, Mode::Lenient).is_ok());
+//! assert!(json::from_str::<LegacyTransaction>(LEGACY_TX_JSON, Mode::Strict).is_err());
+//! ```
+//!
 //! # Key policy requirements
 //!
 //! The principal your code is going to be executing as needs to have the necessary permissions to
@@ -199,3 +241,18 @@
 
 /// Provides abstraction for EVM accounts to sign transactions using AWS KMS keys.
 pub mod evm_account;
+/// C ABI of the signer for non-Rust services, e.g. Go or C++ (see `include/evm_signer_kms.h`).
+#[cfg(feature = "ffi")]
+pub mod ffi;
+/// Kotlin and Swift bindings of the signer for wallet apps, generated with uniffi-bindgen.
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+/// Account owning its KMS key, shared by the bindings.
+#[cfg(any(feature = "ffi", feature = "python", feature = "uniffi"))]
+mod owned_account;
+/// Python bindings of the signer, built with maturin (see `pyproject.toml`).
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
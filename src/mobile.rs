@@ -0,0 +1,209 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, ErrorKind},
+    sync::Arc,
+};
+
+use crate::{
+    evm_account::{
+        hash::Keccak256Digest,
+        policy::SigningPolicy,
+        signature::Signature,
+        transaction::{decode_to_json, json::Mode, Bytes},
+        typed_data::TypedData,
+    },
+    owned_account::OwnedAccount,
+};
+
+/// Error raised to Kotlin and Swift, classified by the `ErrorKind` of the underlying error.
+#[derive(Debug, uniffi::Error)]
+pub enum SignerError {
+    /// Invalid or unsupported input, e.g. malformed transaction JSON.
+    InvalidArgument { message: String },
+    /// KMS denied the request or the transaction violates the signing policy.
+    PermissionDenied { message: String },
+    /// The KMS key was not found.
+    NotFound { message: String },
+    /// Any other failure.
+    Other { message: String },
+}
+
+impl Display for SignerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SignerError::InvalidArgument { message }
+            | SignerError::PermissionDenied { message }
+            | SignerError::NotFound { message }
+            | SignerError::Other { message } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for SignerError {}
+
+impl From<io::Error> for SignerError {
+    fn from(error: io::Error) -> Self {
+        let message = error.to_string();
+
+        match error.kind() {
+            ErrorKind::InvalidInput | ErrorKind::InvalidData | ErrorKind::Unsupported => {
+                SignerError::InvalidArgument { message }
+            }
+            ErrorKind::PermissionDenied => SignerError::PermissionDenied { message },
+            ErrorKind::NotFound => SignerError::NotFound { message },
+            _ => SignerError::Other { message },
+        }
+    }
+}
+
+/// Strictness of transaction JSON deserialization (see `json::Mode`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum TxJsonMode {
+    Strict,
+    Lenient,
+    Tolerant,
+}
+
+impl From<TxJsonMode> for Mode {
+    fn from(mode: TxJsonMode) -> Self {
+        match mode {
+            TxJsonMode::Strict => Mode::Strict,
+            TxJsonMode::Lenient => Mode::Lenient,
+            TxJsonMode::Tolerant => Mode::Tolerant,
+        }
+    }
+}
+
+/// Account backed by a KMS key.
+///
+/// Methods block the calling thread on KMS calls, so they should not be called on the main
+/// thread of the app.
+#[derive(uniffi::Object)]
+pub struct Account {
+    account: OwnedAccount,
+}
+
+#[uniffi::export]
+impl Account {
+    /// Creates the account of the KMS key, with the AWS configuration from the environment,
+    /// enforcing the signing policy made of the optional rules.
+    #[uniffi::constructor(default(permitted_chain_ids = None, max_data_length = None))]
+    pub fn new(
+        key_id: String,
+        permitted_chain_ids: Option<Vec<u64>>,
+        max_data_length: Option<u64>,
+    ) -> Result<Arc<Self>, SignerError> {
+        let mut policy = SigningPolicy::new();
+        if let Some(permitted_chain_ids) = permitted_chain_ids {
+            policy = policy.with_permitted_chain_ids(permitted_chain_ids);
+        }
+        if let Some(max_data_length) = max_data_length {
+            let max_data_length = usize::try_from(max_data_length)
+                .map_err(|error| io::Error::new(ErrorKind::InvalidInput, error))?;
+            policy = policy.with_max_data_length(max_data_length);
+        }
+
+        Ok(Arc::new(Self {
+            account: OwnedAccount::new(key_id, policy)?,
+        }))
+    }
+
+    /// Checksummed address of the account.
+    pub fn address(&self) -> String {
+        self.account.evm_account().checksummed_address().to_string()
+    }
+
+    /// Raw, uncompressed 64-byte public key of the account.
+    pub fn public_key(&self) -> Vec<u8> {
+        self.account.evm_account().public_key.to_vec()
+    }
+
+    /// Signs the transaction JSON of any supported type and returns the signed transaction in hex.
+    #[uniffi::method(default(mode = TxJsonMode::Lenient))]
+    pub fn sign_transaction_json(
+        &self,
+        tx_json: String,
+        mode: TxJsonMode,
+    ) -> Result<String, SignerError> {
+        let encoding = self.account.block_on(
+            self.account
+                .evm_account()
+                .sign_transaction_json(&tx_json, mode.into()),
+        )?;
+
+        Ok(Bytes(encoding).to_string())
+    }
+
+    /// Signs the message according to EIP-191 (i.e. `personal_sign`) and returns the 65-byte
+    /// signature in hex.
+    pub fn sign_message(&self, message: Vec<u8>) -> Result<String, SignerError> {
+        let signature = self
+            .account
+            .block_on(self.account.evm_account().sign_message(message))?;
+
+        Ok(signature.to_string())
+    }
+
+    /// Signs the EIP-712 typed data JSON (i.e. `eth_signTypedData_v4`) and returns the 65-byte
+    /// signature in hex.
+    pub fn sign_typed_data(&self, typed_data_json: String) -> Result<String, SignerError> {
+        let typed_data: TypedData = typed_data_json.parse()?;
+        let signature = self
+            .account
+            .block_on(self.account.evm_account().sign_typed_data(&typed_data))?;
+
+        Ok(signature.to_string())
+    }
+}
+
+/// Decodes the signed transaction in hex into the JSON object of its fields, as printed by `cast`.
+#[uniffi::export]
+pub fn decode_transaction(raw_tx: String) -> Result<String, SignerError> {
+    let raw_tx: Bytes = raw_tx.parse()?;
+
+    Ok(decode_to_json(&raw_tx.0)?.to_string())
+}
+
+/// Recovers the checksummed address which signed the 32-byte digest with the 65-byte signature.
+#[uniffi::export]
+pub fn recover_signer(digest: Vec<u8>, signature: Vec<u8>) -> Result<String, SignerError> {
+    let digest: Keccak256Digest =
+        digest
+            .as_slice()
+            .try_into()
+            .map_err(|_| SignerError::InvalidArgument {
+                message: "Digest must be 32 bytes long".to_string(),
+            })?;
+    let signature = Signature::try_from(signature.as_slice())?;
+
+    Ok(signature.recover(&digest)?.to_string())
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn signer_error_from_io_error_succeed() {
+        let input = io::Error::new(ErrorKind::PermissionDenied, "Chain ID 1 is not permitted");
+
+        let right = SignerError::from(input);
+
+        assert!(matches!(right, SignerError::PermissionDenied { .. }));
+        assert_eq!("Chain ID 1 is not permitted", right.to_string());
+    }
+
+    #[test]
+    fn recover_signer_short_digest_fail() {
+        let right = recover_signer(vec![0u8; 31], vec![0u8; 65]).unwrap_err();
+
+        assert!(matches!(right, SignerError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    fn decode_transaction_fail() {
+        let right = decode_transaction("0xzz".to_string()).unwrap_err();
+
+        assert!(matches!(right, SignerError::InvalidArgument { .. }));
+    }
+}
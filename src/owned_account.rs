@@ -0,0 +1,70 @@
+use std::{future::Future, io::Error, mem::ManuallyDrop};
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::evm_account::{kms_key::KmsKey, policy::SigningPolicy, EvmAccount};
+
+/// Account which owns its KMS key, the key ID and the runtime its KMS calls are blocked on, for
+/// the bindings whose handles cannot carry the lifetime of a borrowed key.
+pub(crate) struct OwnedAccount {
+    // Borrows `kms_key`, so it is dropped before the key is released
+    evm_account: ManuallyDrop<EvmAccount<'static>>,
+    kms_key: *mut KmsKey<'static>,
+    key_id: *mut str,
+    runtime: Runtime,
+}
+
+// SAFETY: The key and its ID are owned by the account and only borrowed immutably by
+// `evm_account`, which can be sent and shared across threads.
+unsafe impl Send for OwnedAccount {}
+unsafe impl Sync for OwnedAccount {}
+
+impl OwnedAccount {
+    /// Creates the account of the KMS key, with the AWS configuration from the environment,
+    /// enforcing the signing policy. Blocks the calling thread on the KMS calls.
+    pub(crate) fn new(key_id: String, policy: SigningPolicy) -> Result<Self, Error> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+
+        let key_id: *mut str = Box::into_raw(key_id.into_boxed_str());
+        // SAFETY: Released in `Drop` after the account borrowing them, or here if the account
+        // cannot be created
+        unsafe {
+            let kms_key = Box::into_raw(Box::new(runtime.block_on(KmsKey::new(&*key_id))));
+            let evm_account = runtime
+                .block_on(EvmAccount::with_policy(&*kms_key, policy))
+                .inspect_err(|_| {
+                    drop(Box::from_raw(kms_key));
+                    drop(Box::from_raw(key_id));
+                })?;
+
+            Ok(Self {
+                evm_account: ManuallyDrop::new(evm_account),
+                kms_key,
+                key_id,
+                runtime,
+            })
+        }
+    }
+
+    /// Account borrowing the owned key, which cannot outlive `self`.
+    pub(crate) fn evm_account(&self) -> &EvmAccount<'_> {
+        &self.evm_account
+    }
+
+    /// Blocks the calling thread on the future, e.g. a KMS call of the account.
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+impl Drop for OwnedAccount {
+    fn drop(&mut self) {
+        // SAFETY: Both were leaked by `OwnedAccount::new` and are only borrowed by `evm_account`,
+        // which is dropped first and never used again.
+        unsafe {
+            ManuallyDrop::drop(&mut self.evm_account);
+            drop(Box::from_raw(self.kms_key));
+            drop(Box::from_raw(self.key_id));
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::io::{Error, ErrorKind};
+
+use crate::{
+    evm_account::{
+        hash::Keccak256Digest,
+        policy::SigningPolicy,
+        signature::Signature,
+        transaction::{decode_to_json, json::Mode, Bytes},
+        typed_data::TypedData,
+    },
+    owned_account::OwnedAccount,
+};
+use pyo3::{
+    exceptions::{PyLookupError, PyPermissionError, PyRuntimeError, PyValueError},
+    prelude::*,
+    types::PyBytes,
+};
+
+/// Account backed by a KMS key, e.g.:
+///
+/// ```python
+/// from evm_signer_kms import EvmAccount
+///
+/// account = EvmAccount("alias/treasury", permitted_chain_ids=[11155111])
+/// raw_tx = account.sign_transaction_json(tx_json)
+/// ```
+///
+/// KMS calls release the GIL.
+#[pyclass(name = "EvmAccount", module = "evm_signer_kms", frozen)]
+pub struct PyEvmAccount {
+    account: OwnedAccount,
+}
+
+#[pymethods]
+impl PyEvmAccount {
+    /// Creates the account of the KMS key, with the AWS configuration from the environment,
+    /// enforcing the signing policy made of the optional rules.
+    #[new]
+    #[pyo3(signature = (key_id, permitted_chain_ids=None, max_data_length=None))]
+    fn new(
+        py: Python<'_>,
+        key_id: String,
+        permitted_chain_ids: Option<Vec<u64>>,
+        max_data_length: Option<usize>,
+    ) -> PyResult<Self> {
+        let mut policy = SigningPolicy::new();
+        if let Some(permitted_chain_ids) = permitted_chain_ids {
+            policy = policy.with_permitted_chain_ids(permitted_chain_ids);
+        }
+        if let Some(max_data_length) = max_data_length {
+            policy = policy.with_max_data_length(max_data_length);
+        }
+
+        let account = py
+            .allow_threads(|| OwnedAccount::new(key_id, policy))
+            .map_err(to_py_err)?;
+
+        Ok(Self { account })
+    }
+
+    /// Checksummed address of the account.
+    #[getter]
+    fn address(&self) -> String {
+        self.account.evm_account().checksummed_address().to_string()
+    }
+
+    /// Raw, uncompressed 64-byte public key of the account.
+    #[getter]
+    fn public_key<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.account.evm_account().public_key)
+    }
+
+    /// Signs the transaction JSON of any supported type and returns the signed transaction in hex.
+    ///
+    /// `mode` is one of `strict`, `lenient` and `tolerant` (see `json::Mode`).
+    #[pyo3(signature = (tx_json, mode="lenient"))]
+    fn sign_transaction_json(&self, py: Python<'_>, tx_json: &str, mode: &str) -> PyResult<String> {
+        let mode = parse_mode(mode).map_err(to_py_err)?;
+        let encoding = py
+            .allow_threads(|| {
+                self.account.block_on(
+                    self.account
+                        .evm_account()
+                        .sign_transaction_json(tx_json, mode),
+                )
+            })
+            .map_err(to_py_err)?;
+
+        Ok(Bytes(encoding).to_string())
+    }
+
+    /// Signs the message according to EIP-191 (i.e. `personal_sign`) and returns the 65-byte
+    /// signature in hex.
+    fn sign_message(&self, py: Python<'_>, message: &[u8]) -> PyResult<String> {
+        let signature = py
+            .allow_threads(|| {
+                self.account
+                    .block_on(self.account.evm_account().sign_message(message))
+            })
+            .map_err(to_py_err)?;
+
+        Ok(signature.to_string())
+    }
+
+    /// Signs the EIP-712 typed data JSON (i.e. `eth_signTypedData_v4`) and returns the 65-byte
+    /// signature in hex.
+    fn sign_typed_data(&self, py: Python<'_>, typed_data_json: &str) -> PyResult<String> {
+        let typed_data: TypedData = typed_data_json.parse().map_err(to_py_err)?;
+        let signature = py
+            .allow_threads(|| {
+                self.account
+                    .block_on(self.account.evm_account().sign_typed_data(&typed_data))
+            })
+            .map_err(to_py_err)?;
+
+        Ok(signature.to_string())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "EvmAccount('{}')",
+            self.account.evm_account().checksummed_address()
+        )
+    }
+}
+
+/// Decodes the signed transaction in hex into the JSON object of its fields, as printed by `cast`.
+#[pyfunction]
+fn decode_transaction(raw_tx: &str) -> PyResult<String> {
+    let raw_tx: Bytes = raw_tx.parse().map_err(to_py_err)?;
+
+    Ok(decode_to_json(&raw_tx.0).map_err(to_py_err)?.to_string())
+}
+
+/// Recovers the checksummed address which signed the 32-byte digest with the 65-byte signature.
+#[pyfunction]
+fn recover_signer(digest: &[u8], signature: &[u8]) -> PyResult<String> {
+    let digest: Keccak256Digest = digest
+        .try_into()
+        .map_err(|_| PyValueError::new_err("Digest must be 32 bytes long"))?;
+    let signature = Signature::try_from(signature).map_err(to_py_err)?;
+
+    Ok(signature.recover(&digest).map_err(to_py_err)?.to_string())
+}
+
+/// Python module `evm_signer_kms`, built with maturin (see `pyproject.toml`).
+#[pymodule]
+fn evm_signer_kms(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyEvmAccount>()?;
+    module.add_function(wrap_pyfunction!(decode_transaction, module)?)?;
+    module.add_function(wrap_pyfunction!(recover_signer, module)?)?;
+
+    Ok(())
+}
+
+fn parse_mode(mode: &str) -> Result<Mode, Error> {
+    match mode {
+        "strict" => Ok(Mode::Strict),
+        "lenient" => Ok(Mode::Lenient),
+        "tolerant" => Ok(Mode::Tolerant),
+        mode => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("Unknown mode: {}", mode),
+        )),
+    }
+}
+
+fn to_py_err(error: Error) -> PyErr {
+    let message = error.to_string();
+
+    match error.kind() {
+        ErrorKind::InvalidInput | ErrorKind::InvalidData | ErrorKind::Unsupported => {
+            PyValueError::new_err(message)
+        }
+        ErrorKind::PermissionDenied => PyPermissionError::new_err(message),
+        ErrorKind::NotFound => PyLookupError::new_err(message),
+        _ => PyRuntimeError::new_err(message),
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn parse_mode_succeed() {
+        assert_eq!(Mode::Strict, parse_mode("strict").unwrap());
+        assert_eq!(Mode::Tolerant, parse_mode("tolerant").unwrap());
+    }
+
+    #[test]
+    fn parse_mode_fail() {
+        let right = parse_mode("loose").unwrap_err();
+
+        assert_eq!(ErrorKind::InvalidInput, right.kind());
+    }
+}
@@ -4,7 +4,9 @@ mod free_market_transaction {
         use std::fs::File;
 
         use evm_signer_kms::evm_account::transaction::{
-            access_list::Access, access_list_transaction::AccessListTransaction,
+            access_list::{Access, StorageKey},
+            access_list_transaction::AccessListTransaction,
+            AccountAddress, Bytes, U256,
         };
 
         const TEST_TO_ADDRESS_BYTES: [u8; 20] = [
@@ -20,11 +22,11 @@ mod free_market_transaction {
             let left = AccessListTransaction {
                 chain_id: 421614,
                 nonce: 5,
-                gas_price: 100_000_000_000,
+                gas_price: U256::new(100_000_000_000),
                 gas_limit: 21_000,
-                to: Some(TEST_TO_ADDRESS_BYTES),
-                value: 10_000_000_000_000_000,
-                data: vec![],
+                to: Some(AccountAddress(TEST_TO_ADDRESS_BYTES)),
+                value: U256::new(10_000_000_000_000_000),
+                data: Bytes::default(),
                 access_list: vec![],
             };
 
@@ -41,35 +43,35 @@ mod free_market_transaction {
             let left = AccessListTransaction {
                 chain_id: 421614,
                 nonce: 5,
-                gas_price: 100_000_000_000,
+                gas_price: U256::new(100_000_000_000),
                 gas_limit: 21_000,
-                to: Some(TEST_TO_ADDRESS_BYTES),
-                value: 10_000_000_000_000_000,
-                data: vec![],
+                to: Some(AccountAddress(TEST_TO_ADDRESS_BYTES)),
+                value: U256::new(10_000_000_000_000_000),
+                data: Bytes::default(),
                 access_list: vec![
                     Access {
-                        address: [
+                        address: AccountAddress([
                             0xde, 0x0b, 0x29, 0x56, 0x69, 0xa9, 0xfd, 0x93, 0xd5, 0xf2, 0x8d, 0x9e,
                             0xc8, 0x5e, 0x40, 0xf4, 0xcb, 0x69, 0x7b, 0xae,
-                        ],
+                        ]),
                         storage_keys: vec![
-                            [
+                            StorageKey([
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
-                            ],
-                            [
+                            ]),
+                            StorageKey([
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07,
-                            ],
+                            ]),
                         ],
                     },
                     Access {
-                        address: [
+                        address: AccountAddress([
                             0xbb, 0x9b, 0xc2, 0x44, 0xd7, 0x98, 0x12, 0x3f, 0xde, 0x78, 0x3f, 0xcc,
                             0x1c, 0x72, 0xd3, 0xbb, 0x8c, 0x18, 0x94, 0x13,
-                        ],
+                        ]),
                         storage_keys: vec![],
                     },
                 ],
@@ -88,35 +90,35 @@ mod free_market_transaction {
             let left = AccessListTransaction {
                 chain_id: 421614,
                 nonce: 5,
-                gas_price: 100_000_000_000,
+                gas_price: U256::new(100_000_000_000),
                 gas_limit: 21_000,
                 to: None,
-                value: 10_000_000_000_000_000,
-                data: vec![],
+                value: U256::new(10_000_000_000_000_000),
+                data: Bytes::default(),
                 access_list: vec![
                     Access {
-                        address: [
+                        address: AccountAddress([
                             0xde, 0x0b, 0x29, 0x56, 0x69, 0xa9, 0xfd, 0x93, 0xd5, 0xf2, 0x8d, 0x9e,
                             0xc8, 0x5e, 0x40, 0xf4, 0xcb, 0x69, 0x7b, 0xae,
-                        ],
+                        ]),
                         storage_keys: vec![
-                            [
+                            StorageKey([
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
-                            ],
-                            [
+                            ]),
+                            StorageKey([
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
                                 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07,
-                            ],
+                            ]),
                         ],
                     },
                     Access {
-                        address: [
+                        address: AccountAddress([
                             0xbb, 0x9b, 0xc2, 0x44, 0xd7, 0x98, 0x12, 0x3f, 0xde, 0x78, 0x3f, 0xcc,
                             0x1c, 0x72, 0xd3, 0xbb, 0x8c, 0x18, 0x94, 0x13,
-                        ],
+                        ]),
                         storage_keys: vec![],
                     },
                 ],
@@ -127,6 +129,47 @@ mod free_market_transaction {
             assert_eq!(left, right);
         }
 
+        #[test]
+        fn deserialize_valid_access_list_tx_04_succeed() {
+            // Same transaction with the access list (partially) in the object format
+            const TX_FILE_PATH: &str = "tests/data/valid-access-list-tx-04.json";
+            const SAME_TX_FILE_PATH: &str = "tests/data/valid-access-list-tx-02.json";
+
+            let left: AccessListTransaction =
+                serde_json::from_reader(File::open(SAME_TX_FILE_PATH).unwrap()).unwrap();
+
+            let right: AccessListTransaction =
+                serde_json::from_reader(File::open(TX_FILE_PATH).unwrap()).unwrap();
+
+            assert_eq!(left, right);
+        }
+
+        #[test]
+        fn serialize_round_trip_01_succeed() {
+            const TX_FILE_PATH: &str = "tests/data/valid-access-list-tx-02.json";
+
+            let tx_file = File::open(TX_FILE_PATH).unwrap();
+            let left: AccessListTransaction = serde_json::from_reader(tx_file).unwrap();
+
+            let tx_json = serde_json::to_string(&left).unwrap();
+            let right: AccessListTransaction = serde_json::from_str(&tx_json).unwrap();
+
+            assert_eq!(left, right);
+        }
+
+        #[test]
+        fn serialize_round_trip_02_succeed() {
+            const TX_FILE_PATH: &str = "tests/data/valid-access-list-tx-03.json";
+
+            let tx_file = File::open(TX_FILE_PATH).unwrap();
+            let left: AccessListTransaction = serde_json::from_reader(tx_file).unwrap();
+
+            let tx_json = serde_json::to_string(&left).unwrap();
+            let right: AccessListTransaction = serde_json::from_str(&tx_json).unwrap();
+
+            assert_eq!(left, right);
+        }
+
         #[test]
         #[should_panic]
         fn deserialize_invalid_free_market_tx_01_fail() {
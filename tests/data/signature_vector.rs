@@ -0,0 +1,16 @@
+// Signature of `TEST_DIGEST` by the account `TEST_SENDER`, as returned by KMS (i.e. with `s` not
+// normalized). Shared by the unit tests of the library and the binary, and by the benchmarks, which
+// `include!` this file.
+pub const TEST_DIGEST: [u8; 32] = [
+    0x02, 0x6f, 0x61, 0x4e, 0xa0, 0x9e, 0x14, 0x68, 0x28, 0xcb, 0x42, 0xe8, 0xda, 0x55, 0xa5, 0x9a,
+    0x90, 0x3b, 0xc6, 0x23, 0x00, 0xa5, 0x27, 0x85, 0xbd, 0xba, 0x8b, 0x94, 0x46, 0xc6, 0x0c, 0x7d,
+];
+pub const TEST_R: [u8; 32] = [
+    0x5e, 0x12, 0x50, 0x05, 0xa0, 0x8e, 0xcd, 0x57, 0x72, 0x81, 0x39, 0x6b, 0x81, 0xb0, 0x57, 0x20,
+    0x13, 0xdb, 0xa0, 0x5b, 0x74, 0xfa, 0xc7, 0x79, 0x21, 0xf4, 0x71, 0x9c, 0xf3, 0x7e, 0x9c, 0xe0,
+];
+pub const TEST_S: [u8; 32] = [
+    0xe9, 0x9f, 0x4f, 0x23, 0x4d, 0x5c, 0x2a, 0x59, 0x0a, 0x4b, 0x0a, 0x07, 0x7d, 0x49, 0x0d, 0xde,
+    0x56, 0x4a, 0xbc, 0x14, 0xfc, 0x4e, 0xa5, 0x30, 0x30, 0xa7, 0x14, 0x39, 0x91, 0x0d, 0xfa, 0x89,
+];
+pub const TEST_SENDER: &str = "0x70AD754Ff670077411dF598FCfFd61c48299f12F";
@@ -7,11 +7,12 @@ mod evm_account {
         use std::fs::File;
 
         use evm_signer_kms::evm_account::{
+            hash::{self, eip191_hash_message},
             kms_key,
             transaction::{
                 access_list_transaction::AccessListTransaction,
                 free_market_transaction::FreeMarketTransaction,
-                legacy_transaction::LegacyTransaction,
+                legacy_transaction::LegacyTransaction, AccountAddress, Bytes, U256,
             },
             EvmAccount,
         };
@@ -43,13 +44,13 @@ mod evm_account {
 
             let tx = FreeMarketTransaction {
                 gas_limit: 21_000,
-                max_fee_per_gas: 100_000_000_000,
-                max_priority_fee_per_gas: 3_000_000_000,
+                max_fee_per_gas: U256::new(100_000_000_000),
+                max_priority_fee_per_gas: U256::new(3_000_000_000),
                 chain_id: 1,
                 nonce: 0,
-                to: Some(TEST_TO_ADDRESS_BYTES),
-                value: 10_000_000_000_000_000,
-                data: vec![],
+                to: Some(AccountAddress(TEST_TO_ADDRESS_BYTES)),
+                value: U256::new(10_000_000_000_000_000),
+                data: Bytes::default(),
                 access_list: vec![],
             };
 
@@ -190,5 +191,42 @@ mod evm_account {
 
             assert!(true);
         }
+
+        #[tokio::test]
+        async fn sign_message_succeed() {
+            const TEST_MESSAGE: &str = "Hello, world!";
+
+            let kms_key = &kms_key::KmsKey::new(&KMS_KEY_ID).await;
+            let evm_account = EvmAccount::new(kms_key).await.unwrap();
+
+            let signature = evm_account.sign_message(TEST_MESSAGE).await.unwrap();
+            let signer = signature
+                .recover(&eip191_hash_message(TEST_MESSAGE))
+                .unwrap();
+
+            assert_eq!(evm_account.address(), signer);
+        }
+
+        #[tokio::test]
+        async fn warm_up_succeed() {
+            let kms_key = &kms_key::KmsKey::new(&KMS_KEY_ID).await;
+            let evm_account = EvmAccount::new(kms_key).await.unwrap();
+
+            evm_account.warm_up().await.unwrap();
+        }
+
+        #[tokio::test]
+        async fn checksummed_address_succeed() {
+            let kms_key = &kms_key::KmsKey::new(&KMS_KEY_ID).await;
+            let evm_account = EvmAccount::new(kms_key).await.unwrap();
+
+            let right = evm_account.checksummed_address();
+
+            assert_eq!(evm_account.address().to_string(), right);
+            assert_eq!(
+                hash::address_from_public_key(&evm_account.public_key),
+                evm_account.address()
+            );
+        }
     }
 }
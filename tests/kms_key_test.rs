@@ -45,6 +45,21 @@ mod kms_key {
             kms_key.await.get_public_key().await.unwrap();
         }
 
+        #[tokio::test]
+        async fn validate_succeed() {
+            let kms_key = KmsKey::new(&KMS_KEY_ID);
+
+            kms_key.await.validate().await.unwrap();
+        }
+
+        #[tokio::test]
+        #[should_panic]
+        async fn validate_fail() {
+            let kms_key = KmsKey::new(DUMMY_KMS_KEY_ID);
+
+            kms_key.await.validate().await.unwrap();
+        }
+
         // Just verifies if the signature process works
         #[tokio::test]
         async fn sign_succeed() {
@@ -2,7 +2,9 @@ mod legacy_transaction {
     mod integration_tests {
         use std::fs::File;
 
-        use evm_signer_kms::evm_account::transaction::legacy_transaction::LegacyTransaction;
+        use evm_signer_kms::evm_account::transaction::{
+            legacy_transaction::LegacyTransaction, AccountAddress, Bytes, U256,
+        };
 
         const TEST_TO_ADDRESS_BYTES: [u8; 20] = [
             0xa9, 0xd8, 0x91, 0x86, 0xca, 0xa6, 0x63, 0xc8, 0xef, 0x03, 0x52, 0xfd, 0x1d, 0xb3,
@@ -17,10 +19,10 @@ mod legacy_transaction {
             let left = LegacyTransaction {
                 nonce: 5,
                 gas_limit: 21_000,
-                gas_price: 100_000_000_000,
-                to: Some(TEST_TO_ADDRESS_BYTES),
-                value: 10_000_000_000_000_000,
-                data: vec![],
+                gas_price: U256::new(100_000_000_000),
+                to: Some(AccountAddress(TEST_TO_ADDRESS_BYTES)),
+                value: U256::new(10_000_000_000_000_000),
+                data: Bytes::default(),
             };
 
             let right: LegacyTransaction = serde_json::from_reader(tx_file).unwrap();
@@ -36,10 +38,10 @@ mod legacy_transaction {
             let left = LegacyTransaction {
                 nonce: 5,
                 gas_limit: 21_000,
-                gas_price: 100_000_000_000,
+                gas_price: U256::new(100_000_000_000),
                 to: None,
-                value: 10_000_000_000_000_000,
-                data: vec![],
+                value: U256::new(10_000_000_000_000_000),
+                data: Bytes::default(),
             };
 
             let right: LegacyTransaction = serde_json::from_reader(tx_file).unwrap();
@@ -47,6 +49,65 @@ mod legacy_transaction {
             assert_eq!(left, right);
         }
 
+        #[test]
+        fn deserialize_valid_legacy_tx_03_succeed() {
+            const TX_FILE_PATH: &str = "tests/data/valid-legacy-tx-03.json";
+
+            let tx_file = File::open(TX_FILE_PATH).unwrap();
+            let left = LegacyTransaction {
+                nonce: 5,
+                gas_limit: 21_000,
+                gas_price: U256::new(100_000_000_000),
+                to: Some(AccountAddress(TEST_TO_ADDRESS_BYTES)),
+                value: U256::new(100_000_000_000_000_000_000_000),
+                data: Bytes::default(),
+            };
+
+            let right: LegacyTransaction = serde_json::from_reader(tx_file).unwrap();
+
+            assert_eq!(left, right);
+        }
+
+        #[test]
+        fn serialize_round_trip_01_succeed() {
+            const TX_FILE_PATH: &str = "tests/data/valid-legacy-tx-02.json";
+
+            let tx_file = File::open(TX_FILE_PATH).unwrap();
+            let left: LegacyTransaction = serde_json::from_reader(tx_file).unwrap();
+
+            let tx_json = serde_json::to_string(&left).unwrap();
+            let right: LegacyTransaction = serde_json::from_str(&tx_json).unwrap();
+
+            assert_eq!(left, right);
+        }
+
+        #[test]
+        fn serialize_round_trip_02_succeed() {
+            const TX_FILE_PATH: &str = "tests/data/valid-legacy-tx-03.json";
+
+            let tx_file = File::open(TX_FILE_PATH).unwrap();
+            let left: LegacyTransaction = serde_json::from_reader(tx_file).unwrap();
+
+            let tx_json = serde_json::to_string(&left).unwrap();
+            let right: LegacyTransaction = serde_json::from_str(&tx_json).unwrap();
+
+            assert_eq!(left, right);
+        }
+
+        #[test]
+        fn serialize_checksummed_address_succeed() {
+            const TX_FILE_PATH: &str = "tests/data/valid-legacy-tx-01.json";
+
+            let tx_file = File::open(TX_FILE_PATH).unwrap();
+            let tx: LegacyTransaction = serde_json::from_reader(tx_file).unwrap();
+
+            let right = serde_json::to_value(&tx).unwrap();
+
+            assert_eq!(right["to"], "0xa9d89186cAA663C8Ef0352Fd1Db3596280625573");
+            assert_eq!(right["data"], "0x");
+            assert_eq!(right["gasPrice"], "100000000000");
+        }
+
         #[test]
         #[should_panic]
         fn deserialize_invalid_legacy_tx_01_succeed() {